@@ -0,0 +1,273 @@
+//! Which render engines a process has loaded, and which one it most likely
+//! renders with.
+//!
+//! Processes routinely load more than their renderer: a DirectX12 game may pull
+//! in `d3d11.dll` for D3D11On12 or video playback, Direct2D loads `d3d10_1.dll`
+//! and launchers bring `d3d9.dll`. Every engine found is reported with the
+//! modules that gave it away, ranked by how likely it is to be the one
+//! presenting frames.
+
+use crate::{
+    module::{self, Module, ModuleEnumerator, ProcessModules},
+    RenderEngine,
+};
+
+/// Modules of each engine, in lower case.
+const ENGINE_MODULES: [(RenderEngine, &[&str]); 4] = [
+    (RenderEngine::DirectX9, &["d3d9.dll"]),
+    (
+        RenderEngine::DirectX10,
+        &[
+            "d3d10.dll",
+            "d3d10_1.dll",
+            "d3d10core.dll",
+            "d3d10_1core.dll",
+        ],
+    ),
+    (RenderEngine::DirectX11, &["d3d11.dll"]),
+    (RenderEngine::DirectX12, &["d3d12.dll", "d3d12core.dll"]),
+];
+
+/// Modules implementing an engine's devices. `d3d10.dll` only loads its runtime
+/// once a device is created, `d3d9.dll` and `d3d11.dll` are runtimes themselves.
+///
+/// `d3d12.dll` counts as much as `d3d11.dll`: `d3d12core.dll` only loads with the
+/// first device, which does not exist yet when shroud is injected early, while
+/// DirectX12 games often have `d3d11.dll` loaded for video playback.
+const RUNTIME_MODULES: [&str; 6] = [
+    "d3d9.dll",
+    "d3d10core.dll",
+    "d3d10_1core.dll",
+    "d3d11.dll",
+    "d3d12.dll",
+    "d3d12core.dll",
+];
+
+/// Mapping layers, and the engines they implement on top of which other one.
+const LAYER_MODULES: [(&str, RenderEngine, RenderEngine); 2] = [
+    (
+        "d3d9on12.dll",
+        RenderEngine::DirectX9,
+        RenderEngine::DirectX12,
+    ),
+    (
+        "d3d11on12.dll",
+        RenderEngine::DirectX11,
+        RenderEngine::DirectX12,
+    ),
+];
+
+const DXGI_MODULE: &str = "dxgi.dll";
+
+/// A render engine found loaded, with the evidence for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detection {
+    pub engine: RenderEngine,
+    /// The engine's modules found loaded, mapping layers onto it included.
+    pub modules: Vec<Module>,
+    /// The engine's device runtime is loaded, not only its API entry point.
+    pub runtime: bool,
+    /// `dxgi.dll` is loaded, which DirectX10 and up present through.
+    pub dxgi: bool,
+    /// The engine another one is mapped onto, e.g. DirectX12 for DirectX11 when
+    /// `d3d11on12.dll` is loaded. Frames are then presented by that engine.
+    pub layered_on: Option<RenderEngine>,
+}
+
+impl Detection {
+    /// Orders detections most likely renderer first: engines frames are
+    /// presented through, then those with a device runtime, then those that can
+    /// present at all, then the newer engine, as old ones are the ones pulled in
+    /// on the side.
+    fn rank(&self) -> impl Ord {
+        let presentable = self.dxgi || self.engine == RenderEngine::DirectX9;
+        (
+            self.layered_on.is_some(),
+            !self.runtime,
+            !presentable,
+            std::cmp::Reverse(self.engine as u8),
+        )
+    }
+}
+
+/// Every render engine loaded in the process, most likely renderer first.
+pub fn detect_render_engines() -> Vec<Detection> {
+    detect_render_engines_in(&ProcessModules)
+}
+
+/// [`detect_render_engines`] over the modules `modules` lists.
+pub fn detect_render_engines_in(modules: &(impl ModuleEnumerator + ?Sized)) -> Vec<Detection> {
+    let modules = modules.modules();
+    let loaded = |name: &str| {
+        modules
+            .iter()
+            .filter(|module| module.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let dxgi = !loaded(DXGI_MODULE).is_empty();
+
+    let mut detections: Vec<Detection> = ENGINE_MODULES
+        .iter()
+        .filter_map(|&(engine, names)| {
+            let found: Vec<Module> = names.iter().flat_map(|name| loaded(name)).collect();
+            if found.is_empty() {
+                return None;
+            }
+
+            let runtime = found.iter().any(|module| {
+                RUNTIME_MODULES
+                    .iter()
+                    .any(|runtime| module.name.eq_ignore_ascii_case(runtime))
+            });
+            Some(Detection {
+                engine,
+                modules: found,
+                runtime,
+                dxgi,
+                layered_on: None,
+            })
+        })
+        .collect();
+
+    for (name, engine, onto) in LAYER_MODULES {
+        let layer = loaded(name);
+        if layer.is_empty() || !detections.iter().any(|d| d.engine == onto) {
+            continue;
+        }
+        if let Some(detection) = detections.iter_mut().find(|d| d.engine == engine) {
+            detection.modules.extend(layer);
+            detection.layered_on = Some(onto);
+        }
+    }
+
+    for detection in &mut detections {
+        for module in &mut detection.modules {
+            if module.version.is_none() {
+                module.version = module::file_version(&module.path);
+            }
+        }
+    }
+
+    detections.sort_by_key(Detection::rank);
+    detections
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn modules(names: &[&str]) -> Vec<Module> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| Module {
+                name: name.to_string(),
+                path: PathBuf::from(format!("C:\\Windows\\System32\\{name}")),
+                base: 0x1000_0000 + index * 0x10_0000,
+                version: Some("10.0.22621.1".to_owned()),
+            })
+            .collect()
+    }
+
+    fn ranked(names: &[&str]) -> Vec<RenderEngine> {
+        detect_render_engines_in(&modules(names)[..])
+            .iter()
+            .map(|detection| detection.engine)
+            .collect()
+    }
+
+    #[test]
+    fn nothing_loaded_detects_nothing() {
+        assert!(ranked(&[]).is_empty());
+        assert!(ranked(&["kernel32.dll", "dxgi.dll"]).is_empty());
+    }
+
+    #[test]
+    fn directx12_ranks_over_directx11_loaded_for_video() {
+        use RenderEngine::*;
+
+        // Before the game created its device, d3d12core.dll is not loaded yet.
+        assert_eq!(
+            ranked(&["dxgi.dll", "d3d12.dll", "d3d11.dll"]),
+            [DirectX12, DirectX11]
+        );
+        assert_eq!(
+            ranked(&["d3d11.dll", "dxgi.dll", "d3d12.dll", "d3d12core.dll"]),
+            [DirectX12, DirectX11]
+        );
+    }
+
+    #[test]
+    fn directx11_ranks_over_a_launchers_directx9() {
+        use RenderEngine::*;
+
+        assert_eq!(
+            ranked(&["d3d9.dll", "dxgi.dll", "d3d11.dll"]),
+            [DirectX11, DirectX9]
+        );
+    }
+
+    #[test]
+    fn runtimes_rank_over_entry_points() {
+        use RenderEngine::*;
+
+        // Direct2D pulls in d3d10_1.dll without ever creating a device with it.
+        assert_eq!(ranked(&["d3d10_1.dll", "d3d9.dll"]), [DirectX9, DirectX10]);
+        assert_eq!(
+            ranked(&["d3d10_1.dll", "d3d10_1core.dll", "dxgi.dll", "d3d9.dll"]),
+            [DirectX10, DirectX9]
+        );
+    }
+
+    #[test]
+    fn engines_without_dxgi_can_not_present() {
+        let detections = detect_render_engines_in(&modules(&["d3d11.dll", "d3d9.dll"])[..]);
+        assert_eq!(detections[0].engine, RenderEngine::DirectX9);
+        assert!(!detections[1].dxgi);
+        assert!(detections[1].runtime);
+    }
+
+    #[test]
+    fn mapping_layers_rank_below_their_target() {
+        let detections = detect_render_engines_in(
+            &modules(&[
+                "d3d11.dll",
+                "d3d11on12.dll",
+                "dxgi.dll",
+                "d3d12.dll",
+                "d3d12core.dll",
+            ])[..],
+        );
+
+        assert_eq!(detections[0].engine, RenderEngine::DirectX12);
+        assert_eq!(detections[0].layered_on, None);
+        assert_eq!(detections[1].engine, RenderEngine::DirectX11);
+        assert_eq!(detections[1].layered_on, Some(RenderEngine::DirectX12));
+        let names: Vec<&str> = detections[1]
+            .modules
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, ["d3d11.dll", "d3d11on12.dll"]);
+    }
+
+    #[test]
+    fn layers_need_their_target_loaded() {
+        let detections = detect_render_engines_in(&modules(&["d3d9.dll", "d3d9on12.dll"])[..]);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].layered_on, None);
+        assert_eq!(detections[0].modules.len(), 1);
+    }
+
+    #[test]
+    fn names_match_in_any_case() {
+        let detections = detect_render_engines_in(&modules(&["DXGI.dll", "D3D12.DLL"])[..]);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].engine, RenderEngine::DirectX12);
+        assert!(detections[0].runtime);
+        assert!(detections[0].dxgi);
+    }
+}
@@ -0,0 +1,565 @@
+//! Inline detours, for callers that never go through a vtable.
+//!
+//! Vtable hooks only see calls made through the vtable. Code that cached a
+//! method's address, or the implementation calling itself, goes straight to the
+//! function. Detouring rewrites the start of the function itself instead:
+//!
+//! - the target's first instructions are decoded until at least five bytes are
+//!   covered, and copied into a trampoline allocated within ±2GB of the target;
+//! - RIP-relative operands and branches of the copies are rewritten for their
+//!   new address, and a jump back to the rest of the target follows them;
+//! - the target's first five bytes become a `jmp rel32` to a relay next to the
+//!   trampoline, which jumps on to the detour at any distance.
+//!
+//! On 32 bit x86 a rel32 reaches the whole address space and there is no
+//! RIP-relative addressing, so only branches need rewriting. The detour's type
+//! carries the calling convention: COM methods are `extern "system"`, which is
+//! `stdcall` there.
+
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use crate::{protect, ShroudError, ShroudResult};
+
+mod decode;
+mod memory;
+
+use decode::Relative;
+
+/// Length of the `jmp rel32` written over the target.
+const JMP_LEN: usize = 5;
+
+/// Room for the relocated instructions and the jump back to the target.
+const TRAMPOLINE_LEN: usize = 64;
+
+/// Offset of the relay after the trampoline, placing its destination on an
+/// 8 byte boundary so it can be swapped atomically.
+const RELAY: usize = TRAMPOLINE_LEN + 2;
+
+/// Length of the relay's indirect `jmp`, which its destination follows.
+const RELAY_JMP_LEN: usize = 6;
+
+const RELAY_LEN: usize = RELAY_JMP_LEN + std::mem::size_of::<usize>();
+
+/// Serializes installing and removing detours, so the bytes a detour saves are
+/// never half of another one's jump.
+static DETOURING: Mutex<()> = Mutex::new(());
+
+/// A function whose first instructions jump to a detour, restored on drop.
+///
+/// [`Detour::original`] runs the overwritten instructions from the trampoline
+/// and continues in the target, so the detour can call through to the function
+/// as it was.
+pub struct Detour<F> {
+    target: *mut u8,
+    original: [u8; JMP_LEN],
+    patch: [u8; JMP_LEN],
+    trampoline: *const c_void,
+    relay: *const AtomicUsize,
+    detour: *const c_void,
+    function: PhantomData<F>,
+}
+
+impl<F: Copy> Detour<F> {
+    /// Detours `target` to `detour`, effective once this returns.
+    ///
+    /// Fails without touching the target when its first instructions can not be
+    /// relocated, e.g. functions shorter than the jump or prologues that branch
+    /// back into the overwritten bytes.
+    ///
+    /// # Safety
+    ///
+    /// `target` must be the start of a function, `F` a function pointer type
+    /// matching its ABI and signature, and no thread may be executing the
+    /// target's first five bytes while the jump is written.
+    pub unsafe fn new(target: *const c_void, detour: F) -> ShroudResult<Self> {
+        assert_eq!(
+            std::mem::size_of::<F>(),
+            std::mem::size_of::<*const c_void>(),
+            "detour must be a function pointer"
+        );
+        if target.is_null() {
+            return Err(ShroudError::NullPointer("target"));
+        }
+
+        let detour: *const c_void = std::mem::transmute_copy(&detour);
+        let target = target.cast_mut().cast::<u8>();
+        let _detouring = DETOURING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let trampoline = memory::alloc(target as usize, RELAY + RELAY_LEN)?;
+        let installed = install(target, trampoline, detour);
+        if installed.is_err() {
+            memory::free(trampoline, RELAY + RELAY_LEN);
+        }
+        let (original, patch) = installed?;
+
+        Ok(Self {
+            target,
+            original,
+            patch,
+            trampoline: trampoline as *const c_void,
+            relay: (trampoline + RELAY + RELAY_JMP_LEN) as *const AtomicUsize,
+            detour,
+            function: PhantomData,
+        })
+    }
+
+    /// The target as it was before detouring, for the detour to call through.
+    pub fn original(&self) -> F {
+        unsafe { std::mem::transmute_copy(&self.trampoline) }
+    }
+
+    /// Removes the detour now, reporting failure instead of ignoring it as drop does.
+    pub fn uninstall(self) -> ShroudResult<()> {
+        let result = unsafe { self.restore() };
+        std::mem::forget(self);
+        result
+    }
+}
+
+impl<F> Detour<F> {
+    pub fn target(&self) -> *const c_void {
+        self.target as *const c_void
+    }
+
+    /// Puts the target's original bytes back. If the target was detoured again on
+    /// top of this detour, its jump may live on in the other trampoline, so the
+    /// relay is pointed at this trampoline instead, passing calls straight through.
+    unsafe fn restore(&self) -> ShroudResult<()> {
+        let _detouring = DETOURING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let (target, original, patch) = (self.target, self.original, self.patch);
+        let restored = protect::patch(target as *const c_void, 8, || {
+            let installed = std::ptr::read_volatile(target as *const [u8; JMP_LEN]) == patch;
+            if installed {
+                write_code(target, original);
+            }
+            installed
+        })?;
+
+        match restored {
+            true => flush(target as *const c_void, JMP_LEN),
+            false => {
+                let (relay, trampoline) = (self.relay, self.trampoline);
+                protect::patch(relay.cast(), std::mem::size_of::<usize>(), || {
+                    (*relay).store(trampoline as usize, Ordering::Release)
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F> Drop for Detour<F> {
+    fn drop(&mut self) {
+        let _ = unsafe { self.restore() };
+    }
+}
+
+impl<F> std::fmt::Debug for Detour<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Detour")
+            .field("target", &self.target)
+            .field("trampoline", &self.trampoline)
+            .field("detour", &self.detour)
+            .finish()
+    }
+}
+
+/// Fills `trampoline` and jumps from `target` to its relay, returning the bytes
+/// the jump replaced and the jump itself. Nothing jumps to the trampoline unless
+/// this succeeds.
+unsafe fn install(
+    target: *mut u8,
+    trampoline: usize,
+    detour: *const c_void,
+) -> ShroudResult<([u8; JMP_LEN], [u8; JMP_LEN])> {
+    let code = relocate(target as usize, trampoline)?;
+    let patch = jump(target as usize, trampoline + RELAY)
+        .ok_or(ShroudError::Detour(target as usize, "relay out of reach"))?;
+
+    let relay = relay(trampoline + RELAY, detour as usize);
+    protect::patch(trampoline as *const c_void, RELAY + RELAY_LEN, || {
+        let base = trampoline as *mut u8;
+        std::ptr::copy_nonoverlapping(code.as_ptr(), base, code.len());
+        std::ptr::copy_nonoverlapping(relay.as_ptr(), base.add(RELAY), RELAY_LEN);
+    })?;
+    flush(trampoline as *const c_void, RELAY + RELAY_LEN);
+
+    let original = std::ptr::read(target as *const [u8; JMP_LEN]);
+    protect::patch(target as *const c_void, 8, || write_code(target, patch))?;
+    flush(target as *const c_void, JMP_LEN);
+    Ok((original, patch))
+}
+
+/// Copies whole instructions from the start of `target` until they cover a jump,
+/// rewritten to run at `trampoline`, followed by a jump back to the first
+/// instruction left in place.
+unsafe fn relocate(target: usize, trampoline: usize) -> ShroudResult<Vec<u8>> {
+    let error = |reason| ShroudError::Detour(target, reason);
+    let mut code = Vec::with_capacity(TRAMPOLINE_LEN);
+
+    let mut stolen = 0;
+    while stolen < JMP_LEN {
+        let source = target + stolen;
+        let bytes = std::slice::from_raw_parts(source as *const u8, decode::MAX_LEN);
+        let instruction = decode::decode(bytes).ok_or(error("unknown instruction"))?;
+        let bytes = &bytes[..instruction.len];
+        let end = source + instruction.len;
+
+        match instruction.relative {
+            None => code.extend_from_slice(bytes),
+            Some(relative @ (Relative::Memory(at) | Relative::Branch32(at))) => {
+                let displacement = i32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+                let destination = end.wrapping_add_signed(displacement as isize);
+                if matches!(relative, Relative::Branch32(_)) && overwritten(target, destination) {
+                    return Err(error("branch into the overwritten instructions"));
+                }
+
+                let moved_end = trampoline + code.len() + instruction.len;
+                let displacement =
+                    rel32(moved_end, destination).ok_or(error("relative operand out of reach"))?;
+                code.extend_from_slice(&bytes[..at]);
+                code.extend_from_slice(&displacement.to_le_bytes());
+                code.extend_from_slice(&bytes[at + 4..]);
+            }
+            Some(Relative::Branch8(at)) => {
+                let destination = end.wrapping_add_signed(bytes[at] as i8 as isize);
+                if overwritten(target, destination) {
+                    return Err(error("branch into the overwritten instructions"));
+                }
+
+                // Only plain `jmp` and `jcc` have rel32 forms, `loop` and `jrcxz` do not.
+                let opcode: &[u8] = match (instruction.opcode, bytes[0]) {
+                    (0, 0xEB) => &[0xE9],
+                    (0, op @ 0x70..=0x7F) => &[0x0F, op + 0x10],
+                    _ => return Err(error("short branch has no near form")),
+                };
+                let moved_end = trampoline + code.len() + opcode.len() + 4;
+                let displacement =
+                    rel32(moved_end, destination).ok_or(error("branch out of reach"))?;
+                code.extend_from_slice(opcode);
+                code.extend_from_slice(&displacement.to_le_bytes());
+            }
+        }
+
+        stolen += instruction.len;
+        if instruction.terminal {
+            break;
+        }
+    }
+
+    match stolen < JMP_LEN {
+        // The function ended early, the jump may only cover the padding after it.
+        true => {
+            if !padding(target + stolen, target + JMP_LEN) {
+                return Err(error("function shorter than a jump"));
+            }
+        }
+        false => {
+            let back = jump(trampoline + code.len(), target + stolen)
+                .ok_or(error("target out of reach"))?;
+            code.extend_from_slice(&back);
+        }
+    }
+
+    debug_assert!(code.len() <= TRAMPOLINE_LEN);
+    Ok(code)
+}
+
+/// Whether a branch to `destination` would land in the bytes the jump replaces.
+fn overwritten(target: usize, destination: usize) -> bool {
+    (target..target + JMP_LEN).contains(&destination)
+}
+
+/// Whether `[start, end)` is covered by `int3` and `nop` instructions.
+unsafe fn padding(mut start: usize, end: usize) -> bool {
+    while start < end {
+        let bytes = std::slice::from_raw_parts(start as *const u8, decode::MAX_LEN);
+        let Some(instruction) = decode::decode(bytes) else {
+            return false;
+        };
+        let opcode = &bytes[instruction.opcode..];
+        if !matches!(opcode, [0xCC, ..] | [0x90, ..] | [0x0F, 0x1F, ..]) {
+            return false;
+        }
+        start += instruction.len;
+    }
+    true
+}
+
+/// Displacement from the end of an instruction to `to`, if it fits 32 bits.
+fn rel32(end: usize, to: usize) -> Option<i32> {
+    i32::try_from(to.wrapping_sub(end) as isize).ok()
+}
+
+/// Indirect jump placed at `address`, through the destination stored after it.
+fn relay(address: usize, destination: usize) -> [u8; RELAY_LEN] {
+    // `jmp [rip + 0]` in 64 bit mode, `jmp [absolute]` in 32 bit mode.
+    let operand = match cfg!(target_arch = "x86_64") {
+        true => 0,
+        false => (address + RELAY_JMP_LEN) as u32,
+    };
+
+    let mut relay = [0; RELAY_LEN];
+    relay[..2].copy_from_slice(&[0xFF, 0x25]);
+    relay[2..RELAY_JMP_LEN].copy_from_slice(&operand.to_le_bytes());
+    relay[RELAY_JMP_LEN..].copy_from_slice(&destination.to_le_bytes());
+    relay
+}
+
+/// `jmp rel32` placed at `from` and landing on `to`.
+fn jump(from: usize, to: usize) -> Option<[u8; JMP_LEN]> {
+    let [a, b, c, d] = rel32(from + JMP_LEN, to)?.to_le_bytes();
+    Some([0xE9, a, b, c, d])
+}
+
+/// Overwrites the first bytes at `address` with `code` without a thread ever
+/// executing a mix of old and new bytes at `address` itself.
+///
+/// Eight bytes within one cache line are stored at once. Otherwise the start is
+/// first turned into a jump to itself, the tail is written, and the head last.
+unsafe fn write_code(address: *mut u8, code: [u8; JMP_LEN]) {
+    let line_left = 64 - address as usize % 64;
+    if line_left >= 8 {
+        let mut word = std::ptr::read_unaligned(address as *const [u8; 8]);
+        word[..JMP_LEN].copy_from_slice(&code);
+        store64(address, u64::from_le_bytes(word));
+    } else if line_left >= 2 {
+        store16(address, u16::from_le_bytes([0xEB, 0xFE]));
+        std::ptr::copy_nonoverlapping(code[2..].as_ptr(), address.add(2), JMP_LEN - 2);
+        store16(address, u16::from_le_bytes([code[0], code[1]]));
+    } else {
+        std::ptr::copy_nonoverlapping(code.as_ptr(), address, JMP_LEN);
+    }
+}
+
+// Plain stores, unaligned stores within a cache line being atomic on x86.
+#[cfg(target_arch = "x86_64")]
+unsafe fn store64(address: *mut u8, value: u64) {
+    std::arch::asm!(
+        "mov qword ptr [{}], {}",
+        in(reg) address,
+        in(reg) value,
+        options(nostack, preserves_flags)
+    );
+}
+
+// No general purpose register holds 8 bytes in 32 bit mode, an SSE register does.
+#[cfg(target_arch = "x86")]
+unsafe fn store64(address: *mut u8, value: u64) {
+    std::arch::asm!(
+        "movq {buffer}, qword ptr [{value}]",
+        "movq qword ptr [{address}], {buffer}",
+        address = in(reg) address,
+        value = in(reg) &value,
+        buffer = out(xmm_reg) _,
+        options(nostack, preserves_flags)
+    );
+}
+
+unsafe fn store16(address: *mut u8, value: u16) {
+    std::arch::asm!(
+        "mov word ptr [{}], {:x}",
+        in(reg) address,
+        in(reg) value,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Makes freshly written code visible to instruction fetch.
+#[cfg(windows)]
+unsafe fn flush(address: *const c_void, len: usize) {
+    use windows::Win32::System::{
+        Diagnostics::Debug::FlushInstructionCache, Threading::GetCurrentProcess,
+    };
+
+    let _ = FlushInstructionCache(GetCurrentProcess(), Some(address), len);
+}
+
+/// x86 keeps instruction fetch coherent with stores on its own.
+#[cfg(not(windows))]
+unsafe fn flush(_address: *const c_void, _len: usize) {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    /// Code to relocate, padded so the decoder never reads past the end.
+    fn code(bytes: &[u8]) -> Vec<u8> {
+        let mut code = bytes.to_vec();
+        code.resize(bytes.len() + 2 * decode::MAX_LEN, 0xCC);
+        code
+    }
+
+    /// Relocates `code` to a trampoline 0x1000 bytes after it.
+    fn relocated(code: &[u8]) -> (usize, usize, ShroudResult<Vec<u8>>) {
+        let target = code.as_ptr() as usize;
+        let trampoline = target + 0x1000;
+        (target, trampoline, unsafe { relocate(target, trampoline) })
+    }
+
+    fn jmp(from: usize, to: usize) -> Vec<u8> {
+        jump(from, to).unwrap().to_vec()
+    }
+
+    #[test]
+    fn copies_plain_instructions() {
+        // push rbp; mov rbp, rsp; sub rsp, 0x20
+        let code = code(&[0x55, 0x48, 0x89, 0xE5, 0x48, 0x83, 0xEC, 0x20]);
+        let (target, trampoline, relocated) = relocated(&code);
+
+        let mut expected = code[..8].to_vec();
+        expected.extend(jmp(trampoline + 8, target + 8));
+        assert_eq!(relocated.unwrap(), expected);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn rewrites_rip_relative_operands() {
+        // mov rax, [rip + 0x10]
+        let code = code(&[0x48, 0x8B, 0x05, 0x10, 0, 0, 0]);
+        let (target, trampoline, relocated) = relocated(&code);
+
+        let displacement = rel32(trampoline + 7, target + 7 + 0x10).unwrap();
+        let mut expected = vec![0x48, 0x8B, 0x05];
+        expected.extend(displacement.to_le_bytes());
+        expected.extend(jmp(trampoline + 7, target + 7));
+        assert_eq!(relocated.unwrap(), expected);
+    }
+
+    #[test]
+    fn rewrites_calls() {
+        let code = code(&[0xE8, 0x10, 0, 0, 0]);
+        let (target, trampoline, relocated) = relocated(&code);
+
+        let displacement = rel32(trampoline + 5, target + 5 + 0x10).unwrap();
+        let mut expected = vec![0xE8];
+        expected.extend(displacement.to_le_bytes());
+        expected.extend(jmp(trampoline + 5, target + 5));
+        assert_eq!(relocated.unwrap(), expected);
+    }
+
+    #[test]
+    fn widens_short_jumps() {
+        // jmp short +3, over padding the jump may cover
+        let code = code(&[0xEB, 0x03, 0x90, 0x90, 0x90]);
+        let (target, trampoline, relocated) = relocated(&code);
+
+        assert_eq!(relocated.unwrap(), jmp(trampoline, target + 5));
+    }
+
+    #[test]
+    fn widens_short_conditional_jumps() {
+        // je short +0x10; push rbp; mov rbp, rsp
+        let code = code(&[0x74, 0x10, 0x55, 0x48, 0x89, 0xE5]);
+        let (target, trampoline, relocated) = relocated(&code);
+
+        let displacement = rel32(trampoline + 6, target + 2 + 0x10).unwrap();
+        let mut expected = vec![0x0F, 0x84];
+        expected.extend(displacement.to_le_bytes());
+        expected.extend([0x55, 0x48, 0x89, 0xE5]);
+        expected.extend(jmp(trampoline + 10, target + 6));
+        assert_eq!(relocated.unwrap(), expected);
+    }
+
+    #[test]
+    fn rejects_what_cannot_be_relocated() {
+        for (bytes, reason) in [
+            (
+                &[0xEB, 0xFE][..],
+                "branch into the overwritten instructions",
+            ),
+            (
+                &[0x55, 0xE9, 0xFA, 0xFF, 0xFF, 0xFF],
+                "branch into the overwritten instructions",
+            ),
+            (
+                &[0xE2, 0x10, 0x90, 0x90, 0x90],
+                "short branch has no near form",
+            ),
+            (
+                &[0xC3, 0x00, 0x00, 0x00, 0x00],
+                "function shorter than a jump",
+            ),
+            (&[0xC4, 0xE4, 0x7D, 0x00, 0xC0], "unknown instruction"),
+        ] {
+            let code = code(bytes);
+            let (_, _, relocated) = self::relocated(&code);
+            assert!(
+                matches!(relocated, Err(ShroudError::Detour(_, r)) if r == reason),
+                "{bytes:02x?}: {relocated:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn relocates_functions_ending_in_padding() {
+        let code = code(&[0xC3, 0xCC, 0x0F, 0x1F, 0x00]);
+        let (_, _, relocated) = relocated(&code);
+        assert_eq!(relocated.unwrap(), [0xC3]);
+    }
+
+    /// Detours share the trampoline pool, which the tests below check the use of.
+    static POOL: Mutex<()> = Mutex::new(());
+
+    static ORIGINAL: AtomicUsize = AtomicUsize::new(0);
+
+    type Function = extern "C" fn(u32) -> u32;
+
+    #[inline(never)]
+    extern "C" fn tripled(x: u32) -> u32 {
+        std::hint::black_box(x).wrapping_mul(3)
+    }
+
+    extern "C" fn detoured(x: u32) -> u32 {
+        let original: Function = unsafe { std::mem::transmute(ORIGINAL.load(Ordering::Acquire)) };
+        original(x) + 1000
+    }
+
+    #[test]
+    fn detours_a_function_and_back() {
+        let _pool = POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let function = std::hint::black_box(tripled as Function);
+        assert_eq!(function(2), 6);
+
+        let detour =
+            unsafe { Detour::new(function as *const c_void, detoured as Function) }.unwrap();
+        ORIGINAL.store(detour.original() as usize, Ordering::Release);
+        assert_eq!(detour.target(), function as *const c_void);
+        assert_eq!(function(2), 1006);
+        assert_eq!((detour.original())(2), 6);
+
+        detour.uninstall().unwrap();
+        assert_eq!(function(2), 6);
+    }
+
+    #[test]
+    fn failed_detours_free_their_trampoline() {
+        let _pool = POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let code = code(&[0xC3, 0x00, 0x00, 0x00, 0x00]);
+        let near = code.as_ptr() as usize;
+
+        let before = memory::alloc(near, RELAY + RELAY_LEN).unwrap();
+        memory::free(before, RELAY + RELAY_LEN);
+
+        let result = unsafe { Detour::new(code.as_ptr().cast(), detoured as Function) };
+        assert!(matches!(result, Err(ShroudError::Detour(_, _))));
+
+        let after = memory::alloc(near, RELAY + RELAY_LEN).unwrap();
+        memory::free(after, RELAY + RELAY_LEN);
+        assert_eq!(after, before);
+    }
+}
@@ -0,0 +1,540 @@
+//! Length decoding of x86 and x86-64 instructions.
+//!
+//! Only as much of each instruction is understood as relocating it requires: its
+//! length, whether it addresses memory or branches relative to its own address,
+//! and whether execution can fall through to the next instruction.
+
+/// Longest instruction the architecture allows.
+pub(super) const MAX_LEN: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Instruction {
+    pub(super) len: usize,
+    /// Offset of the opcode, i.e. the number of prefix bytes.
+    pub(super) opcode: usize,
+    pub(super) relative: Option<Relative>,
+    /// Execution never continues with the next instruction, as after `ret` or `jmp`.
+    pub(super) terminal: bool,
+}
+
+/// An operand relative to the end of its instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Relative {
+    /// `[rip + disp32]` memory operand, the displacement at this offset. Only
+    /// exists in 64 bit mode.
+    Memory(usize),
+    /// Branch with a 32 bit displacement at this offset.
+    Branch32(usize),
+    /// Branch with an 8 bit displacement at this offset.
+    Branch8(usize),
+}
+
+/// Decodes the instruction at the start of `code`, `None` if it is invalid or
+/// not covered.
+pub(super) fn decode(code: &[u8]) -> Option<Instruction> {
+    decode_in(code, cfg!(target_arch = "x86_64"))
+}
+
+/// [`decode`] for 64 bit mode when `long` is set, 32 bit mode otherwise.
+fn decode_in(code: &[u8], long: bool) -> Option<Instruction> {
+    let code = &code[..code.len().min(MAX_LEN)];
+    let mut at = 0;
+
+    let mut operand16 = false;
+    let mut address_override = false;
+    while let prefix
+    @ (0x26 | 0x2E | 0x36 | 0x3E | 0x64 | 0x65 | 0x66 | 0x67 | 0xF0 | 0xF2 | 0xF3) =
+        *code.get(at)?
+    {
+        operand16 |= prefix == 0x66;
+        address_override |= prefix == 0x67;
+        at += 1;
+    }
+
+    let mut wide = false;
+    match *code.get(at)? {
+        rex @ 0x40..=0x4F if long => {
+            wide = rex & 0x08 != 0;
+            at += 1;
+        }
+        _ => {}
+    }
+
+    // Outside 64 bit mode the VEX and EVEX escapes double as `les`, `lds` and
+    // `bound`, which can not encode a register operand.
+    let vex = long || code.get(at + 1).is_some_and(|&next| next >= 0xC0);
+
+    let opcode = at;
+    let (map, op) = match *code.get(at)? {
+        0x0F => match *code.get(at + 1)? {
+            0x38 => (2, *code.get(at + 2)?),
+            0x3A => (3, *code.get(at + 2)?),
+            op => (1, op),
+        },
+        // Two byte VEX: one payload byte, implied 0F map.
+        0xC5 if vex => (VEX | 1, *code.get(at + 2)?),
+        // Three byte VEX: the map is in the low bits of the first payload byte.
+        0xC4 if vex => (
+            VEX | (code.get(at + 1)? & 0x1F) as usize,
+            *code.get(at + 3)?,
+        ),
+        // EVEX: three payload bytes, map in the low bits of the first.
+        0x62 if vex => (
+            VEX | (code.get(at + 1)? & 0x07) as usize,
+            *code.get(at + 4)?,
+        ),
+        op => (0, op),
+    };
+    at += match map {
+        0 | 1 => map + 1,
+        2 | 3 => 3,
+        _ => match code[opcode] {
+            0xC5 => 3,
+            0xC4 => 4,
+            _ => 5,
+        },
+    };
+
+    let operand = if operand16 && !wide { 2 } else { 4 };
+    let mut instruction = Instruction {
+        len: 0,
+        opcode,
+        relative: None,
+        terminal: false,
+    };
+
+    let (modrm, immediate) = match (map, op) {
+        (0, _) => match op {
+            0x00..=0x3F => match op & 0x07 {
+                0..=3 => (true, 0),
+                4 => (false, 1),
+                5 => (false, operand),
+                // Segment pushes and BCD adjusts, invalid in 64 bit mode.
+                _ if long => return None,
+                _ => (false, 0),
+            },
+            0x50..=0x5F | 0x6C..=0x6F | 0x90..=0x99 | 0x9B..=0x9F => (false, 0),
+            0xA4..=0xA7 | 0xAA..=0xAF | 0xC9 | 0xD7 | 0xEC..=0xEF | 0xF1 | 0xF4 | 0xF5 => {
+                (false, 0)
+            }
+            0xF8..=0xFD => (false, 0),
+            0x63 | 0x84..=0x8F | 0xD0..=0xD3 | 0xD8..=0xDF | 0xFE => (true, 0),
+            0x68 => (false, operand),
+            0x69 => (true, operand),
+            0x6A | 0xA8 | 0xB0..=0xB7 | 0xCD | 0xE4..=0xE7 => (false, 1),
+            0x6B | 0x80 | 0x83 | 0xC0 | 0xC1 | 0xC6 => (true, 1),
+            // `xbegin` branches to its abort handler.
+            0xC7 if *code.get(at)? == 0xF8 => {
+                instruction.relative = Some(Relative::Branch32(at + 1));
+                (true, operand)
+            }
+            0x81 | 0xC7 => (true, operand),
+            0x70..=0x7F | 0xE0..=0xE3 => {
+                instruction.relative = Some(Relative::Branch8(at));
+                (false, 1)
+            }
+            0xA0..=0xA3 => match (long, address_override) {
+                (true, false) => (false, 8),
+                (false, true) => (false, 2),
+                _ => (false, 4),
+            },
+            0xA9 => (false, operand),
+            0xB8..=0xBF => (false, if wide { 8 } else { operand }),
+            0xC2 | 0xCA => {
+                instruction.terminal = true;
+                (false, 2)
+            }
+            0xC3 | 0xCB | 0xCC | 0xCF => {
+                instruction.terminal = true;
+                (false, 0)
+            }
+            0xC8 => (false, 3),
+            0xE8 => {
+                instruction.relative = Some(Relative::Branch32(at));
+                (false, 4)
+            }
+            0xE9 => {
+                instruction.relative = Some(Relative::Branch32(at));
+                instruction.terminal = true;
+                (false, 4)
+            }
+            0xEB => {
+                instruction.relative = Some(Relative::Branch8(at));
+                instruction.terminal = true;
+                (false, 1)
+            }
+            // `test` takes an immediate, the other group 3 members do not.
+            0xF6 | 0xF7 => match (code.get(at)? >> 3) & 0x07 {
+                0 | 1 if op == 0xF6 => (true, 1),
+                0 | 1 => (true, operand),
+                _ => (true, 0),
+            },
+            // `jmp` and `jmp far` through memory leave the function.
+            0xFF => {
+                instruction.terminal = matches!((code.get(at)? >> 3) & 0x07, 4 | 5);
+                (true, 0)
+            }
+            // Only valid outside 64 bit mode.
+            0x40..=0x4F | 0x60 | 0x61 | 0xCE | 0xD6 if !long => (false, 0),
+            0x62 | 0xC4 | 0xC5 => (true, 0),
+            0x82 if !long => (true, 1),
+            0xD4 | 0xD5 if !long => (false, 1),
+            0x9A if !long => (false, operand + 2),
+            0xEA if !long => {
+                instruction.terminal = true;
+                (false, operand + 2)
+            }
+            _ => return None,
+        },
+        (1, _) => match op {
+            0x05..=0x09 | 0x0E | 0x30..=0x37 | 0xA0..=0xA2 | 0xA8..=0xAA | 0xC8..=0xCF => {
+                (false, 0)
+            }
+            0x0B => {
+                instruction.terminal = true;
+                (false, 0)
+            }
+            0x80..=0x8F => {
+                instruction.relative = Some(Relative::Branch32(at));
+                (false, 4)
+            }
+            0x0F | 0x70..=0x73 | 0xA4 | 0xAC | 0xBA | 0xC2 | 0xC4..=0xC6 => (true, 1),
+            _ => (true, 0),
+        },
+        (2, _) => (true, 0),
+        (3, _) => (true, 1),
+        (_, 0x77) if map == VEX | 1 => (false, 0),
+        (_, 0x70..=0x73 | 0xC2 | 0xC4..=0xC6) if map == VEX | 1 => (true, 1),
+        (_, _) if matches!(map & !VEX, 1 | 2 | 5 | 6) => (true, 0),
+        (_, _) if map & !VEX == 3 => (true, 1),
+        _ => return None,
+    };
+
+    // Branches shrink to rel16 with an operand size prefix outside 64 bit mode.
+    if !long && operand16 && matches!(instruction.relative, Some(Relative::Branch32(_))) {
+        return None;
+    }
+
+    if modrm {
+        let modrm = *code.get(at)?;
+        let (mode, rm) = (modrm >> 6, modrm & 0x07);
+        at += 1;
+        if mode != 3 && !long && address_override {
+            // 16 bit addressing has neither SIB bytes nor 32 bit displacements.
+            at += match (mode, rm) {
+                (0, 6) | (2, _) => 2,
+                (1, _) => 1,
+                _ => 0,
+            };
+        } else if mode != 3 {
+            if rm == 4 {
+                let sib = *code.get(at)?;
+                at += 1;
+                if mode == 0 && sib & 0x07 == 5 {
+                    at += 4;
+                }
+            } else if mode == 0 && rm == 5 {
+                // An absolute address outside 64 bit mode.
+                if long {
+                    instruction.relative = Some(Relative::Memory(at));
+                }
+                at += 4;
+            }
+            at += match mode {
+                1 => 1,
+                2 => 4,
+                _ => 0,
+            };
+        }
+    }
+
+    instruction.len = at + immediate;
+    (instruction.len <= code.len()).then_some(instruction)
+}
+
+/// Marks maps reached through a VEX or EVEX prefix.
+const VEX: usize = 0x100;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Length, relative operand and whether execution stops, per encoding.
+    type Case = (&'static [u8], usize, Option<Relative>, bool);
+
+    fn check(cases: &[Case], long: bool) {
+        for &(code, len, relative, terminal) in cases {
+            let instruction =
+                decode_in(code, long).unwrap_or_else(|| panic!("{code:02x?} did not decode"));
+            assert_eq!(
+                (instruction.len, instruction.relative, instruction.terminal),
+                (len, relative, terminal),
+                "{code:02x?}"
+            );
+        }
+    }
+
+    #[test]
+    fn decodes_prologues() {
+        check(
+            &[
+                (&[0x55], 1, None, false),                                // push rbp
+                (&[0x48, 0x89, 0xE5], 3, None, false),                    // mov rbp, rsp
+                (&[0x48, 0x83, 0xEC, 0x20], 4, None, false),              // sub rsp, 0x20
+                (&[0x48, 0x81, 0xEC, 0, 1, 0, 0], 7, None, false),        // sub rsp, 0x100
+                (&[0x48, 0x89, 0x5C, 0x24, 0x08], 5, None, false),        // mov [rsp + 8], rbx
+                (&[0xF3, 0x0F, 0x1E, 0xFA], 4, None, false),              // endbr64
+                (&[0x40, 0x53], 2, None, false),                          // push rbx
+                (&[0x66, 0x0F, 0x1F, 0x44, 0, 0], 6, None, false),        // nop word [rax + rax]
+                (&[0x48, 0xB8, 1, 2, 3, 4, 5, 6, 7, 8], 10, None, false), // mov rax, imm64
+                (&[0x66, 0xB8, 1, 2], 4, None, false),                    // mov ax, imm16
+                (&[0xF6, 0xC1, 0x01], 3, None, false),                    // test cl, 1
+                (&[0xF7, 0xD8], 2, None, false),                          // neg eax
+                (&[0x0F, 0xB6, 0xC0], 3, None, false),                    // movzx eax, al
+                (&[0x66, 0x0F, 0x3A, 0x0F, 0xC1, 0x08], 6, None, false),  // palignr
+            ],
+            true,
+        );
+    }
+
+    #[test]
+    fn decodes_rip_relative_operands() {
+        check(
+            &[
+                // mov rax, [rip + 0x10]
+                (
+                    &[0x48, 0x8B, 0x05, 0x10, 0, 0, 0],
+                    7,
+                    Some(Relative::Memory(3)),
+                    false,
+                ),
+                // lea rcx, [rip - 0x10]
+                (
+                    &[0x48, 0x8D, 0x0D, 0xF0, 0xFF, 0xFF, 0xFF],
+                    7,
+                    Some(Relative::Memory(3)),
+                    false,
+                ),
+                // mov qword [rip + 0x10], 1
+                (
+                    &[0x48, 0xC7, 0x05, 0x10, 0, 0, 0, 1, 0, 0, 0],
+                    11,
+                    Some(Relative::Memory(3)),
+                    false,
+                ),
+                // cmp byte [rip + 0x10], 0 behind a segment prefix
+                (
+                    &[0x65, 0x80, 0x3D, 0x10, 0, 0, 0, 0],
+                    8,
+                    Some(Relative::Memory(3)),
+                    false,
+                ),
+                // jmp [rip]
+                (
+                    &[0xFF, 0x25, 0, 0, 0, 0],
+                    6,
+                    Some(Relative::Memory(2)),
+                    true,
+                ),
+                // call [rip + 0x10]
+                (
+                    &[0xFF, 0x15, 0x10, 0, 0, 0],
+                    6,
+                    Some(Relative::Memory(2)),
+                    false,
+                ),
+                // [disp32] through a SIB byte is absolute, not RIP-relative.
+                (&[0x8B, 0x04, 0x25, 0x10, 0, 0, 0], 7, None, false),
+            ],
+            true,
+        );
+    }
+
+    #[test]
+    fn decodes_branches() {
+        check(
+            &[
+                (&[0xE8, 0, 0, 0, 0], 5, Some(Relative::Branch32(1)), false), // call
+                (&[0xE9, 0, 0, 0, 0], 5, Some(Relative::Branch32(1)), true),  // jmp
+                (
+                    &[0x0F, 0x84, 0, 0, 0, 0],
+                    6,
+                    Some(Relative::Branch32(2)),
+                    false,
+                ), // je
+                (&[0xEB, 0x05], 2, Some(Relative::Branch8(1)), true),         // jmp short
+                (&[0x74, 0x02], 2, Some(Relative::Branch8(1)), false),        // je short
+                (&[0xE2, 0xFE], 2, Some(Relative::Branch8(1)), false),        // loop
+                (&[0xE3, 0x02], 2, Some(Relative::Branch8(1)), false),        // jrcxz
+                (
+                    &[0xC7, 0xF8, 0, 0, 0, 0],
+                    6,
+                    Some(Relative::Branch32(2)),
+                    false,
+                ), // xbegin
+                (&[0xFF, 0xE0], 2, None, true),                               // jmp rax
+                (&[0xFF, 0xD0], 2, None, false),                              // call rax
+                (&[0xC3], 1, None, true),                                     // ret
+                (&[0xC2, 0x08, 0], 3, None, true),                            // ret 8
+                (&[0xCC], 1, None, true),                                     // int3
+                (&[0x0F, 0x0B], 2, None, true),                               // ud2
+            ],
+            true,
+        );
+    }
+
+    #[test]
+    fn decodes_vex_and_evex() {
+        check(
+            &[
+                // vzeroupper
+                (&[0xC5, 0xF8, 0x77], 3, None, false),
+                // vmovaps ymm0, ymm1
+                (&[0xC5, 0xFC, 0x28, 0xC1], 4, None, false),
+                // vmovaps ymm0, [rip + 0x10]
+                (
+                    &[0xC5, 0xFC, 0x28, 0x05, 0x10, 0, 0, 0],
+                    8,
+                    Some(Relative::Memory(4)),
+                    false,
+                ),
+                // vpshufd xmm0, xmm1, 0x1b
+                (&[0xC5, 0xF9, 0x70, 0xC1, 0x1B], 5, None, false),
+                // vbroadcastss ymm0, [rip + 0x10]
+                (
+                    &[0xC4, 0xE2, 0x7D, 0x18, 0x05, 0x10, 0, 0, 0],
+                    9,
+                    Some(Relative::Memory(5)),
+                    false,
+                ),
+                // vinsertf128 ymm0, ymm0, xmm1, 1
+                (&[0xC4, 0xE3, 0x7D, 0x18, 0xC1, 0x01], 6, None, false),
+                // vmovaps zmm0, zmm1
+                (&[0x62, 0xF1, 0x7C, 0x48, 0x28, 0xC1], 6, None, false),
+                // vmovaps zmm0, [rip + 0x10]
+                (
+                    &[0x62, 0xF1, 0x7C, 0x48, 0x28, 0x05, 0x10, 0, 0, 0],
+                    10,
+                    Some(Relative::Memory(6)),
+                    false,
+                ),
+            ],
+            true,
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_and_truncated_code() {
+        for code in [
+            &[][..],
+            &[0x06],                   // push es, invalid in 64 bit mode
+            &[0x48, 0x8B],             // missing ModRM
+            &[0x48, 0x8B, 0x05, 0x10], // missing displacement bytes
+            &[0xE8, 0, 0],             // missing immediate bytes
+            &[0x66; 16],               // prefixes only, longer than any instruction
+            &[0xEA, 0, 0, 0, 0, 0, 0], // jmp far, invalid in 64 bit mode
+        ] {
+            assert_eq!(decode_in(code, true), None, "{code:02x?}");
+        }
+    }
+
+    #[test]
+    fn records_the_opcode_offset() {
+        let instruction = decode_in(&[0x66, 0x2E, 0x0F, 0x1F, 0x84, 0, 0, 0, 0, 0], true).unwrap();
+        assert_eq!((instruction.opcode, instruction.len), (2, 10));
+        let instruction = decode_in(&[0x48, 0x8B, 0xC1], true).unwrap();
+        assert_eq!(instruction.opcode, 1);
+    }
+
+    #[test]
+    fn decodes_32_bit_prologues() {
+        check(
+            &[
+                (&[0x8B, 0xFF], 2, None, false),             // mov edi, edi
+                (&[0x55], 1, None, false),                   // push ebp
+                (&[0x8B, 0xEC], 2, None, false),             // mov ebp, esp
+                (&[0x83, 0xEC, 0x10], 3, None, false),       // sub esp, 0x10
+                (&[0x8B, 0x04, 0x24], 3, None, false),       // mov eax, [esp]
+                (&[0x8B, 0x45, 0x08], 3, None, false),       // mov eax, [ebp + 8]
+                (&[0x81, 0xEC, 0, 1, 0, 0], 6, None, false), // sub esp, 0x100
+                (&[0x66, 0xB8, 1, 2], 4, None, false),       // mov ax, imm16
+                (&[0xB8, 1, 2, 3, 4], 5, None, false),       // mov eax, imm32
+            ],
+            false,
+        );
+    }
+
+    #[test]
+    fn decodes_32_bit_only_opcodes() {
+        check(
+            &[
+                (&[0x40], 1, None, false), // inc eax, a REX prefix in 64 bit mode
+                (&[0x48, 0x8B, 0xC1], 1, None, false), // dec eax
+                (&[0x06], 1, None, false), // push es
+                (&[0x27], 1, None, false), // daa
+                (&[0x60], 1, None, false), // pushad
+                (&[0xD4, 0x0A], 2, None, false), // aam
+                (&[0x82, 0xC0, 0x01], 3, None, false), // add al, 1
+                (&[0x9A, 1, 2, 3, 4, 5, 6], 7, None, false), // call far
+                (&[0xEA, 1, 2, 3, 4, 5, 6], 7, None, true), // jmp far
+                (&[0xC4, 0x06], 2, None, false), // les eax, [esi]
+                (&[0xC5, 0x06], 2, None, false), // lds eax, [esi]
+                (&[0x62, 0x06], 2, None, false), // bound eax, [esi]
+            ],
+            false,
+        );
+    }
+
+    #[test]
+    fn decodes_32_bit_addressing() {
+        check(
+            &[
+                // mov eax, [0x1234], absolute rather than RIP-relative
+                (&[0x8B, 0x05, 0x34, 0x12, 0, 0], 6, None, false),
+                (&[0xA1, 0x34, 0x12, 0, 0], 5, None, false),
+                // jmp [0x1234], through an import table
+                (&[0xFF, 0x25, 0x34, 0x12, 0, 0], 6, None, true),
+                // 16 bit addressing: [0x1234], [bx + 0x10] and [bx + 0x1234]
+                (&[0x67, 0x8B, 0x06, 0x34, 0x12], 5, None, false),
+                (&[0x67, 0x8B, 0x47, 0x10], 4, None, false),
+                (&[0x67, 0x8B, 0x87, 0x34, 0x12], 5, None, false),
+                (&[0x67, 0xA1, 0x34, 0x12], 4, None, false),
+            ],
+            false,
+        );
+    }
+
+    #[test]
+    fn decodes_32_bit_branches_and_vex() {
+        check(
+            &[
+                (&[0xE8, 0, 0, 0, 0], 5, Some(Relative::Branch32(1)), false),
+                (
+                    &[0x0F, 0x84, 0, 0, 0, 0],
+                    6,
+                    Some(Relative::Branch32(2)),
+                    false,
+                ),
+                (&[0xEB, 0x05], 2, Some(Relative::Branch8(1)), true),
+                (&[0x74, 0x02], 2, Some(Relative::Branch8(1)), false),
+                (&[0xC2, 0x08, 0], 3, None, true),
+                (&[0xC5, 0xF8, 0x77], 3, None, false),
+                (&[0xC4, 0xE3, 0x7D, 0x18, 0xC1, 0x01], 6, None, false),
+            ],
+            false,
+        );
+
+        // Operand size prefixed branches are rel16, which are not supported.
+        assert_eq!(decode_in(&[0x66, 0xE8, 0, 0], false), None);
+        assert_eq!(decode_in(&[0xC4, 0xE4, 0x7D, 0x00, 0xC0], false), None);
+    }
+
+    #[test]
+    fn decodes_in_the_host_mode() {
+        let code = [0x48, 0x8B, 0xC1];
+        assert_eq!(
+            decode(&code),
+            decode_in(&code, cfg!(target_arch = "x86_64"))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Executable memory within reach of a rel32 jump from the code it serves.
+
+use std::sync::Mutex;
+
+use crate::{ShroudError, ShroudResult};
+
+/// Size of each mapping the pool carves trampolines from, the allocation
+/// granularity on Windows.
+const CHUNK: usize = 0x10000;
+
+/// Farthest a chunk may start from the code it serves, leaving room for the
+/// chunk itself within a rel32 jump.
+const REACH: usize = i32::MAX as usize - CHUNK;
+
+struct Chunk {
+    base: usize,
+    used: usize,
+}
+
+/// Chunks are mapped read and execute only, and written through
+/// [`crate::protect::patch`]. They are never unmapped, as a thread may still be
+/// running in a trampoline long after its detour is removed.
+static CHUNKS: Mutex<Vec<Chunk>> = Mutex::new(Vec::new());
+
+/// Reserves `len` bytes of executable memory within ±2GB of `near`, aligned to 16.
+pub(super) fn alloc(near: usize, len: usize) -> ShroudResult<usize> {
+    let len = len.next_multiple_of(16);
+    let mut chunks = CHUNKS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let free = chunks
+        .iter_mut()
+        .find(|chunk| reachable(chunk.base, near) && CHUNK - chunk.used >= len);
+    if let Some(chunk) = free {
+        let address = chunk.base + chunk.used;
+        chunk.used += len;
+        return Ok(address);
+    }
+
+    let base =
+        unsafe { map_near(near) }.ok_or(ShroudError::Detour(near, "no free memory within 2GB"))?;
+    chunks.push(Chunk { base, used: len });
+    Ok(base)
+}
+
+/// Gives back `len` bytes at `address`, the latest allocation of its chunk, for
+/// detours that fail before their trampoline is used. Trampolines in use are
+/// never freed, see [`CHUNKS`].
+pub(super) fn free(address: usize, len: usize) {
+    let len = len.next_multiple_of(16);
+    let mut chunks = CHUNKS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let owner = chunks
+        .iter_mut()
+        .find(|chunk| address >= chunk.base && chunk.base + chunk.used == address + len);
+    if let Some(chunk) = owner {
+        chunk.used -= len;
+    }
+}
+
+/// Whether a chunk at `base` can be jumped to and from `near` with a rel32. On
+/// 32 bit x86 the displacement wraps around the address space, reaching anywhere.
+fn reachable(base: usize, near: usize) -> bool {
+    cfg!(target_arch = "x86") || base.abs_diff(near) < REACH
+}
+
+/// Candidate chunk addresses, nearest to `near` first.
+fn candidates(near: usize, step: usize) -> impl Iterator<Item = usize> {
+    let origin = near & !(CHUNK - 1);
+    (1..REACH / step).flat_map(move |i| {
+        [origin.checked_sub(i * step), origin.checked_add(i * step)]
+            .into_iter()
+            .flatten()
+            .filter(move |&address| address != 0 && address.abs_diff(near) < REACH)
+    })
+}
+
+#[cfg(windows)]
+unsafe fn map_near(near: usize) -> Option<usize> {
+    use windows::Win32::System::Memory::{
+        VirtualAlloc, VirtualQuery, MEMORY_BASIC_INFORMATION, MEM_COMMIT, MEM_FREE, MEM_RESERVE,
+        PAGE_EXECUTE_READ,
+    };
+
+    for address in candidates(near, CHUNK) {
+        let mut info = MEMORY_BASIC_INFORMATION::default();
+        let size = std::mem::size_of::<MEMORY_BASIC_INFORMATION>();
+        if VirtualQuery(Some(address as *const _), &mut info, size) == 0 || info.State != MEM_FREE {
+            continue;
+        }
+
+        let chunk = VirtualAlloc(
+            Some(address as *const _),
+            CHUNK,
+            MEM_COMMIT | MEM_RESERVE,
+            PAGE_EXECUTE_READ,
+        );
+        if !chunk.is_null() {
+            return Some(chunk as usize);
+        }
+    }
+    None
+}
+
+#[cfg(unix)]
+unsafe fn map_near(near: usize) -> Option<usize> {
+    // Without MAP_FIXED the kernel takes the hint only if it is free, so a miss
+    // maps elsewhere and is given back.
+    for address in candidates(near, CHUNK * 16) {
+        let chunk = libc::mmap(
+            address as *mut _,
+            CHUNK,
+            libc::PROT_READ | libc::PROT_EXEC,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        if chunk == libc::MAP_FAILED {
+            continue;
+        }
+        if reachable(chunk as usize, near) {
+            return Some(chunk as usize);
+        }
+        libc::munmap(chunk, CHUNK);
+    }
+    None
+}
+
+#[cfg(not(any(windows, unix)))]
+unsafe fn map_near(_near: usize) -> Option<usize> {
+    None
+}
@@ -0,0 +1,431 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::snapshot::{Snapshot, SnapshotEntry};
+
+/// Everything that changed between two [`Snapshot`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SnapshotDiff {
+    /// Modules present in both snapshots whose version changed.
+    pub versions: Vec<VersionChange>,
+    /// Per-slot changes, ordered by interface and slot.
+    pub slots: Vec<SlotChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VersionChange {
+    pub module: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SlotChange {
+    pub interface: String,
+    pub slot: usize,
+    /// Method name in the newer snapshot, or the older one for removed slots.
+    pub method: String,
+    pub kind: SlotChangeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SlotChangeKind {
+    Added,
+    Removed,
+    /// The slot now names a different method.
+    Renamed {
+        from: String,
+    },
+    /// Another module owns the method, e.g. `Present` moved from dxgi.dll to an overlay.
+    Module {
+        from: Option<String>,
+        to: Option<String>,
+        from_rva: Option<u64>,
+        to_rva: Option<u64>,
+    },
+    /// Same module, different offset.
+    Rva {
+        module: Option<String>,
+        from: Option<u64>,
+        to: Option<u64>,
+    },
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty() && self.slots.is_empty()
+    }
+}
+
+/// Compares two snapshots, `a` being the older one.
+pub fn diff(a: &Snapshot, b: &Snapshot) -> SnapshotDiff {
+    SnapshotDiff {
+        versions: version_changes(a, b),
+        slots: slot_changes(a, b),
+    }
+}
+
+fn version_changes(a: &Snapshot, b: &Snapshot) -> Vec<VersionChange> {
+    fn versions(snapshot: &Snapshot) -> BTreeMap<&str, Option<&str>> {
+        snapshot
+            .entries
+            .iter()
+            .filter_map(|entry| Some((entry.module.as_deref()?, entry.module_version.as_deref())))
+            .collect()
+    }
+
+    let (old, new) = (versions(a), versions(b));
+    old.iter()
+        .filter_map(|(module, from)| {
+            let to = new.get(module)?;
+            (from != to).then(|| VersionChange {
+                module: module.to_string(),
+                from: from.map(str::to_owned),
+                to: to.map(str::to_owned),
+            })
+        })
+        .collect()
+}
+
+fn slot_changes(a: &Snapshot, b: &Snapshot) -> Vec<SlotChange> {
+    fn slots(snapshot: &Snapshot) -> BTreeMap<(&str, usize), &SnapshotEntry> {
+        snapshot
+            .entries
+            .iter()
+            .map(|entry| ((entry.interface.as_str(), entry.slot), entry))
+            .collect()
+    }
+
+    let (old, new) = (slots(a), slots(b));
+    let mut keys: Vec<(&str, usize)> = old.keys().chain(new.keys()).copied().collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    let mut changes = Vec::new();
+    for key in keys {
+        let change = |method: &str, kind| SlotChange {
+            interface: key.0.to_owned(),
+            slot: key.1,
+            method: method.to_owned(),
+            kind,
+        };
+
+        match (old.get(&key), new.get(&key)) {
+            (None, Some(to)) => changes.push(change(&to.method, SlotChangeKind::Added)),
+            (Some(from), None) => changes.push(change(&from.method, SlotChangeKind::Removed)),
+            (Some(from), Some(to)) => {
+                if from.method != to.method {
+                    changes.push(change(
+                        &to.method,
+                        SlotChangeKind::Renamed {
+                            from: from.method.clone(),
+                        },
+                    ));
+                }
+
+                if from.module != to.module {
+                    changes.push(change(
+                        &to.method,
+                        SlotChangeKind::Module {
+                            from: from.module.clone(),
+                            to: to.module.clone(),
+                            from_rva: from.rva,
+                            to_rva: to.rva,
+                        },
+                    ));
+                } else if from.rva != to.rva {
+                    changes.push(change(
+                        &to.method,
+                        SlotChangeKind::Rva {
+                            module: to.module.clone(),
+                            from: from.rva,
+                            to: to.rva,
+                        },
+                    ));
+                }
+            }
+            (None, None) => unreachable!("key taken from one of the snapshots"),
+        }
+    }
+    changes
+}
+
+struct Place<'a>(Option<&'a str>, Option<u64>);
+
+impl std::fmt::Display for Place<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.0, self.1) {
+            (Some(module), Some(rva)) => write!(f, "{}+{:#x}", module, rva),
+            (Some(module), None) => write!(f, "{}", module),
+            (None, Some(rva)) => write!(f, "{:#x}", rva),
+            (None, None) => write!(f, "<unknown>"),
+        }
+    }
+}
+
+struct Version<'a>(Option<&'a str>);
+
+impl std::fmt::Display for Version<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.unwrap_or("<unknown>"))
+    }
+}
+
+impl std::fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+
+        if !self.versions.is_empty() {
+            writeln!(f, "Module versions")?;
+            for change in &self.versions {
+                writeln!(
+                    f,
+                    "\t{} {} -> {}",
+                    change.module,
+                    Version(change.from.as_deref()),
+                    Version(change.to.as_deref())
+                )?;
+            }
+        }
+
+        if !self.slots.is_empty() {
+            writeln!(f, "Slots")?;
+            for change in &self.slots {
+                let slot = format!("{}[{}] {}", change.interface, change.slot, change.method);
+                match &change.kind {
+                    SlotChangeKind::Added => writeln!(f, "\t+ {}", slot)?,
+                    SlotChangeKind::Removed => writeln!(f, "\t- {}", slot)?,
+                    SlotChangeKind::Renamed { from } => {
+                        writeln!(f, "\t~ {} renamed from {}", slot, from)?
+                    }
+                    SlotChangeKind::Module {
+                        from,
+                        to,
+                        from_rva,
+                        to_rva,
+                    } => writeln!(
+                        f,
+                        "\t~ {} moved {} -> {}",
+                        slot,
+                        Place(from.as_deref(), *from_rva),
+                        Place(to.as_deref(), *to_rva)
+                    )?,
+                    SlotChangeKind::Rva { module, from, to } => writeln!(
+                        f,
+                        "\t~ {} {} -> {}",
+                        slot,
+                        Place(module.as_deref(), *from),
+                        Place(module.as_deref(), *to)
+                    )?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RenderEngine;
+
+    fn entry(slot: usize, method: &str, module: &str, version: &str, rva: u64) -> SnapshotEntry {
+        SnapshotEntry {
+            interface: "IDXGISwapChain".to_owned(),
+            slot,
+            method: method.to_owned(),
+            module: Some(module.to_owned()),
+            module_version: Some(version.to_owned()),
+            rva: Some(rva),
+        }
+    }
+
+    fn snapshot(entries: Vec<SnapshotEntry>) -> Snapshot {
+        Snapshot {
+            entries,
+            ..Snapshot::new(RenderEngine::DirectX11)
+        }
+    }
+
+    fn base() -> Vec<SnapshotEntry> {
+        vec![
+            entry(8, "Present", "dxgi.dll", "10.0.1", 0x1000),
+            entry(13, "ResizeBuffers", "dxgi.dll", "10.0.1", 0x2000),
+        ]
+    }
+
+    fn slots(a: Vec<SnapshotEntry>, b: Vec<SnapshotEntry>) -> Vec<SlotChange> {
+        diff(&snapshot(a), &snapshot(b)).slots
+    }
+
+    #[test]
+    fn equal_snapshots_have_no_differences() {
+        let diff = diff(&snapshot(base()), &snapshot(base()));
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "No differences\n");
+    }
+
+    #[test]
+    fn finds_added_slots() {
+        let mut b = base();
+        b.push(entry(14, "ResizeTarget", "dxgi.dll", "10.0.1", 0x3000));
+        let changes = slots(base(), b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].slot, 14);
+        assert_eq!(changes[0].method, "ResizeTarget");
+        assert_eq!(changes[0].kind, SlotChangeKind::Added);
+    }
+
+    #[test]
+    fn finds_removed_slots() {
+        let mut b = base();
+        b.pop();
+        let changes = slots(base(), b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].method, "ResizeBuffers");
+        assert_eq!(changes[0].kind, SlotChangeKind::Removed);
+    }
+
+    #[test]
+    fn finds_renamed_slots() {
+        let mut b = base();
+        b[0].method = "Present1".to_owned();
+        let changes = slots(base(), b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].method, "Present1");
+        assert_eq!(
+            changes[0].kind,
+            SlotChangeKind::Renamed {
+                from: "Present".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn finds_slots_moved_to_another_module() {
+        let mut b = base();
+        b[0].module = Some("overlay.dll".to_owned());
+        b[0].rva = Some(0x42);
+        let changes = slots(base(), b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].kind,
+            SlotChangeKind::Module {
+                from: Some("dxgi.dll".to_owned()),
+                to: Some("overlay.dll".to_owned()),
+                from_rva: Some(0x1000),
+                to_rva: Some(0x42),
+            }
+        );
+    }
+
+    #[test]
+    fn finds_moved_rvas() {
+        let mut b = base();
+        b[1].rva = Some(0x2040);
+        let changes = slots(base(), b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].slot, 13);
+        assert_eq!(
+            changes[0].kind,
+            SlotChangeKind::Rva {
+                module: Some("dxgi.dll".to_owned()),
+                from: Some(0x2000),
+                to: Some(0x2040),
+            }
+        );
+    }
+
+    #[test]
+    fn renames_and_moves_are_both_reported() {
+        let mut b = base();
+        b[0].method = "Present1".to_owned();
+        b[0].rva = Some(0x1100);
+        let kinds: Vec<SlotChangeKind> = slots(base(), b).into_iter().map(|c| c.kind).collect();
+        assert!(matches!(
+            kinds[..],
+            [SlotChangeKind::Renamed { .. }, SlotChangeKind::Rva { .. }]
+        ));
+    }
+
+    #[test]
+    fn finds_version_changes_of_shared_modules() {
+        let mut a = base();
+        a.push(entry(0, "QueryInterface", "gone.dll", "1.0", 0x10));
+        let mut b: Vec<SnapshotEntry> = base()
+            .into_iter()
+            .map(|entry| SnapshotEntry {
+                module_version: Some("10.0.2".to_owned()),
+                ..entry
+            })
+            .collect();
+        b.push(entry(1, "AddRef", "new.dll", "2.0", 0x20));
+
+        let diff = diff(&snapshot(a), &snapshot(b));
+        assert_eq!(
+            diff.versions,
+            vec![VersionChange {
+                module: "dxgi.dll".to_owned(),
+                from: Some("10.0.1".to_owned()),
+                to: Some("10.0.2".to_owned()),
+            }]
+        );
+    }
+
+    #[test]
+    fn displays_every_change() {
+        let mut a = base();
+        a.push(entry(2, "Release", "dxgi.dll", "10.0.1", 0x500));
+        let mut b = base();
+        b[0].module = Some("overlay.dll".to_owned());
+        b[0].module_version = None;
+        b[0].rva = None;
+        b[1].method = "ResizeBuffers1".to_owned();
+        b[1].rva = Some(0x2040);
+        b[1].module_version = Some("10.0.2".to_owned());
+        b.push(entry(14, "ResizeTarget", "dxgi.dll", "10.0.2", 0x3000));
+
+        assert_eq!(
+            diff(&snapshot(a), &snapshot(b)).to_string(),
+            "Module versions\n\
+             \tdxgi.dll 10.0.1 -> 10.0.2\n\
+             Slots\n\
+             \t- IDXGISwapChain[2] Release\n\
+             \t~ IDXGISwapChain[8] Present moved dxgi.dll+0x1000 -> overlay.dll\n\
+             \t~ IDXGISwapChain[13] ResizeBuffers1 renamed from ResizeBuffers\n\
+             \t~ IDXGISwapChain[13] ResizeBuffers1 dxgi.dll+0x2000 -> dxgi.dll+0x2040\n\
+             \t+ IDXGISwapChain[14] ResizeTarget\n"
+        );
+    }
+
+    #[test]
+    fn displays_unknown_places_and_versions() {
+        let mut a = base();
+        a[0].module = None;
+        a[0].rva = Some(0x1000);
+        a[1].module_version = None;
+        let mut b = base();
+        b[0].rva = None;
+        b[0].module = Some("dxgi.dll".to_owned());
+        b[1].module_version = None;
+        let mut c = base();
+        c[1].module_version = Some("10.0.2".to_owned());
+
+        let text = diff(&snapshot(a.clone()), &snapshot(b)).to_string();
+        assert!(
+            text.contains("Present moved 0x1000 -> dxgi.dll\n"),
+            "{text}"
+        );
+        let text = diff(&snapshot(a), &snapshot(c)).to_string();
+        assert!(text.contains("\tdxgi.dll <unknown> -> 10.0.2\n"), "{text}");
+    }
+}
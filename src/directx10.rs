@@ -1 +1,326 @@
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
+use windows::Win32::{
+    Foundation::{HMODULE, HWND},
+    Graphics::{
+        Direct3D10::{
+            D3D10CreateDevice1, D3D10CreateDeviceAndSwapChain, ID3D10Device, ID3D10Device1,
+            D3D10_DRIVER_TYPE, D3D10_DRIVER_TYPE_HARDWARE, D3D10_DRIVER_TYPE_NULL,
+            D3D10_DRIVER_TYPE_REFERENCE, D3D10_DRIVER_TYPE_WARP, D3D10_FEATURE_LEVEL1,
+            D3D10_SDK_VERSION,
+        },
+        Dxgi::IDXGISwapChain,
+    },
+};
 
+#[cfg(windows)]
+use crate::{
+    discovery::{self, Attempt, DriverType},
+    dxgi,
+    swapchain_util::default_swapchain_descriptor,
+    window::{ProvidedWindow, WindowProvider},
+    DiscoveryOptions, WindowSelector,
+};
+use crate::{
+    dxgi::DxgiSwapchainVmt, snapshot::snapshot_serde, MethodTable, RenderEngine, ShroudError,
+    ShroudResult, Snapshot,
+};
+
+mod methods;
+pub use methods::*;
+
+/// `D3D10_1_SDK_VERSION` from d3d10_1.h, which the Windows metadata does not carry.
+#[cfg(windows)]
+const D3D10_1_SDK_VERSION: u32 = 0x20;
+
+pub struct DirectX10Methods {
+    swapchain_vmt: MethodTable<DirectX10SwapchainMethods>,
+    latest_swapchain_vmt: Option<DxgiSwapchainVmt>,
+    device_vmt: MethodTable<DirectX10DeviceMethods>,
+    device1_vmt: MethodTable<DirectX10Device1Methods>,
+}
+
+impl DirectX10Methods {
+    pub fn swapchain_vmt(&self) -> &MethodTable<DirectX10SwapchainMethods> {
+        &self.swapchain_vmt
+    }
+
+    /// The swap chain's table at the highest `IDXGISwapChain` version it supports,
+    /// `None` if it is only an `IDXGISwapChain`.
+    pub fn latest_swapchain_vmt(&self) -> Option<&DxgiSwapchainVmt> {
+        self.latest_swapchain_vmt.as_ref()
+    }
+
+    /// Table of a d3d10.dll device.
+    pub fn device_vmt(&self) -> &MethodTable<DirectX10DeviceMethods> {
+        &self.device_vmt
+    }
+
+    /// Table of a d3d10_1.dll device.
+    pub fn device1_vmt(&self) -> &MethodTable<DirectX10Device1Methods> {
+        &self.device1_vmt
+    }
+}
+
+impl DirectX10Methods {
+    /// Reads the tables of a swap chain, a d3d10.dll device and a d3d10_1.dll
+    /// device.
+    ///
+    /// # Safety
+    ///
+    /// See [`vtable::read`](crate::vtable::read), for each object.
+    #[cfg(any(windows, test))]
+    unsafe fn read(
+        swapchain: *const std::ffi::c_void,
+        latest_swapchain_vmt: Option<DxgiSwapchainVmt>,
+        device: *const std::ffi::c_void,
+        device1: *const std::ffi::c_void,
+    ) -> ShroudResult<Self> {
+        Ok(Self {
+            swapchain_vmt: crate::vtable::read(swapchain)?,
+            latest_swapchain_vmt,
+            device_vmt: crate::vtable::read(device)?,
+            device1_vmt: crate::vtable::read(device1)?,
+        })
+    }
+
+    /// Host-independent record of every table, see [`Snapshot`].
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new(RenderEngine::DirectX10);
+        snapshot.push(&self.swapchain_vmt);
+        if let Some(vmt) = &self.latest_swapchain_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot.push(&self.device_vmt);
+        snapshot.push(&self.device1_vmt);
+        snapshot
+    }
+}
+
+impl TryFrom<&Snapshot> for DirectX10Methods {
+    type Error = ShroudError;
+
+    fn try_from(snapshot: &Snapshot) -> ShroudResult<Self> {
+        snapshot.expect(RenderEngine::DirectX10)?;
+        Ok(Self {
+            swapchain_vmt: snapshot.required_table()?,
+            latest_swapchain_vmt: DxgiSwapchainVmt::from_snapshot(snapshot)?,
+            device_vmt: snapshot.required_table()?,
+            device1_vmt: snapshot.required_table()?,
+        })
+    }
+}
+
+snapshot_serde!(DirectX10Methods);
+
+impl std::fmt::Debug for DirectX10Methods {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "DirectX10 Method Table")?;
+
+        writeln!(f, "Swapchain Virtual Method Table")?;
+        write!(f, "{:?}", self.swapchain_vmt)?;
+        writeln!(f)?;
+
+        if let Some(vmt) = &self.latest_swapchain_vmt {
+            writeln!(f, "Swapchain{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Device Virtual Method Table")?;
+        write!(f, "{:?}", self.device_vmt)?;
+        writeln!(f)?;
+
+        writeln!(f, "Device1 Virtual Method Table")?;
+        write!(f, "{:?}", self.device1_vmt)?;
+        writeln!(f)?;
+
+        Ok(())
+    }
+}
+
+/// Discovers every table, creating the device for the window
+/// [`WindowSelector::default`] picks.
+#[cfg(windows)]
+pub fn methods() -> ShroudResult<DirectX10Methods> {
+    methods_for(&WindowSelector::default())
+}
+
+/// [`methods`], creating the device for the window `window` provides, such as one
+/// [`WindowSelector`] picks or a [`crate::TemporaryWindow`].
+#[cfg(windows)]
+pub fn methods_for(window: &impl WindowProvider) -> ShroudResult<DirectX10Methods> {
+    methods_with(window, &DiscoveryOptions::default())
+}
+
+/// [`methods_for`], creating the devices with the driver, adapter and
+/// `ID3D10Device1` feature levels `options` ask for, and falling back along its
+/// plan.
+#[cfg(windows)]
+pub fn methods_with(
+    window: &impl WindowProvider,
+    options: &DiscoveryOptions,
+) -> ShroudResult<DirectX10Methods> {
+    let window = window.acquire()?;
+    let hwnd = HWND(window.handle() as *mut _);
+    let adapters = dxgi::adapters().unwrap_or_default();
+    let attempts = options.plan(RenderEngine::DirectX10, &adapters);
+    discovery::retry(RenderEngine::DirectX10, attempts, |attempt| {
+        discover(hwnd, attempt)
+    })
+}
+
+#[cfg(windows)]
+fn discover(hwnd: HWND, attempt: &Attempt) -> ShroudResult<DirectX10Methods> {
+    let adapter = dxgi::attempt_adapter(attempt)?;
+    let driver_type: D3D10_DRIVER_TYPE = match attempt.driver {
+        DriverType::Hardware => D3D10_DRIVER_TYPE_HARDWARE,
+        DriverType::Warp => D3D10_DRIVER_TYPE_WARP,
+        DriverType::Reference => D3D10_DRIVER_TYPE_REFERENCE,
+        DriverType::Null => D3D10_DRIVER_TYPE_NULL,
+    };
+    let swapchain_desc = default_swapchain_descriptor(hwnd);
+
+    let mut swapchain: Option<IDXGISwapChain> = None;
+    let mut device: Option<ID3D10Device> = None;
+
+    unsafe {
+        D3D10CreateDeviceAndSwapChain(
+            adapter.as_ref(),
+            driver_type,
+            HMODULE::default(),
+            0,
+            D3D10_SDK_VERSION,
+            Some(&swapchain_desc),
+            Some(&mut swapchain),
+            Some(&mut device),
+        )
+    }
+    .map_err(|e| ShroudError::DirectX10CreateDeviceAndSwapchain(e.code()))?;
+
+    // d3d10_1.dll implements its own device, create one at the first level the adapter supports.
+    let mut device1: Option<ID3D10Device1> = None;
+    let mut result = Ok(());
+    for level in &attempt.feature_levels {
+        result = unsafe {
+            D3D10CreateDevice1(
+                adapter.as_ref(),
+                driver_type,
+                HMODULE::default(),
+                0,
+                D3D10_FEATURE_LEVEL1(level.raw()),
+                D3D10_1_SDK_VERSION,
+                Some(&mut device1),
+            )
+        };
+        if result.is_ok() {
+            break;
+        }
+    }
+    result.map_err(|e| ShroudError::DirectX10CreateDevice1(e.code()))?;
+
+    let swapchain = swapchain.ok_or(ShroudError::Expectation("Dx10 Swapchain created"))?;
+    let device = device.ok_or(ShroudError::Expectation("Dx10 Device created"))?;
+    let device1 = device1.ok_or(ShroudError::Expectation("Dx10.1 Device created"))?;
+
+    unsafe {
+        DirectX10Methods::read(
+            swapchain.as_raw(),
+            DxgiSwapchainVmt::query(&swapchain)?,
+            device.as_raw(),
+            device1.as_raw(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::EnumCount;
+
+    use super::*;
+    use crate::{dxgi::DxgiSwapchain1Methods, testing::FakeObject};
+
+    struct Objects {
+        swapchain: Box<FakeObject>,
+        swapchain1: Box<FakeObject>,
+        device: Box<FakeObject>,
+        device1: Box<FakeObject>,
+    }
+
+    impl Objects {
+        fn new() -> Self {
+            Self {
+                swapchain: FakeObject::new(DirectX10SwapchainMethods::COUNT),
+                swapchain1: FakeObject::new(DxgiSwapchain1Methods::COUNT),
+                device: FakeObject::new(DirectX10DeviceMethods::COUNT),
+                device1: FakeObject::new(DirectX10Device1Methods::COUNT),
+            }
+        }
+
+        fn methods(&self) -> ShroudResult<DirectX10Methods> {
+            let swapchain1 = unsafe { crate::vtable::read(self.swapchain1.as_ptr()) }
+                .map(DxgiSwapchainVmt::Swapchain1)?;
+            unsafe {
+                DirectX10Methods::read(
+                    self.swapchain.as_ptr(),
+                    Some(swapchain1),
+                    self.device.as_ptr(),
+                    self.device1.as_ptr(),
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn reads_every_table() {
+        let objects = Objects::new();
+        let methods = objects.methods().unwrap();
+
+        assert_eq!(methods.swapchain_vmt().vtable(), objects.swapchain.vtable);
+        assert_eq!(
+            methods
+                .swapchain_vmt()
+                .get(DirectX10SwapchainMethods::Present),
+            unsafe { *objects.swapchain.vtable.add(8) }
+        );
+        assert_eq!(methods.device_vmt().vtable(), objects.device.vtable);
+        assert_eq!(methods.device_vmt().len(), DirectX10DeviceMethods::COUNT);
+        assert_eq!(methods.device1_vmt().vtable(), objects.device1.vtable);
+        assert_eq!(methods.device1_vmt().len(), DirectX10Device1Methods::COUNT);
+        assert_eq!(methods.latest_swapchain_vmt().unwrap().version(), 1);
+    }
+
+    #[test]
+    fn missing_objects_are_errors() {
+        let objects = Objects::new();
+        let result = unsafe {
+            DirectX10Methods::read(
+                objects.swapchain.as_ptr(),
+                None,
+                std::ptr::null(),
+                objects.device1.as_ptr(),
+            )
+        };
+        assert!(matches!(result, Err(ShroudError::NullPointer("object"))));
+    }
+
+    #[test]
+    fn snapshots_every_table() {
+        let objects = Objects::new();
+        let snapshot = objects.methods().unwrap().snapshot();
+        assert_eq!(snapshot.engine, RenderEngine::DirectX10);
+        assert_eq!(
+            snapshot.entries.len(),
+            DirectX10SwapchainMethods::COUNT
+                + DxgiSwapchain1Methods::COUNT
+                + DirectX10DeviceMethods::COUNT
+                + DirectX10Device1Methods::COUNT
+        );
+
+        let loaded = DirectX10Methods::try_from(&snapshot).unwrap();
+        assert_eq!(loaded.snapshot(), snapshot);
+        assert_eq!(loaded.latest_swapchain_vmt().unwrap().version(), 1);
+        assert!(DirectX10Methods::try_from(&Snapshot::new(RenderEngine::DirectX11)).is_err());
+    }
+}
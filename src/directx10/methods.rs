@@ -0,0 +1,742 @@
+// Generated by `cargo xtask codegen` from xtask/winmd/Windows.Win32.winmd, do not edit.
+
+use strum_macros::{EnumCount, EnumIter};
+
+#[cfg(windows)]
+use crate::vtable::assert_slot;
+use crate::InterfaceMethods;
+
+/// `IDXGISwapChain` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX10SwapchainMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+}
+
+impl InterfaceMethods for DirectX10SwapchainMethods {
+    const INTERFACE: &'static str = "IDXGISwapChain";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain_Vtbl as Vtbl;
+    use DirectX10SwapchainMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, Present => M::Present);
+    assert_slot!(Vtbl, GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, GetLastPresentCount => M::GetLastPresentCount);
+};
+
+/// `ID3D10Device` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX10DeviceMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    ClearRenderTargetView,
+    ClearDepthStencilView,
+    GenerateMips,
+    ResolveSubresource,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    GetDeviceRemovedReason,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    ClearState,
+    Flush,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    GetCreationFlags,
+    OpenSharedResource,
+    SetTextFilterSize,
+    GetTextFilterSize,
+}
+
+impl InterfaceMethods for DirectX10DeviceMethods {
+    const INTERFACE: &'static str = "ID3D10Device";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D10::ID3D10Device_Vtbl as Vtbl;
+    use DirectX10DeviceMethods as M;
+
+    assert_slot!(Vtbl, base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.Release => M::Release);
+    assert_slot!(Vtbl, VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, Draw => M::Draw);
+    assert_slot!(Vtbl, PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, ClearState => M::ClearState);
+    assert_slot!(Vtbl, Flush => M::Flush);
+    assert_slot!(Vtbl, CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, SetTextFilterSize => M::SetTextFilterSize);
+    assert_slot!(Vtbl, GetTextFilterSize => M::GetTextFilterSize);
+};
+
+/// `ID3D10Device1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX10Device1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    ClearRenderTargetView,
+    ClearDepthStencilView,
+    GenerateMips,
+    ResolveSubresource,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    GetDeviceRemovedReason,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    ClearState,
+    Flush,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    GetCreationFlags,
+    OpenSharedResource,
+    SetTextFilterSize,
+    GetTextFilterSize,
+    CreateShaderResourceView1,
+    CreateBlendState1,
+    GetFeatureLevel,
+}
+
+impl InterfaceMethods for DirectX10Device1Methods {
+    const INTERFACE: &'static str = "ID3D10Device1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D10::ID3D10Device1_Vtbl as Vtbl;
+    use DirectX10Device1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, base__.PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, base__.PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, base__.VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, base__.DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, base__.Draw => M::Draw);
+    assert_slot!(Vtbl, base__.PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, base__.VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, base__.GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, base__.OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, base__.RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, base__.PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, base__.PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, base__.VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, base__.PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, base__.IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, base__.IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, base__.GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, base__.IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, base__.VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, base__.GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, base__.GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, base__.GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, base__.OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, base__.OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, base__.OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, base__.SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, base__.RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, base__.RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, base__.RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, base__.GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.Flush => M::Flush);
+    assert_slot!(Vtbl, base__.CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, base__.CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, base__.CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, base__.CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, base__.CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, base__.CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, base__.CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, base__.CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, base__.CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, base__.CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, base__.CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, base__.CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, base__.CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, base__.CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, base__.SetTextFilterSize => M::SetTextFilterSize);
+    assert_slot!(Vtbl, base__.GetTextFilterSize => M::GetTextFilterSize);
+    assert_slot!(Vtbl, CreateShaderResourceView1 => M::CreateShaderResourceView1);
+    assert_slot!(Vtbl, CreateBlendState1 => M::CreateBlendState1);
+    assert_slot!(Vtbl, GetFeatureLevel => M::GetFeatureLevel);
+};
+
+/// Typed signatures of [`DirectX10SwapchainMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain {
+    use std::ffi::c_void;
+
+    use super::DirectX10SwapchainMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX10SwapchainMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX10DeviceMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device {
+    use std::ffi::c_void;
+
+    use super::DirectX10DeviceMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX10DeviceMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(num_rects: *mut u32, p_rects: *mut c_void),
+        GetDeviceRemovedReason() -> HRESULT,
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        ClearState(),
+        Flush(),
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, output_stream_stride: u32, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        GetCreationFlags() -> u32,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        SetTextFilterSize(width: u32, height: u32),
+        GetTextFilterSize(p_width: *mut u32, p_height: *mut u32),
+    }
+}
+
+/// Typed signatures of [`DirectX10Device1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device1 {
+    use std::ffi::c_void;
+
+    use super::DirectX10Device1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX10Device1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(num_rects: *mut u32, p_rects: *mut c_void),
+        GetDeviceRemovedReason() -> HRESULT,
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        ClearState(),
+        Flush(),
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, output_stream_stride: u32, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        GetCreationFlags() -> u32,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        SetTextFilterSize(width: u32, height: u32),
+        GetTextFilterSize(p_width: *mut u32, p_height: *mut u32),
+        CreateShaderResourceView1(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateBlendState1(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+    }
+}
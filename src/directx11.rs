@@ -1,291 +1,225 @@
-use strum::{EnumCount, IntoEnumIterator};
-use strum_macros::{EnumCount, EnumIter};
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
+use windows::Win32::Foundation::HWND;
+#[cfg(windows)]
 use windows::Win32::Graphics::{
     Direct3D::{
-        D3D_DRIVER_TYPE_HARDWARE, D3D_FEATURE_LEVEL, D3D_FEATURE_LEVEL_10_0, D3D_FEATURE_LEVEL_11_1,
+        D3D_DRIVER_TYPE, D3D_DRIVER_TYPE_HARDWARE, D3D_DRIVER_TYPE_NULL, D3D_DRIVER_TYPE_REFERENCE,
+        D3D_DRIVER_TYPE_UNKNOWN, D3D_DRIVER_TYPE_WARP, D3D_FEATURE_LEVEL,
     },
     Direct3D11::{
-        D3D11CreateDeviceAndSwapChain, ID3D11Device, ID3D11DeviceContext, D3D11_SDK_VERSION,
+        D3D11CreateDeviceAndSwapChain, ID3D11Device, ID3D11Device1, ID3D11Device2, ID3D11Device3,
+        ID3D11Device4, ID3D11Device5, ID3D11DeviceContext, ID3D11DeviceContext1,
+        ID3D11DeviceContext2, ID3D11DeviceContext3, ID3D11DeviceContext4, D3D11_SDK_VERSION,
     },
     Dxgi::IDXGISwapChain,
 };
 
+#[cfg(windows)]
 use crate::{
-    get_process_window, swapchain_util::default_swapchain_descriptor, ShroudError, ShroudResult,
+    discovery::{self, Attempt, DriverType},
+    dxgi,
+    swapchain_util::default_swapchain_descriptor,
+    vtable,
+    window::{ProvidedWindow, WindowProvider},
+    DiscoveryOptions, WindowSelector,
+};
+use crate::{
+    dxgi::DxgiSwapchainVmt, method_table::versioned_vmt, snapshot::snapshot_serde, MethodTable,
+    RenderEngine, ShroudError, ShroudResult, Snapshot,
 };
 
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX11SwapchainMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    GetPrivateData,
-    GetParent,
-    GetDevice,
-    Present,
-    GetBuffer,
-    SetFullscreenState,
-    GetFullscreenState,
-    GetDesc,
-    ResizeBuffers,
-    ResizeTarget,
-    GetContainingOutput,
-    GetFrameStatistics,
-    GetLastPresentCount,
-}
+mod methods;
+pub use methods::*;
 
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX11DeviceMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    CreateBuffer,
-    CreateTexture1D,
-    CreateTexture2D,
-    CreateTexture3D,
-    CreateShaderResourceView,
-    CreateUnorderedAccessView,
-    CreateRenderTargetView,
-    CreateDepthStencilView,
-    CreateInputLayout,
-    CreateVertexShader,
-    CreateGeometryShader,
-    CreateGeometryShaderWithStreamOutput,
-    CreatePixelShader,
-    CreateHullShader,
-    CreateDomainShader,
-    CreateComputeShader,
-    CreateClassLinkage,
-    CreateBlendState,
-    CreateDepthStencilState,
-    CreateRasterizerState,
-    CreateSamplerState,
-    CreateQuery,
-    CreatePredicate,
-    CreateCounter,
-    CreateDeferredContext,
-    OpenSharedResource,
-    CheckFormatSupport,
-    CheckMultisampleQualityLevels,
-    CheckCounterInfo,
-    CheckCounter,
-    CheckFeatureSupport,
-    GetPrivateData,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    GetFeatureLevel,
-    GetCreationFlags,
-    GetDeviceRemovedReason,
-    GetImmediateContext,
-    SetExceptionMode,
-    GetExceptionMode,
+versioned_vmt! {
+    /// Method table of a device at the highest `ID3D11Device` version it supports.
+    pub enum DirectX11DeviceVmt {
+        Device5(5, DirectX11Device5Methods, ID3D11Device5),
+        Device4(4, DirectX11Device4Methods, ID3D11Device4),
+        Device3(3, DirectX11Device3Methods, ID3D11Device3),
+        Device2(2, DirectX11Device2Methods, ID3D11Device2),
+        Device1(1, DirectX11Device1Methods, ID3D11Device1),
+    }
 }
 
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX11ContextMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    GetDevice,
-    GetPrivateData,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    SetConstantBuffers,
-    SetShaderResources,
-    SetShader,
-    SetSamplers,
-    SetShader1,
-    DrawIndexed,
-    Draw,
-    Map,
-    Unmap,
-    SetConstantBuffers1,
-    IASetInputLayout,
-    IASetVertexBuffers,
-    IASetIndexBuffer,
-    DrawIndexedInstanced,
-    DrawInstanced,
-    SetConstantBuffers2,
-    SetShader2,
-    IASetPrimitiveTopology,
-    SetShaderResources1,
-    SetSamplers1,
-    Begin,
-    End,
-    GetData,
-    SetPredication,
-    SetShaderResources2,
-    SetSamplers2,
-    OMSetRenderTargets,
-    OMSetRenderTargetsAndUnorderedAccessViews,
-    OMSetBlendState,
-    OMSetDepthStencilState,
-    SOSetTargets,
-    DrawAuto,
-    DrawIndexedInstancedIndirect,
-    DrawInstancedIndirect,
-    Dispatch,
-    DispatchIndirect,
-    RSSetState,
-    RSSetViewports,
-    RSSetScissorRects,
-    CopySubresourceRegion,
-    CopyResource,
-    UpdateSubresource,
-    CopyStructureCount,
-    ClearRenderTargetView,
-    ClearUnorderedAccessViewUint,
-    ClearUnorderedAccessViewFloat,
-    ClearDepthStencilView,
-    GenerateMips,
-    SetResourceMinLOD,
-    GetResourceMinLOD,
-    ResolveSubresource,
-    ExecuteCommandList,
-    SetShaderResources3,
-    SetShader4,
-    SetSamplers3,
-    SetConstantBuffers3,
-    SetShaderResources4,
-    SetShader5,
-    SetSamplers4,
-    SetConstantBuffers4,
-    SetShaderResources5,
-    CSSetUnorderedAccessViews,
-    SetShader6,
-    SetSamplers5,
-    SetConstantBuffers5,
-    VSGetConstantBuffers,
-    PSGetShaderResources,
-    PSGetShader,
-    PSGetSamplers,
-    VSGetShader,
-    PSGetConstantBuffers,
-    IAGetInputLayout,
-    IAGetVertexBuffers,
-    IAGetIndexBuffer,
-    GSGetConstantBuffers,
-    GSGetShader,
-    IAGetPrimitiveTopology,
-    VSGetShaderResources,
-    VSGetSamplers,
-    GetPredication,
-    GSGetShaderResources,
-    GSGetSamplers,
-    OMGetRenderTargets,
-    OMGetRenderTargetsAndUnorderedAccessViews,
-    OMGetBlendState,
-    OMGetDepthStencilState,
-    SOGetTargets,
-    RSGetState,
-    RSGetViewports,
-    RSGetScissorRects,
-    HSGetShaderResources,
-    HSGetShader,
-    HSGetSamplers,
-    HSGetConstantBuffers,
-    DSGetShaderResources,
-    DSGetShader,
-    DSGetSamplers,
-    DSGetConstantBuffers,
-    CSGetShaderResources,
-    CSGetUnorderedAccessViews,
-    CSGetShader,
-    CSGetSamplers,
-    CSGetConstantBuffers,
-    ClearState,
-    Flush,
-    GetType,
-    GetContextFlags,
-    FinishCommandList,
-    CopySubresourceRegion1,
-    UpdateSubresource1,
-    DiscardResource,
-    DiscardView,
-    SetConstantBuffers1_0,
-    SetConstantBuffers1_1,
-    SetConstantBuffers1_2,
-    SetConstantBuffers1_3,
-    SetConstantBuffers1_4,
-    SetConstantBuffers1_5,
-    VSGetConstantBuffers1,
-    HSGetConstantBuffers1,
-    DSGetConstantBuffers1,
-    GSGetConstantBuffers1,
-    PSGetConstantBuffers1,
-    CSGetConstantBuffers1,
-    SwapDeviceContextState,
-    ClearView,
-    DiscardView1,
-    UpdateTileMappings,
-    CopyTileMappings,
-    CopyTiles,
-    UpdateTiles,
-    ResizeTilePool,
-    TiledResourceBarrier,
-    IsAnnotationEnabled,
-    SetMarkerInt,
-    BeginEventInt,
-    EndEvent,
+versioned_vmt! {
+    /// Method table of an immediate context at the highest `ID3D11DeviceContext`
+    /// version it supports.
+    pub enum DirectX11ContextVmt {
+        Context4(4, DirectX11Context4Methods, ID3D11DeviceContext4),
+        Context3(3, DirectX11Context3Methods, ID3D11DeviceContext3),
+        Context2(2, DirectX11Context2Methods, ID3D11DeviceContext2),
+        Context1(1, DirectX11Context1Methods, ID3D11DeviceContext1),
+    }
 }
 
 pub struct DirectX11Methods {
-    swapchain_vmt: Vec<*const usize>,
-    device_vmt: Vec<*const usize>,
-    context_vmt: Vec<*const usize>,
+    swapchain_vmt: MethodTable<DirectX11SwapchainMethods>,
+    latest_swapchain_vmt: Option<DxgiSwapchainVmt>,
+    device_vmt: MethodTable<DirectX11DeviceMethods>,
+    latest_device_vmt: Option<DirectX11DeviceVmt>,
+    context_vmt: MethodTable<DirectX11ContextMethods>,
+    latest_context_vmt: Option<DirectX11ContextVmt>,
 }
 
 impl DirectX11Methods {
-    pub fn swapchain_vmt(&self) -> &Vec<*const usize> {
+    pub fn swapchain_vmt(&self) -> &MethodTable<DirectX11SwapchainMethods> {
         &self.swapchain_vmt
     }
 
-    pub fn device_vmt(&self) -> &Vec<*const usize> {
+    /// The swap chain's table at the highest `IDXGISwapChain` version it supports,
+    /// `None` if it is only an `IDXGISwapChain`.
+    pub fn latest_swapchain_vmt(&self) -> Option<&DxgiSwapchainVmt> {
+        self.latest_swapchain_vmt.as_ref()
+    }
+
+    pub fn device_vmt(&self) -> &MethodTable<DirectX11DeviceMethods> {
         &self.device_vmt
     }
 
-    pub fn context_vmt(&self) -> &Vec<*const usize> {
+    /// The device's table at the highest `ID3D11Device` version it supports,
+    /// `None` if it is only an `ID3D11Device`.
+    pub fn latest_device_vmt(&self) -> Option<&DirectX11DeviceVmt> {
+        self.latest_device_vmt.as_ref()
+    }
+
+    pub fn context_vmt(&self) -> &MethodTable<DirectX11ContextMethods> {
         &self.context_vmt
     }
+
+    /// The immediate context's table at the highest `ID3D11DeviceContext` version it
+    /// supports, `None` if it is only an `ID3D11DeviceContext`.
+    pub fn latest_context_vmt(&self) -> Option<&DirectX11ContextVmt> {
+        self.latest_context_vmt.as_ref()
+    }
+}
+
+impl DirectX11Methods {
+    /// Host-independent record of every table, see [`Snapshot`].
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new(RenderEngine::DirectX11);
+        snapshot.push(&self.swapchain_vmt);
+        if let Some(vmt) = &self.latest_swapchain_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot.push(&self.device_vmt);
+        if let Some(vmt) = &self.latest_device_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot.push(&self.context_vmt);
+        if let Some(vmt) = &self.latest_context_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot
+    }
+}
+
+impl TryFrom<&Snapshot> for DirectX11Methods {
+    type Error = ShroudError;
+
+    fn try_from(snapshot: &Snapshot) -> ShroudResult<Self> {
+        snapshot.expect(RenderEngine::DirectX11)?;
+        Ok(Self {
+            swapchain_vmt: snapshot.required_table()?,
+            latest_swapchain_vmt: DxgiSwapchainVmt::from_snapshot(snapshot)?,
+            device_vmt: snapshot.required_table()?,
+            latest_device_vmt: DirectX11DeviceVmt::from_snapshot(snapshot)?,
+            context_vmt: snapshot.required_table()?,
+            latest_context_vmt: DirectX11ContextVmt::from_snapshot(snapshot)?,
+        })
+    }
 }
 
+snapshot_serde!(DirectX11Methods);
+
 impl std::fmt::Debug for DirectX11Methods {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "DirectX11 Method Table")?;
 
-        let mut index = 0;
         writeln!(f, "Swapchain Virtual Method Table")?;
-        for (i, method) in DirectX11SwapchainMethods::iter().enumerate() {
-            writeln!(f, "\t[{}] {:?} {:#?}", index, method, self.swapchain_vmt[i])?;
+        write!(f, "{:?}", self.swapchain_vmt)?;
+        writeln!(f)?;
 
-            index += 1;
+        if let Some(vmt) = &self.latest_swapchain_vmt {
+            writeln!(f, "Swapchain{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
-        writeln!(f)?;
 
         writeln!(f, "Devices Virtual Method Table")?;
-        for (i, method) in DirectX11SwapchainMethods::iter().enumerate() {
-            writeln!(f, "\t[{}] {:?} {:#?}", index, method, self.device_vmt[i])?;
+        write!(f, "{:?}", self.device_vmt)?;
+        writeln!(f)?;
 
-            index += 1;
+        if let Some(vmt) = &self.latest_device_vmt {
+            writeln!(f, "Device{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
-        writeln!(f)?;
 
         writeln!(f, "Context Virtual Method Table")?;
-        for (i, method) in DirectX11ContextMethods::iter().enumerate() {
-            writeln!(f, "\t[{}] {:?} {:#?}", index, method, self.context_vmt[i])?;
+        write!(f, "{:?}", self.context_vmt)?;
+        writeln!(f)?;
 
-            index += 1;
+        if let Some(vmt) = &self.latest_context_vmt {
+            writeln!(f, "Context{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
 
         Ok(())
     }
 }
 
+/// Discovers every table, creating the device for the window
+/// [`WindowSelector::default`] picks.
+#[cfg(windows)]
 pub fn methods() -> ShroudResult<DirectX11Methods> {
-    let window = get_process_window().ok_or(ShroudError::Window)?;
-    let swapchain_desc = default_swapchain_descriptor(window);
+    methods_for(&WindowSelector::default())
+}
+
+/// [`methods`], creating the device for the window `window` provides, such as one
+/// [`WindowSelector`] picks or a [`crate::TemporaryWindow`].
+#[cfg(windows)]
+pub fn methods_for(window: &impl WindowProvider) -> ShroudResult<DirectX11Methods> {
+    methods_with(window, &DiscoveryOptions::default())
+}
+
+/// [`methods_for`], creating the device with the driver, feature levels and
+/// adapter `options` ask for, and falling back along its plan.
+#[cfg(windows)]
+pub fn methods_with(
+    window: &impl WindowProvider,
+    options: &DiscoveryOptions,
+) -> ShroudResult<DirectX11Methods> {
+    let window = window.acquire()?;
+    let hwnd = HWND(window.handle() as *mut _);
+    let adapters = dxgi::adapters().unwrap_or_default();
+    let attempts = options.plan(RenderEngine::DirectX11, &adapters);
+    discovery::retry(RenderEngine::DirectX11, attempts, |attempt| {
+        discover(hwnd, attempt)
+    })
+}
+
+#[cfg(windows)]
+fn discover(hwnd: HWND, attempt: &Attempt) -> ShroudResult<DirectX11Methods> {
+    let adapter = dxgi::attempt_adapter(attempt)?;
+    // Devices on an explicit adapter take their driver from it.
+    let driver_type: D3D_DRIVER_TYPE = match attempt.driver {
+        DriverType::Hardware if adapter.is_some() => D3D_DRIVER_TYPE_UNKNOWN,
+        DriverType::Hardware => D3D_DRIVER_TYPE_HARDWARE,
+        DriverType::Warp => D3D_DRIVER_TYPE_WARP,
+        DriverType::Reference => D3D_DRIVER_TYPE_REFERENCE,
+        DriverType::Null => D3D_DRIVER_TYPE_NULL,
+    };
+    let feature_levels: Vec<D3D_FEATURE_LEVEL> = attempt
+        .feature_levels
+        .iter()
+        .map(|&level| level.into())
+        .collect();
+
+    let swapchain_desc = default_swapchain_descriptor(hwnd);
     let feature_level: *mut D3D_FEATURE_LEVEL = std::ptr::null_mut();
 
     let mut swapchain: Option<IDXGISwapChain> = None;
@@ -294,11 +228,11 @@ pub fn methods() -> ShroudResult<DirectX11Methods> {
 
     unsafe {
         D3D11CreateDeviceAndSwapChain(
-            None,
-            D3D_DRIVER_TYPE_HARDWARE,
+            adapter.as_ref(),
+            driver_type,
             None,
             windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_FLAG(0),
-            Some(&[D3D_FEATURE_LEVEL_10_0, D3D_FEATURE_LEVEL_11_1]),
+            Some(&feature_levels),
             D3D11_SDK_VERSION,
             Some(&swapchain_desc),
             Some(&mut swapchain),
@@ -310,35 +244,23 @@ pub fn methods() -> ShroudResult<DirectX11Methods> {
     .map_err(|e| ShroudError::DirectX11CreateDeviceAndSwapchain(e.code()))?;
 
     let swapchain = swapchain.ok_or(ShroudError::Expectation("Dx11 Swapchain created"))?;
-    let swapchain_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(swapchain).read(),
-            DirectX11SwapchainMethods::COUNT,
-        )
-    }
-    .to_vec();
+    let swapchain_vmt = unsafe { vtable::read::<DirectX11SwapchainMethods>(swapchain.as_raw())? };
+    let latest_swapchain_vmt = DxgiSwapchainVmt::query(&swapchain)?;
 
     let device = device.ok_or(ShroudError::Expectation("Dx11 Device created"))?;
-    let device_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(device).read(),
-            DirectX11DeviceMethods::COUNT,
-        )
-        .to_vec()
-    };
+    let device_vmt = unsafe { vtable::read::<DirectX11DeviceMethods>(device.as_raw())? };
+    let latest_device_vmt = DirectX11DeviceVmt::query(&device)?;
 
     let device_context = device_context.ok_or(ShroudError::Expectation("Dx11 Context created"))?;
-    let context_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(device_context).read(),
-            DirectX11ContextMethods::COUNT,
-        )
-        .to_vec()
-    };
+    let context_vmt = unsafe { vtable::read::<DirectX11ContextMethods>(device_context.as_raw())? };
+    let latest_context_vmt = DirectX11ContextVmt::query(&device_context)?;
 
     Ok(DirectX11Methods {
         swapchain_vmt,
+        latest_swapchain_vmt,
         device_vmt,
+        latest_device_vmt,
         context_vmt,
+        latest_context_vmt,
     })
 }
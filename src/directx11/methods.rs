@@ -0,0 +1,3508 @@
+// Generated by `cargo xtask codegen` from xtask/winmd/Windows.Win32.winmd, do not edit.
+
+use strum_macros::{EnumCount, EnumIter};
+
+#[cfg(windows)]
+use crate::vtable::assert_slot;
+use crate::InterfaceMethods;
+
+/// `IDXGISwapChain` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11SwapchainMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+}
+
+impl InterfaceMethods for DirectX11SwapchainMethods {
+    const INTERFACE: &'static str = "IDXGISwapChain";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain_Vtbl as Vtbl;
+    use DirectX11SwapchainMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, Present => M::Present);
+    assert_slot!(Vtbl, GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, GetLastPresentCount => M::GetLastPresentCount);
+};
+
+/// `ID3D11Device` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11DeviceMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateHullShader,
+    CreateDomainShader,
+    CreateComputeShader,
+    CreateClassLinkage,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CreateDeferredContext,
+    OpenSharedResource,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    CheckFeatureSupport,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetFeatureLevel,
+    GetCreationFlags,
+    GetDeviceRemovedReason,
+    GetImmediateContext,
+    SetExceptionMode,
+    GetExceptionMode,
+}
+
+impl InterfaceMethods for DirectX11DeviceMethods {
+    const INTERFACE: &'static str = "ID3D11Device";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11Device_Vtbl as Vtbl;
+    use DirectX11DeviceMethods as M;
+
+    assert_slot!(Vtbl, base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.Release => M::Release);
+    assert_slot!(Vtbl, CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, CreateHullShader => M::CreateHullShader);
+    assert_slot!(Vtbl, CreateDomainShader => M::CreateDomainShader);
+    assert_slot!(Vtbl, CreateComputeShader => M::CreateComputeShader);
+    assert_slot!(Vtbl, CreateClassLinkage => M::CreateClassLinkage);
+    assert_slot!(Vtbl, CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, CreateDeferredContext => M::CreateDeferredContext);
+    assert_slot!(Vtbl, OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, GetFeatureLevel => M::GetFeatureLevel);
+    assert_slot!(Vtbl, GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, GetImmediateContext => M::GetImmediateContext);
+    assert_slot!(Vtbl, SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, GetExceptionMode => M::GetExceptionMode);
+};
+
+/// `ID3D11DeviceContext` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11ContextMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetDevice,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    Map,
+    Unmap,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    Begin,
+    End,
+    GetData,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetRenderTargetsAndUnorderedAccessViews,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    DrawIndexedInstancedIndirect,
+    DrawInstancedIndirect,
+    Dispatch,
+    DispatchIndirect,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    CopyStructureCount,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    ClearDepthStencilView,
+    GenerateMips,
+    SetResourceMinLOD,
+    GetResourceMinLOD,
+    ResolveSubresource,
+    ExecuteCommandList,
+    HSSetShaderResources,
+    HSSetShader,
+    HSSetSamplers,
+    HSSetConstantBuffers,
+    DSSetShaderResources,
+    DSSetShader,
+    DSSetSamplers,
+    DSSetConstantBuffers,
+    CSSetShaderResources,
+    CSSetUnorderedAccessViews,
+    CSSetShader,
+    CSSetSamplers,
+    CSSetConstantBuffers,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetRenderTargetsAndUnorderedAccessViews,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    HSGetShaderResources,
+    HSGetShader,
+    HSGetSamplers,
+    HSGetConstantBuffers,
+    DSGetShaderResources,
+    DSGetShader,
+    DSGetSamplers,
+    DSGetConstantBuffers,
+    CSGetShaderResources,
+    CSGetUnorderedAccessViews,
+    CSGetShader,
+    CSGetSamplers,
+    CSGetConstantBuffers,
+    ClearState,
+    Flush,
+    GetType,
+    GetContextFlags,
+    FinishCommandList,
+}
+
+impl InterfaceMethods for DirectX11ContextMethods {
+    const INTERFACE: &'static str = "ID3D11DeviceContext";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11DeviceContext_Vtbl as Vtbl;
+    use DirectX11ContextMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, Draw => M::Draw);
+    assert_slot!(Vtbl, Map => M::Map);
+    assert_slot!(Vtbl, Unmap => M::Unmap);
+    assert_slot!(Vtbl, PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, Begin => M::Begin);
+    assert_slot!(Vtbl, End => M::End);
+    assert_slot!(Vtbl, GetData => M::GetData);
+    assert_slot!(Vtbl, SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, OMSetRenderTargetsAndUnorderedAccessViews => M::OMSetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, DrawIndexedInstancedIndirect => M::DrawIndexedInstancedIndirect);
+    assert_slot!(Vtbl, DrawInstancedIndirect => M::DrawInstancedIndirect);
+    assert_slot!(Vtbl, Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, DispatchIndirect => M::DispatchIndirect);
+    assert_slot!(Vtbl, RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, CopyStructureCount => M::CopyStructureCount);
+    assert_slot!(Vtbl, ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, SetResourceMinLOD => M::SetResourceMinLOD);
+    assert_slot!(Vtbl, GetResourceMinLOD => M::GetResourceMinLOD);
+    assert_slot!(Vtbl, ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, ExecuteCommandList => M::ExecuteCommandList);
+    assert_slot!(Vtbl, HSSetShaderResources => M::HSSetShaderResources);
+    assert_slot!(Vtbl, HSSetShader => M::HSSetShader);
+    assert_slot!(Vtbl, HSSetSamplers => M::HSSetSamplers);
+    assert_slot!(Vtbl, HSSetConstantBuffers => M::HSSetConstantBuffers);
+    assert_slot!(Vtbl, DSSetShaderResources => M::DSSetShaderResources);
+    assert_slot!(Vtbl, DSSetShader => M::DSSetShader);
+    assert_slot!(Vtbl, DSSetSamplers => M::DSSetSamplers);
+    assert_slot!(Vtbl, DSSetConstantBuffers => M::DSSetConstantBuffers);
+    assert_slot!(Vtbl, CSSetShaderResources => M::CSSetShaderResources);
+    assert_slot!(Vtbl, CSSetUnorderedAccessViews => M::CSSetUnorderedAccessViews);
+    assert_slot!(Vtbl, CSSetShader => M::CSSetShader);
+    assert_slot!(Vtbl, CSSetSamplers => M::CSSetSamplers);
+    assert_slot!(Vtbl, CSSetConstantBuffers => M::CSSetConstantBuffers);
+    assert_slot!(Vtbl, VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, OMGetRenderTargetsAndUnorderedAccessViews => M::OMGetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, HSGetShaderResources => M::HSGetShaderResources);
+    assert_slot!(Vtbl, HSGetShader => M::HSGetShader);
+    assert_slot!(Vtbl, HSGetSamplers => M::HSGetSamplers);
+    assert_slot!(Vtbl, HSGetConstantBuffers => M::HSGetConstantBuffers);
+    assert_slot!(Vtbl, DSGetShaderResources => M::DSGetShaderResources);
+    assert_slot!(Vtbl, DSGetShader => M::DSGetShader);
+    assert_slot!(Vtbl, DSGetSamplers => M::DSGetSamplers);
+    assert_slot!(Vtbl, DSGetConstantBuffers => M::DSGetConstantBuffers);
+    assert_slot!(Vtbl, CSGetShaderResources => M::CSGetShaderResources);
+    assert_slot!(Vtbl, CSGetUnorderedAccessViews => M::CSGetUnorderedAccessViews);
+    assert_slot!(Vtbl, CSGetShader => M::CSGetShader);
+    assert_slot!(Vtbl, CSGetSamplers => M::CSGetSamplers);
+    assert_slot!(Vtbl, CSGetConstantBuffers => M::CSGetConstantBuffers);
+    assert_slot!(Vtbl, ClearState => M::ClearState);
+    assert_slot!(Vtbl, Flush => M::Flush);
+    assert_slot!(Vtbl, GetType => M::GetType);
+    assert_slot!(Vtbl, GetContextFlags => M::GetContextFlags);
+    assert_slot!(Vtbl, FinishCommandList => M::FinishCommandList);
+};
+
+/// `ID3D11Device1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Device1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateHullShader,
+    CreateDomainShader,
+    CreateComputeShader,
+    CreateClassLinkage,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CreateDeferredContext,
+    OpenSharedResource,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    CheckFeatureSupport,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetFeatureLevel,
+    GetCreationFlags,
+    GetDeviceRemovedReason,
+    GetImmediateContext,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetImmediateContext1,
+    CreateDeferredContext1,
+    CreateBlendState1,
+    CreateRasterizerState1,
+    CreateDeviceContextState,
+    OpenSharedResource1,
+    OpenSharedResourceByName,
+}
+
+impl InterfaceMethods for DirectX11Device1Methods {
+    const INTERFACE: &'static str = "ID3D11Device1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11Device1_Vtbl as Vtbl;
+    use DirectX11Device1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, base__.CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, base__.CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, base__.CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, base__.CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.CreateHullShader => M::CreateHullShader);
+    assert_slot!(Vtbl, base__.CreateDomainShader => M::CreateDomainShader);
+    assert_slot!(Vtbl, base__.CreateComputeShader => M::CreateComputeShader);
+    assert_slot!(Vtbl, base__.CreateClassLinkage => M::CreateClassLinkage);
+    assert_slot!(Vtbl, base__.CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, base__.CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, base__.CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, base__.CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, base__.CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, base__.CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, base__.CreateDeferredContext => M::CreateDeferredContext);
+    assert_slot!(Vtbl, base__.OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, base__.CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, base__.CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, base__.CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, base__.CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.GetFeatureLevel => M::GetFeatureLevel);
+    assert_slot!(Vtbl, base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.GetImmediateContext => M::GetImmediateContext);
+    assert_slot!(Vtbl, base__.SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, base__.GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, GetImmediateContext1 => M::GetImmediateContext1);
+    assert_slot!(Vtbl, CreateDeferredContext1 => M::CreateDeferredContext1);
+    assert_slot!(Vtbl, CreateBlendState1 => M::CreateBlendState1);
+    assert_slot!(Vtbl, CreateRasterizerState1 => M::CreateRasterizerState1);
+    assert_slot!(Vtbl, CreateDeviceContextState => M::CreateDeviceContextState);
+    assert_slot!(Vtbl, OpenSharedResource1 => M::OpenSharedResource1);
+    assert_slot!(Vtbl, OpenSharedResourceByName => M::OpenSharedResourceByName);
+};
+
+/// `ID3D11Device2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Device2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateHullShader,
+    CreateDomainShader,
+    CreateComputeShader,
+    CreateClassLinkage,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CreateDeferredContext,
+    OpenSharedResource,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    CheckFeatureSupport,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetFeatureLevel,
+    GetCreationFlags,
+    GetDeviceRemovedReason,
+    GetImmediateContext,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetImmediateContext1,
+    CreateDeferredContext1,
+    CreateBlendState1,
+    CreateRasterizerState1,
+    CreateDeviceContextState,
+    OpenSharedResource1,
+    OpenSharedResourceByName,
+    GetImmediateContext2,
+    CreateDeferredContext2,
+    GetResourceTiling,
+    CheckMultisampleQualityLevels1,
+}
+
+impl InterfaceMethods for DirectX11Device2Methods {
+    const INTERFACE: &'static str = "ID3D11Device2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11Device2_Vtbl as Vtbl;
+    use DirectX11Device2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, base__.base__.CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, base__.base__.CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, base__.base__.CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, base__.base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.base__.CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, base__.base__.CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, base__.base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.base__.CreateHullShader => M::CreateHullShader);
+    assert_slot!(Vtbl, base__.base__.CreateDomainShader => M::CreateDomainShader);
+    assert_slot!(Vtbl, base__.base__.CreateComputeShader => M::CreateComputeShader);
+    assert_slot!(Vtbl, base__.base__.CreateClassLinkage => M::CreateClassLinkage);
+    assert_slot!(Vtbl, base__.base__.CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, base__.base__.CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, base__.base__.CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, base__.base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, base__.base__.CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, base__.base__.CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, base__.base__.CreateDeferredContext => M::CreateDeferredContext);
+    assert_slot!(Vtbl, base__.base__.OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, base__.base__.CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, base__.base__.CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, base__.base__.CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, base__.base__.CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetFeatureLevel => M::GetFeatureLevel);
+    assert_slot!(Vtbl, base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.GetImmediateContext => M::GetImmediateContext);
+    assert_slot!(Vtbl, base__.base__.SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, base__.GetImmediateContext1 => M::GetImmediateContext1);
+    assert_slot!(Vtbl, base__.CreateDeferredContext1 => M::CreateDeferredContext1);
+    assert_slot!(Vtbl, base__.CreateBlendState1 => M::CreateBlendState1);
+    assert_slot!(Vtbl, base__.CreateRasterizerState1 => M::CreateRasterizerState1);
+    assert_slot!(Vtbl, base__.CreateDeviceContextState => M::CreateDeviceContextState);
+    assert_slot!(Vtbl, base__.OpenSharedResource1 => M::OpenSharedResource1);
+    assert_slot!(Vtbl, base__.OpenSharedResourceByName => M::OpenSharedResourceByName);
+    assert_slot!(Vtbl, GetImmediateContext2 => M::GetImmediateContext2);
+    assert_slot!(Vtbl, CreateDeferredContext2 => M::CreateDeferredContext2);
+    assert_slot!(Vtbl, GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, CheckMultisampleQualityLevels1 => M::CheckMultisampleQualityLevels1);
+};
+
+/// `ID3D11Device3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Device3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateHullShader,
+    CreateDomainShader,
+    CreateComputeShader,
+    CreateClassLinkage,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CreateDeferredContext,
+    OpenSharedResource,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    CheckFeatureSupport,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetFeatureLevel,
+    GetCreationFlags,
+    GetDeviceRemovedReason,
+    GetImmediateContext,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetImmediateContext1,
+    CreateDeferredContext1,
+    CreateBlendState1,
+    CreateRasterizerState1,
+    CreateDeviceContextState,
+    OpenSharedResource1,
+    OpenSharedResourceByName,
+    GetImmediateContext2,
+    CreateDeferredContext2,
+    GetResourceTiling,
+    CheckMultisampleQualityLevels1,
+    CreateTexture2D1,
+    CreateTexture3D1,
+    CreateRasterizerState2,
+    CreateShaderResourceView1,
+    CreateUnorderedAccessView1,
+    CreateRenderTargetView1,
+    CreateQuery1,
+    GetImmediateContext3,
+    CreateDeferredContext3,
+    WriteToSubresource,
+    ReadFromSubresource,
+}
+
+impl InterfaceMethods for DirectX11Device3Methods {
+    const INTERFACE: &'static str = "ID3D11Device3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11Device3_Vtbl as Vtbl;
+    use DirectX11Device3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, base__.base__.base__.CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, base__.base__.base__.CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.base__.base__.CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, base__.base__.base__.CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, base__.base__.base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.base__.base__.CreateHullShader => M::CreateHullShader);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDomainShader => M::CreateDomainShader);
+    assert_slot!(Vtbl, base__.base__.base__.CreateComputeShader => M::CreateComputeShader);
+    assert_slot!(Vtbl, base__.base__.base__.CreateClassLinkage => M::CreateClassLinkage);
+    assert_slot!(Vtbl, base__.base__.base__.CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, base__.base__.base__.CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDeferredContext => M::CreateDeferredContext);
+    assert_slot!(Vtbl, base__.base__.base__.OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, base__.base__.base__.CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, base__.base__.base__.CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, base__.base__.base__.CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, base__.base__.base__.CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.GetFeatureLevel => M::GetFeatureLevel);
+    assert_slot!(Vtbl, base__.base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.GetImmediateContext => M::GetImmediateContext);
+    assert_slot!(Vtbl, base__.base__.base__.SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.base__.GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.GetImmediateContext1 => M::GetImmediateContext1);
+    assert_slot!(Vtbl, base__.base__.CreateDeferredContext1 => M::CreateDeferredContext1);
+    assert_slot!(Vtbl, base__.base__.CreateBlendState1 => M::CreateBlendState1);
+    assert_slot!(Vtbl, base__.base__.CreateRasterizerState1 => M::CreateRasterizerState1);
+    assert_slot!(Vtbl, base__.base__.CreateDeviceContextState => M::CreateDeviceContextState);
+    assert_slot!(Vtbl, base__.base__.OpenSharedResource1 => M::OpenSharedResource1);
+    assert_slot!(Vtbl, base__.base__.OpenSharedResourceByName => M::OpenSharedResourceByName);
+    assert_slot!(Vtbl, base__.GetImmediateContext2 => M::GetImmediateContext2);
+    assert_slot!(Vtbl, base__.CreateDeferredContext2 => M::CreateDeferredContext2);
+    assert_slot!(Vtbl, base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.CheckMultisampleQualityLevels1 => M::CheckMultisampleQualityLevels1);
+    assert_slot!(Vtbl, CreateTexture2D1 => M::CreateTexture2D1);
+    assert_slot!(Vtbl, CreateTexture3D1 => M::CreateTexture3D1);
+    assert_slot!(Vtbl, CreateRasterizerState2 => M::CreateRasterizerState2);
+    assert_slot!(Vtbl, CreateShaderResourceView1 => M::CreateShaderResourceView1);
+    assert_slot!(Vtbl, CreateUnorderedAccessView1 => M::CreateUnorderedAccessView1);
+    assert_slot!(Vtbl, CreateRenderTargetView1 => M::CreateRenderTargetView1);
+    assert_slot!(Vtbl, CreateQuery1 => M::CreateQuery1);
+    assert_slot!(Vtbl, GetImmediateContext3 => M::GetImmediateContext3);
+    assert_slot!(Vtbl, CreateDeferredContext3 => M::CreateDeferredContext3);
+    assert_slot!(Vtbl, WriteToSubresource => M::WriteToSubresource);
+    assert_slot!(Vtbl, ReadFromSubresource => M::ReadFromSubresource);
+};
+
+/// `ID3D11Device4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Device4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateHullShader,
+    CreateDomainShader,
+    CreateComputeShader,
+    CreateClassLinkage,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CreateDeferredContext,
+    OpenSharedResource,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    CheckFeatureSupport,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetFeatureLevel,
+    GetCreationFlags,
+    GetDeviceRemovedReason,
+    GetImmediateContext,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetImmediateContext1,
+    CreateDeferredContext1,
+    CreateBlendState1,
+    CreateRasterizerState1,
+    CreateDeviceContextState,
+    OpenSharedResource1,
+    OpenSharedResourceByName,
+    GetImmediateContext2,
+    CreateDeferredContext2,
+    GetResourceTiling,
+    CheckMultisampleQualityLevels1,
+    CreateTexture2D1,
+    CreateTexture3D1,
+    CreateRasterizerState2,
+    CreateShaderResourceView1,
+    CreateUnorderedAccessView1,
+    CreateRenderTargetView1,
+    CreateQuery1,
+    GetImmediateContext3,
+    CreateDeferredContext3,
+    WriteToSubresource,
+    ReadFromSubresource,
+    RegisterDeviceRemovedEvent,
+    UnregisterDeviceRemoved,
+}
+
+impl InterfaceMethods for DirectX11Device4Methods {
+    const INTERFACE: &'static str = "ID3D11Device4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11Device4_Vtbl as Vtbl;
+    use DirectX11Device4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateHullShader => M::CreateHullShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDomainShader => M::CreateDomainShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateComputeShader => M::CreateComputeShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateClassLinkage => M::CreateClassLinkage);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDeferredContext => M::CreateDeferredContext);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetFeatureLevel => M::GetFeatureLevel);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetImmediateContext => M::GetImmediateContext);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.base__.GetImmediateContext1 => M::GetImmediateContext1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDeferredContext1 => M::CreateDeferredContext1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateBlendState1 => M::CreateBlendState1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateRasterizerState1 => M::CreateRasterizerState1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDeviceContextState => M::CreateDeviceContextState);
+    assert_slot!(Vtbl, base__.base__.base__.OpenSharedResource1 => M::OpenSharedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.OpenSharedResourceByName => M::OpenSharedResourceByName);
+    assert_slot!(Vtbl, base__.base__.GetImmediateContext2 => M::GetImmediateContext2);
+    assert_slot!(Vtbl, base__.base__.CreateDeferredContext2 => M::CreateDeferredContext2);
+    assert_slot!(Vtbl, base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.CheckMultisampleQualityLevels1 => M::CheckMultisampleQualityLevels1);
+    assert_slot!(Vtbl, base__.CreateTexture2D1 => M::CreateTexture2D1);
+    assert_slot!(Vtbl, base__.CreateTexture3D1 => M::CreateTexture3D1);
+    assert_slot!(Vtbl, base__.CreateRasterizerState2 => M::CreateRasterizerState2);
+    assert_slot!(Vtbl, base__.CreateShaderResourceView1 => M::CreateShaderResourceView1);
+    assert_slot!(Vtbl, base__.CreateUnorderedAccessView1 => M::CreateUnorderedAccessView1);
+    assert_slot!(Vtbl, base__.CreateRenderTargetView1 => M::CreateRenderTargetView1);
+    assert_slot!(Vtbl, base__.CreateQuery1 => M::CreateQuery1);
+    assert_slot!(Vtbl, base__.GetImmediateContext3 => M::GetImmediateContext3);
+    assert_slot!(Vtbl, base__.CreateDeferredContext3 => M::CreateDeferredContext3);
+    assert_slot!(Vtbl, base__.WriteToSubresource => M::WriteToSubresource);
+    assert_slot!(Vtbl, base__.ReadFromSubresource => M::ReadFromSubresource);
+    assert_slot!(Vtbl, RegisterDeviceRemovedEvent => M::RegisterDeviceRemovedEvent);
+    assert_slot!(Vtbl, UnregisterDeviceRemoved => M::UnregisterDeviceRemoved);
+};
+
+/// `ID3D11Device5` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Device5Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    CreateBuffer,
+    CreateTexture1D,
+    CreateTexture2D,
+    CreateTexture3D,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateInputLayout,
+    CreateVertexShader,
+    CreateGeometryShader,
+    CreateGeometryShaderWithStreamOutput,
+    CreatePixelShader,
+    CreateHullShader,
+    CreateDomainShader,
+    CreateComputeShader,
+    CreateClassLinkage,
+    CreateBlendState,
+    CreateDepthStencilState,
+    CreateRasterizerState,
+    CreateSamplerState,
+    CreateQuery,
+    CreatePredicate,
+    CreateCounter,
+    CreateDeferredContext,
+    OpenSharedResource,
+    CheckFormatSupport,
+    CheckMultisampleQualityLevels,
+    CheckCounterInfo,
+    CheckCounter,
+    CheckFeatureSupport,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetFeatureLevel,
+    GetCreationFlags,
+    GetDeviceRemovedReason,
+    GetImmediateContext,
+    SetExceptionMode,
+    GetExceptionMode,
+    GetImmediateContext1,
+    CreateDeferredContext1,
+    CreateBlendState1,
+    CreateRasterizerState1,
+    CreateDeviceContextState,
+    OpenSharedResource1,
+    OpenSharedResourceByName,
+    GetImmediateContext2,
+    CreateDeferredContext2,
+    GetResourceTiling,
+    CheckMultisampleQualityLevels1,
+    CreateTexture2D1,
+    CreateTexture3D1,
+    CreateRasterizerState2,
+    CreateShaderResourceView1,
+    CreateUnorderedAccessView1,
+    CreateRenderTargetView1,
+    CreateQuery1,
+    GetImmediateContext3,
+    CreateDeferredContext3,
+    WriteToSubresource,
+    ReadFromSubresource,
+    RegisterDeviceRemovedEvent,
+    UnregisterDeviceRemoved,
+    OpenSharedFence,
+    CreateFence,
+}
+
+impl InterfaceMethods for DirectX11Device5Methods {
+    const INTERFACE: &'static str = "ID3D11Device5";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11Device5_Vtbl as Vtbl;
+    use DirectX11Device5Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateBuffer => M::CreateBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateTexture1D => M::CreateTexture1D);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateTexture2D => M::CreateTexture2D);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateTexture3D => M::CreateTexture3D);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateInputLayout => M::CreateInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateGeometryShader => M::CreateGeometryShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateGeometryShaderWithStreamOutput => M::CreateGeometryShaderWithStreamOutput);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateHullShader => M::CreateHullShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateDomainShader => M::CreateDomainShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateComputeShader => M::CreateComputeShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateClassLinkage => M::CreateClassLinkage);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateBlendState => M::CreateBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateDepthStencilState => M::CreateDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateRasterizerState => M::CreateRasterizerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSamplerState => M::CreateSamplerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreatePredicate => M::CreatePredicate);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCounter => M::CreateCounter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateDeferredContext => M::CreateDeferredContext);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OpenSharedResource => M::OpenSharedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckFormatSupport => M::CheckFormatSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckMultisampleQualityLevels => M::CheckMultisampleQualityLevels);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckCounterInfo => M::CheckCounterInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckCounter => M::CheckCounter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetFeatureLevel => M::GetFeatureLevel);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetImmediateContext => M::GetImmediateContext);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetExceptionMode => M::SetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetExceptionMode => M::GetExceptionMode);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetImmediateContext1 => M::GetImmediateContext1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDeferredContext1 => M::CreateDeferredContext1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateBlendState1 => M::CreateBlendState1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateRasterizerState1 => M::CreateRasterizerState1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDeviceContextState => M::CreateDeviceContextState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenSharedResource1 => M::OpenSharedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenSharedResourceByName => M::OpenSharedResourceByName);
+    assert_slot!(Vtbl, base__.base__.base__.GetImmediateContext2 => M::GetImmediateContext2);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDeferredContext2 => M::CreateDeferredContext2);
+    assert_slot!(Vtbl, base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.CheckMultisampleQualityLevels1 => M::CheckMultisampleQualityLevels1);
+    assert_slot!(Vtbl, base__.base__.CreateTexture2D1 => M::CreateTexture2D1);
+    assert_slot!(Vtbl, base__.base__.CreateTexture3D1 => M::CreateTexture3D1);
+    assert_slot!(Vtbl, base__.base__.CreateRasterizerState2 => M::CreateRasterizerState2);
+    assert_slot!(Vtbl, base__.base__.CreateShaderResourceView1 => M::CreateShaderResourceView1);
+    assert_slot!(Vtbl, base__.base__.CreateUnorderedAccessView1 => M::CreateUnorderedAccessView1);
+    assert_slot!(Vtbl, base__.base__.CreateRenderTargetView1 => M::CreateRenderTargetView1);
+    assert_slot!(Vtbl, base__.base__.CreateQuery1 => M::CreateQuery1);
+    assert_slot!(Vtbl, base__.base__.GetImmediateContext3 => M::GetImmediateContext3);
+    assert_slot!(Vtbl, base__.base__.CreateDeferredContext3 => M::CreateDeferredContext3);
+    assert_slot!(Vtbl, base__.base__.WriteToSubresource => M::WriteToSubresource);
+    assert_slot!(Vtbl, base__.base__.ReadFromSubresource => M::ReadFromSubresource);
+    assert_slot!(Vtbl, base__.RegisterDeviceRemovedEvent => M::RegisterDeviceRemovedEvent);
+    assert_slot!(Vtbl, base__.UnregisterDeviceRemoved => M::UnregisterDeviceRemoved);
+    assert_slot!(Vtbl, OpenSharedFence => M::OpenSharedFence);
+    assert_slot!(Vtbl, CreateFence => M::CreateFence);
+};
+
+/// `ID3D11DeviceContext1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Context1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetDevice,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    Map,
+    Unmap,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    Begin,
+    End,
+    GetData,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetRenderTargetsAndUnorderedAccessViews,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    DrawIndexedInstancedIndirect,
+    DrawInstancedIndirect,
+    Dispatch,
+    DispatchIndirect,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    CopyStructureCount,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    ClearDepthStencilView,
+    GenerateMips,
+    SetResourceMinLOD,
+    GetResourceMinLOD,
+    ResolveSubresource,
+    ExecuteCommandList,
+    HSSetShaderResources,
+    HSSetShader,
+    HSSetSamplers,
+    HSSetConstantBuffers,
+    DSSetShaderResources,
+    DSSetShader,
+    DSSetSamplers,
+    DSSetConstantBuffers,
+    CSSetShaderResources,
+    CSSetUnorderedAccessViews,
+    CSSetShader,
+    CSSetSamplers,
+    CSSetConstantBuffers,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetRenderTargetsAndUnorderedAccessViews,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    HSGetShaderResources,
+    HSGetShader,
+    HSGetSamplers,
+    HSGetConstantBuffers,
+    DSGetShaderResources,
+    DSGetShader,
+    DSGetSamplers,
+    DSGetConstantBuffers,
+    CSGetShaderResources,
+    CSGetUnorderedAccessViews,
+    CSGetShader,
+    CSGetSamplers,
+    CSGetConstantBuffers,
+    ClearState,
+    Flush,
+    GetType,
+    GetContextFlags,
+    FinishCommandList,
+    CopySubresourceRegion1,
+    UpdateSubresource1,
+    DiscardResource,
+    DiscardView,
+    VSSetConstantBuffers1,
+    HSSetConstantBuffers1,
+    DSSetConstantBuffers1,
+    GSSetConstantBuffers1,
+    PSSetConstantBuffers1,
+    CSSetConstantBuffers1,
+    VSGetConstantBuffers1,
+    HSGetConstantBuffers1,
+    DSGetConstantBuffers1,
+    GSGetConstantBuffers1,
+    PSGetConstantBuffers1,
+    CSGetConstantBuffers1,
+    SwapDeviceContextState,
+    ClearView,
+    DiscardView1,
+}
+
+impl InterfaceMethods for DirectX11Context1Methods {
+    const INTERFACE: &'static str = "ID3D11DeviceContext1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11DeviceContext1_Vtbl as Vtbl;
+    use DirectX11Context1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, base__.PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, base__.PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, base__.VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, base__.DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, base__.Draw => M::Draw);
+    assert_slot!(Vtbl, base__.Map => M::Map);
+    assert_slot!(Vtbl, base__.Unmap => M::Unmap);
+    assert_slot!(Vtbl, base__.PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, base__.VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, base__.Begin => M::Begin);
+    assert_slot!(Vtbl, base__.End => M::End);
+    assert_slot!(Vtbl, base__.GetData => M::GetData);
+    assert_slot!(Vtbl, base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, base__.GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.OMSetRenderTargetsAndUnorderedAccessViews => M::OMSetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, base__.OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, base__.DrawIndexedInstancedIndirect => M::DrawIndexedInstancedIndirect);
+    assert_slot!(Vtbl, base__.DrawInstancedIndirect => M::DrawInstancedIndirect);
+    assert_slot!(Vtbl, base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.DispatchIndirect => M::DispatchIndirect);
+    assert_slot!(Vtbl, base__.RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, base__.CopyStructureCount => M::CopyStructureCount);
+    assert_slot!(Vtbl, base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, base__.SetResourceMinLOD => M::SetResourceMinLOD);
+    assert_slot!(Vtbl, base__.GetResourceMinLOD => M::GetResourceMinLOD);
+    assert_slot!(Vtbl, base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.ExecuteCommandList => M::ExecuteCommandList);
+    assert_slot!(Vtbl, base__.HSSetShaderResources => M::HSSetShaderResources);
+    assert_slot!(Vtbl, base__.HSSetShader => M::HSSetShader);
+    assert_slot!(Vtbl, base__.HSSetSamplers => M::HSSetSamplers);
+    assert_slot!(Vtbl, base__.HSSetConstantBuffers => M::HSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.DSSetShaderResources => M::DSSetShaderResources);
+    assert_slot!(Vtbl, base__.DSSetShader => M::DSSetShader);
+    assert_slot!(Vtbl, base__.DSSetSamplers => M::DSSetSamplers);
+    assert_slot!(Vtbl, base__.DSSetConstantBuffers => M::DSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.CSSetShaderResources => M::CSSetShaderResources);
+    assert_slot!(Vtbl, base__.CSSetUnorderedAccessViews => M::CSSetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.CSSetShader => M::CSSetShader);
+    assert_slot!(Vtbl, base__.CSSetSamplers => M::CSSetSamplers);
+    assert_slot!(Vtbl, base__.CSSetConstantBuffers => M::CSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, base__.PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, base__.PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, base__.VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, base__.PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, base__.IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, base__.IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, base__.GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, base__.IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, base__.VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, base__.GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, base__.GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, base__.GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, base__.OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, base__.OMGetRenderTargetsAndUnorderedAccessViews => M::OMGetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, base__.OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, base__.SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, base__.RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, base__.RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, base__.RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, base__.HSGetShaderResources => M::HSGetShaderResources);
+    assert_slot!(Vtbl, base__.HSGetShader => M::HSGetShader);
+    assert_slot!(Vtbl, base__.HSGetSamplers => M::HSGetSamplers);
+    assert_slot!(Vtbl, base__.HSGetConstantBuffers => M::HSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.DSGetShaderResources => M::DSGetShaderResources);
+    assert_slot!(Vtbl, base__.DSGetShader => M::DSGetShader);
+    assert_slot!(Vtbl, base__.DSGetSamplers => M::DSGetSamplers);
+    assert_slot!(Vtbl, base__.DSGetConstantBuffers => M::DSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.CSGetShaderResources => M::CSGetShaderResources);
+    assert_slot!(Vtbl, base__.CSGetUnorderedAccessViews => M::CSGetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.CSGetShader => M::CSGetShader);
+    assert_slot!(Vtbl, base__.CSGetSamplers => M::CSGetSamplers);
+    assert_slot!(Vtbl, base__.CSGetConstantBuffers => M::CSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.Flush => M::Flush);
+    assert_slot!(Vtbl, base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.GetContextFlags => M::GetContextFlags);
+    assert_slot!(Vtbl, base__.FinishCommandList => M::FinishCommandList);
+    assert_slot!(Vtbl, CopySubresourceRegion1 => M::CopySubresourceRegion1);
+    assert_slot!(Vtbl, UpdateSubresource1 => M::UpdateSubresource1);
+    assert_slot!(Vtbl, DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, DiscardView => M::DiscardView);
+    assert_slot!(Vtbl, VSSetConstantBuffers1 => M::VSSetConstantBuffers1);
+    assert_slot!(Vtbl, HSSetConstantBuffers1 => M::HSSetConstantBuffers1);
+    assert_slot!(Vtbl, DSSetConstantBuffers1 => M::DSSetConstantBuffers1);
+    assert_slot!(Vtbl, GSSetConstantBuffers1 => M::GSSetConstantBuffers1);
+    assert_slot!(Vtbl, PSSetConstantBuffers1 => M::PSSetConstantBuffers1);
+    assert_slot!(Vtbl, CSSetConstantBuffers1 => M::CSSetConstantBuffers1);
+    assert_slot!(Vtbl, VSGetConstantBuffers1 => M::VSGetConstantBuffers1);
+    assert_slot!(Vtbl, HSGetConstantBuffers1 => M::HSGetConstantBuffers1);
+    assert_slot!(Vtbl, DSGetConstantBuffers1 => M::DSGetConstantBuffers1);
+    assert_slot!(Vtbl, GSGetConstantBuffers1 => M::GSGetConstantBuffers1);
+    assert_slot!(Vtbl, PSGetConstantBuffers1 => M::PSGetConstantBuffers1);
+    assert_slot!(Vtbl, CSGetConstantBuffers1 => M::CSGetConstantBuffers1);
+    assert_slot!(Vtbl, SwapDeviceContextState => M::SwapDeviceContextState);
+    assert_slot!(Vtbl, ClearView => M::ClearView);
+    assert_slot!(Vtbl, DiscardView1 => M::DiscardView1);
+};
+
+/// `ID3D11DeviceContext2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Context2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetDevice,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    Map,
+    Unmap,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    Begin,
+    End,
+    GetData,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetRenderTargetsAndUnorderedAccessViews,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    DrawIndexedInstancedIndirect,
+    DrawInstancedIndirect,
+    Dispatch,
+    DispatchIndirect,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    CopyStructureCount,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    ClearDepthStencilView,
+    GenerateMips,
+    SetResourceMinLOD,
+    GetResourceMinLOD,
+    ResolveSubresource,
+    ExecuteCommandList,
+    HSSetShaderResources,
+    HSSetShader,
+    HSSetSamplers,
+    HSSetConstantBuffers,
+    DSSetShaderResources,
+    DSSetShader,
+    DSSetSamplers,
+    DSSetConstantBuffers,
+    CSSetShaderResources,
+    CSSetUnorderedAccessViews,
+    CSSetShader,
+    CSSetSamplers,
+    CSSetConstantBuffers,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetRenderTargetsAndUnorderedAccessViews,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    HSGetShaderResources,
+    HSGetShader,
+    HSGetSamplers,
+    HSGetConstantBuffers,
+    DSGetShaderResources,
+    DSGetShader,
+    DSGetSamplers,
+    DSGetConstantBuffers,
+    CSGetShaderResources,
+    CSGetUnorderedAccessViews,
+    CSGetShader,
+    CSGetSamplers,
+    CSGetConstantBuffers,
+    ClearState,
+    Flush,
+    GetType,
+    GetContextFlags,
+    FinishCommandList,
+    CopySubresourceRegion1,
+    UpdateSubresource1,
+    DiscardResource,
+    DiscardView,
+    VSSetConstantBuffers1,
+    HSSetConstantBuffers1,
+    DSSetConstantBuffers1,
+    GSSetConstantBuffers1,
+    PSSetConstantBuffers1,
+    CSSetConstantBuffers1,
+    VSGetConstantBuffers1,
+    HSGetConstantBuffers1,
+    DSGetConstantBuffers1,
+    GSGetConstantBuffers1,
+    PSGetConstantBuffers1,
+    CSGetConstantBuffers1,
+    SwapDeviceContextState,
+    ClearView,
+    DiscardView1,
+    UpdateTileMappings,
+    CopyTileMappings,
+    CopyTiles,
+    UpdateTiles,
+    ResizeTilePool,
+    TiledResourceBarrier,
+    IsAnnotationEnabled,
+    SetMarkerInt,
+    BeginEventInt,
+    EndEvent,
+}
+
+impl InterfaceMethods for DirectX11Context2Methods {
+    const INTERFACE: &'static str = "ID3D11DeviceContext2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11DeviceContext2_Vtbl as Vtbl;
+    use DirectX11Context2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, base__.base__.PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, base__.base__.DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, base__.base__.Draw => M::Draw);
+    assert_slot!(Vtbl, base__.base__.Map => M::Map);
+    assert_slot!(Vtbl, base__.base__.Unmap => M::Unmap);
+    assert_slot!(Vtbl, base__.base__.PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.Begin => M::Begin);
+    assert_slot!(Vtbl, base__.base__.End => M::End);
+    assert_slot!(Vtbl, base__.base__.GetData => M::GetData);
+    assert_slot!(Vtbl, base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.OMSetRenderTargetsAndUnorderedAccessViews => M::OMSetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, base__.base__.OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, base__.base__.DrawIndexedInstancedIndirect => M::DrawIndexedInstancedIndirect);
+    assert_slot!(Vtbl, base__.base__.DrawInstancedIndirect => M::DrawInstancedIndirect);
+    assert_slot!(Vtbl, base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.DispatchIndirect => M::DispatchIndirect);
+    assert_slot!(Vtbl, base__.base__.RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, base__.base__.CopyStructureCount => M::CopyStructureCount);
+    assert_slot!(Vtbl, base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, base__.base__.SetResourceMinLOD => M::SetResourceMinLOD);
+    assert_slot!(Vtbl, base__.base__.GetResourceMinLOD => M::GetResourceMinLOD);
+    assert_slot!(Vtbl, base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.ExecuteCommandList => M::ExecuteCommandList);
+    assert_slot!(Vtbl, base__.base__.HSSetShaderResources => M::HSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.HSSetShader => M::HSSetShader);
+    assert_slot!(Vtbl, base__.base__.HSSetSamplers => M::HSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.HSSetConstantBuffers => M::HSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.DSSetShaderResources => M::DSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.DSSetShader => M::DSSetShader);
+    assert_slot!(Vtbl, base__.base__.DSSetSamplers => M::DSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.DSSetConstantBuffers => M::DSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.CSSetShaderResources => M::CSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.CSSetUnorderedAccessViews => M::CSSetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.CSSetShader => M::CSSetShader);
+    assert_slot!(Vtbl, base__.base__.CSSetSamplers => M::CSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.CSSetConstantBuffers => M::CSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, base__.base__.PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, base__.base__.PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, base__.base__.IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, base__.base__.IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, base__.base__.GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.OMGetRenderTargetsAndUnorderedAccessViews => M::OMGetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, base__.base__.OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, base__.base__.RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, base__.base__.RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, base__.base__.RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, base__.base__.HSGetShaderResources => M::HSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.HSGetShader => M::HSGetShader);
+    assert_slot!(Vtbl, base__.base__.HSGetSamplers => M::HSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.HSGetConstantBuffers => M::HSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.DSGetShaderResources => M::DSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.DSGetShader => M::DSGetShader);
+    assert_slot!(Vtbl, base__.base__.DSGetSamplers => M::DSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.DSGetConstantBuffers => M::DSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.CSGetShaderResources => M::CSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.CSGetUnorderedAccessViews => M::CSGetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.CSGetShader => M::CSGetShader);
+    assert_slot!(Vtbl, base__.base__.CSGetSamplers => M::CSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.CSGetConstantBuffers => M::CSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.Flush => M::Flush);
+    assert_slot!(Vtbl, base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.GetContextFlags => M::GetContextFlags);
+    assert_slot!(Vtbl, base__.base__.FinishCommandList => M::FinishCommandList);
+    assert_slot!(Vtbl, base__.CopySubresourceRegion1 => M::CopySubresourceRegion1);
+    assert_slot!(Vtbl, base__.UpdateSubresource1 => M::UpdateSubresource1);
+    assert_slot!(Vtbl, base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.DiscardView => M::DiscardView);
+    assert_slot!(Vtbl, base__.VSSetConstantBuffers1 => M::VSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.HSSetConstantBuffers1 => M::HSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.DSSetConstantBuffers1 => M::DSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.GSSetConstantBuffers1 => M::GSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.PSSetConstantBuffers1 => M::PSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.CSSetConstantBuffers1 => M::CSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.VSGetConstantBuffers1 => M::VSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.HSGetConstantBuffers1 => M::HSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.DSGetConstantBuffers1 => M::DSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.GSGetConstantBuffers1 => M::GSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.PSGetConstantBuffers1 => M::PSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.CSGetConstantBuffers1 => M::CSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.SwapDeviceContextState => M::SwapDeviceContextState);
+    assert_slot!(Vtbl, base__.ClearView => M::ClearView);
+    assert_slot!(Vtbl, base__.DiscardView1 => M::DiscardView1);
+    assert_slot!(Vtbl, UpdateTileMappings => M::UpdateTileMappings);
+    assert_slot!(Vtbl, CopyTileMappings => M::CopyTileMappings);
+    assert_slot!(Vtbl, CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, UpdateTiles => M::UpdateTiles);
+    assert_slot!(Vtbl, ResizeTilePool => M::ResizeTilePool);
+    assert_slot!(Vtbl, TiledResourceBarrier => M::TiledResourceBarrier);
+    assert_slot!(Vtbl, IsAnnotationEnabled => M::IsAnnotationEnabled);
+    assert_slot!(Vtbl, SetMarkerInt => M::SetMarkerInt);
+    assert_slot!(Vtbl, BeginEventInt => M::BeginEventInt);
+    assert_slot!(Vtbl, EndEvent => M::EndEvent);
+};
+
+/// `ID3D11DeviceContext3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Context3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetDevice,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    Map,
+    Unmap,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    Begin,
+    End,
+    GetData,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetRenderTargetsAndUnorderedAccessViews,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    DrawIndexedInstancedIndirect,
+    DrawInstancedIndirect,
+    Dispatch,
+    DispatchIndirect,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    CopyStructureCount,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    ClearDepthStencilView,
+    GenerateMips,
+    SetResourceMinLOD,
+    GetResourceMinLOD,
+    ResolveSubresource,
+    ExecuteCommandList,
+    HSSetShaderResources,
+    HSSetShader,
+    HSSetSamplers,
+    HSSetConstantBuffers,
+    DSSetShaderResources,
+    DSSetShader,
+    DSSetSamplers,
+    DSSetConstantBuffers,
+    CSSetShaderResources,
+    CSSetUnorderedAccessViews,
+    CSSetShader,
+    CSSetSamplers,
+    CSSetConstantBuffers,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetRenderTargetsAndUnorderedAccessViews,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    HSGetShaderResources,
+    HSGetShader,
+    HSGetSamplers,
+    HSGetConstantBuffers,
+    DSGetShaderResources,
+    DSGetShader,
+    DSGetSamplers,
+    DSGetConstantBuffers,
+    CSGetShaderResources,
+    CSGetUnorderedAccessViews,
+    CSGetShader,
+    CSGetSamplers,
+    CSGetConstantBuffers,
+    ClearState,
+    Flush,
+    GetType,
+    GetContextFlags,
+    FinishCommandList,
+    CopySubresourceRegion1,
+    UpdateSubresource1,
+    DiscardResource,
+    DiscardView,
+    VSSetConstantBuffers1,
+    HSSetConstantBuffers1,
+    DSSetConstantBuffers1,
+    GSSetConstantBuffers1,
+    PSSetConstantBuffers1,
+    CSSetConstantBuffers1,
+    VSGetConstantBuffers1,
+    HSGetConstantBuffers1,
+    DSGetConstantBuffers1,
+    GSGetConstantBuffers1,
+    PSGetConstantBuffers1,
+    CSGetConstantBuffers1,
+    SwapDeviceContextState,
+    ClearView,
+    DiscardView1,
+    UpdateTileMappings,
+    CopyTileMappings,
+    CopyTiles,
+    UpdateTiles,
+    ResizeTilePool,
+    TiledResourceBarrier,
+    IsAnnotationEnabled,
+    SetMarkerInt,
+    BeginEventInt,
+    EndEvent,
+    Flush1,
+    SetHardwareProtectionState,
+    GetHardwareProtectionState,
+}
+
+impl InterfaceMethods for DirectX11Context3Methods {
+    const INTERFACE: &'static str = "ID3D11DeviceContext3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11DeviceContext3_Vtbl as Vtbl;
+    use DirectX11Context3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, base__.base__.base__.Draw => M::Draw);
+    assert_slot!(Vtbl, base__.base__.base__.Map => M::Map);
+    assert_slot!(Vtbl, base__.base__.base__.Unmap => M::Unmap);
+    assert_slot!(Vtbl, base__.base__.base__.PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.Begin => M::Begin);
+    assert_slot!(Vtbl, base__.base__.base__.End => M::End);
+    assert_slot!(Vtbl, base__.base__.base__.GetData => M::GetData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetRenderTargetsAndUnorderedAccessViews => M::OMSetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, base__.base__.base__.DrawIndexedInstancedIndirect => M::DrawIndexedInstancedIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.DrawInstancedIndirect => M::DrawInstancedIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.DispatchIndirect => M::DispatchIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.CopyStructureCount => M::CopyStructureCount);
+    assert_slot!(Vtbl, base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, base__.base__.base__.SetResourceMinLOD => M::SetResourceMinLOD);
+    assert_slot!(Vtbl, base__.base__.base__.GetResourceMinLOD => M::GetResourceMinLOD);
+    assert_slot!(Vtbl, base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.ExecuteCommandList => M::ExecuteCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.HSSetShaderResources => M::HSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.HSSetShader => M::HSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.HSSetSamplers => M::HSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.HSSetConstantBuffers => M::HSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.DSSetShaderResources => M::DSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.DSSetShader => M::DSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.DSSetSamplers => M::DSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.DSSetConstantBuffers => M::DSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.CSSetShaderResources => M::CSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.CSSetUnorderedAccessViews => M::CSSetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.CSSetShader => M::CSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.CSSetSamplers => M::CSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.CSSetConstantBuffers => M::CSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.OMGetRenderTargetsAndUnorderedAccessViews => M::OMGetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, base__.base__.base__.RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.HSGetShaderResources => M::HSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.HSGetShader => M::HSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.HSGetSamplers => M::HSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.HSGetConstantBuffers => M::HSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.DSGetShaderResources => M::DSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.DSGetShader => M::DSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.DSGetSamplers => M::DSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.DSGetConstantBuffers => M::DSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.CSGetShaderResources => M::CSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.CSGetUnorderedAccessViews => M::CSGetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.CSGetShader => M::CSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.CSGetSamplers => M::CSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.CSGetConstantBuffers => M::CSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.Flush => M::Flush);
+    assert_slot!(Vtbl, base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.GetContextFlags => M::GetContextFlags);
+    assert_slot!(Vtbl, base__.base__.base__.FinishCommandList => M::FinishCommandList);
+    assert_slot!(Vtbl, base__.base__.CopySubresourceRegion1 => M::CopySubresourceRegion1);
+    assert_slot!(Vtbl, base__.base__.UpdateSubresource1 => M::UpdateSubresource1);
+    assert_slot!(Vtbl, base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.DiscardView => M::DiscardView);
+    assert_slot!(Vtbl, base__.base__.VSSetConstantBuffers1 => M::VSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.HSSetConstantBuffers1 => M::HSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.DSSetConstantBuffers1 => M::DSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.GSSetConstantBuffers1 => M::GSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.PSSetConstantBuffers1 => M::PSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.CSSetConstantBuffers1 => M::CSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.VSGetConstantBuffers1 => M::VSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.HSGetConstantBuffers1 => M::HSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.DSGetConstantBuffers1 => M::DSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.GSGetConstantBuffers1 => M::GSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.PSGetConstantBuffers1 => M::PSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.CSGetConstantBuffers1 => M::CSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.SwapDeviceContextState => M::SwapDeviceContextState);
+    assert_slot!(Vtbl, base__.base__.ClearView => M::ClearView);
+    assert_slot!(Vtbl, base__.base__.DiscardView1 => M::DiscardView1);
+    assert_slot!(Vtbl, base__.UpdateTileMappings => M::UpdateTileMappings);
+    assert_slot!(Vtbl, base__.CopyTileMappings => M::CopyTileMappings);
+    assert_slot!(Vtbl, base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.UpdateTiles => M::UpdateTiles);
+    assert_slot!(Vtbl, base__.ResizeTilePool => M::ResizeTilePool);
+    assert_slot!(Vtbl, base__.TiledResourceBarrier => M::TiledResourceBarrier);
+    assert_slot!(Vtbl, base__.IsAnnotationEnabled => M::IsAnnotationEnabled);
+    assert_slot!(Vtbl, base__.SetMarkerInt => M::SetMarkerInt);
+    assert_slot!(Vtbl, base__.BeginEventInt => M::BeginEventInt);
+    assert_slot!(Vtbl, base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, Flush1 => M::Flush1);
+    assert_slot!(Vtbl, SetHardwareProtectionState => M::SetHardwareProtectionState);
+    assert_slot!(Vtbl, GetHardwareProtectionState => M::GetHardwareProtectionState);
+};
+
+/// `ID3D11DeviceContext4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX11Context4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetDevice,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    VSSetConstantBuffers,
+    PSSetShaderResources,
+    PSSetShader,
+    PSSetSamplers,
+    VSSetShader,
+    DrawIndexed,
+    Draw,
+    Map,
+    Unmap,
+    PSSetConstantBuffers,
+    IASetInputLayout,
+    IASetVertexBuffers,
+    IASetIndexBuffer,
+    DrawIndexedInstanced,
+    DrawInstanced,
+    GSSetConstantBuffers,
+    GSSetShader,
+    IASetPrimitiveTopology,
+    VSSetShaderResources,
+    VSSetSamplers,
+    Begin,
+    End,
+    GetData,
+    SetPredication,
+    GSSetShaderResources,
+    GSSetSamplers,
+    OMSetRenderTargets,
+    OMSetRenderTargetsAndUnorderedAccessViews,
+    OMSetBlendState,
+    OMSetDepthStencilState,
+    SOSetTargets,
+    DrawAuto,
+    DrawIndexedInstancedIndirect,
+    DrawInstancedIndirect,
+    Dispatch,
+    DispatchIndirect,
+    RSSetState,
+    RSSetViewports,
+    RSSetScissorRects,
+    CopySubresourceRegion,
+    CopyResource,
+    UpdateSubresource,
+    CopyStructureCount,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    ClearDepthStencilView,
+    GenerateMips,
+    SetResourceMinLOD,
+    GetResourceMinLOD,
+    ResolveSubresource,
+    ExecuteCommandList,
+    HSSetShaderResources,
+    HSSetShader,
+    HSSetSamplers,
+    HSSetConstantBuffers,
+    DSSetShaderResources,
+    DSSetShader,
+    DSSetSamplers,
+    DSSetConstantBuffers,
+    CSSetShaderResources,
+    CSSetUnorderedAccessViews,
+    CSSetShader,
+    CSSetSamplers,
+    CSSetConstantBuffers,
+    VSGetConstantBuffers,
+    PSGetShaderResources,
+    PSGetShader,
+    PSGetSamplers,
+    VSGetShader,
+    PSGetConstantBuffers,
+    IAGetInputLayout,
+    IAGetVertexBuffers,
+    IAGetIndexBuffer,
+    GSGetConstantBuffers,
+    GSGetShader,
+    IAGetPrimitiveTopology,
+    VSGetShaderResources,
+    VSGetSamplers,
+    GetPredication,
+    GSGetShaderResources,
+    GSGetSamplers,
+    OMGetRenderTargets,
+    OMGetRenderTargetsAndUnorderedAccessViews,
+    OMGetBlendState,
+    OMGetDepthStencilState,
+    SOGetTargets,
+    RSGetState,
+    RSGetViewports,
+    RSGetScissorRects,
+    HSGetShaderResources,
+    HSGetShader,
+    HSGetSamplers,
+    HSGetConstantBuffers,
+    DSGetShaderResources,
+    DSGetShader,
+    DSGetSamplers,
+    DSGetConstantBuffers,
+    CSGetShaderResources,
+    CSGetUnorderedAccessViews,
+    CSGetShader,
+    CSGetSamplers,
+    CSGetConstantBuffers,
+    ClearState,
+    Flush,
+    GetType,
+    GetContextFlags,
+    FinishCommandList,
+    CopySubresourceRegion1,
+    UpdateSubresource1,
+    DiscardResource,
+    DiscardView,
+    VSSetConstantBuffers1,
+    HSSetConstantBuffers1,
+    DSSetConstantBuffers1,
+    GSSetConstantBuffers1,
+    PSSetConstantBuffers1,
+    CSSetConstantBuffers1,
+    VSGetConstantBuffers1,
+    HSGetConstantBuffers1,
+    DSGetConstantBuffers1,
+    GSGetConstantBuffers1,
+    PSGetConstantBuffers1,
+    CSGetConstantBuffers1,
+    SwapDeviceContextState,
+    ClearView,
+    DiscardView1,
+    UpdateTileMappings,
+    CopyTileMappings,
+    CopyTiles,
+    UpdateTiles,
+    ResizeTilePool,
+    TiledResourceBarrier,
+    IsAnnotationEnabled,
+    SetMarkerInt,
+    BeginEventInt,
+    EndEvent,
+    Flush1,
+    SetHardwareProtectionState,
+    GetHardwareProtectionState,
+    Signal,
+    Wait,
+}
+
+impl InterfaceMethods for DirectX11Context4Methods {
+    const INTERFACE: &'static str = "ID3D11DeviceContext4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D11::ID3D11DeviceContext4_Vtbl as Vtbl;
+    use DirectX11Context4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSSetConstantBuffers => M::VSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSSetShaderResources => M::PSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSSetShader => M::PSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSSetSamplers => M::PSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSSetShader => M::VSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawIndexed => M::DrawIndexed);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Draw => M::Draw);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Map => M::Map);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Unmap => M::Unmap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSSetConstantBuffers => M::PSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetInputLayout => M::IASetInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSSetConstantBuffers => M::GSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSSetShader => M::GSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSSetShaderResources => M::VSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSSetSamplers => M::VSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Begin => M::Begin);
+    assert_slot!(Vtbl, base__.base__.base__.base__.End => M::End);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetData => M::GetData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSSetShaderResources => M::GSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSSetSamplers => M::GSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetRenderTargetsAndUnorderedAccessViews => M::OMSetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetBlendState => M::OMSetBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetDepthStencilState => M::OMSetDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawAuto => M::DrawAuto);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawIndexedInstancedIndirect => M::DrawIndexedInstancedIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawInstancedIndirect => M::DrawInstancedIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DispatchIndirect => M::DispatchIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSSetState => M::RSSetState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopySubresourceRegion => M::CopySubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.UpdateSubresource => M::UpdateSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyStructureCount => M::CopyStructureCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GenerateMips => M::GenerateMips);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetResourceMinLOD => M::SetResourceMinLOD);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetResourceMinLOD => M::GetResourceMinLOD);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ExecuteCommandList => M::ExecuteCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSSetShaderResources => M::HSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSSetShader => M::HSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSSetSamplers => M::HSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSSetConstantBuffers => M::HSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSSetShaderResources => M::DSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSSetShader => M::DSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSSetSamplers => M::DSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSSetConstantBuffers => M::DSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSSetShaderResources => M::CSSetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSSetUnorderedAccessViews => M::CSSetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSSetShader => M::CSSetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSSetSamplers => M::CSSetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSSetConstantBuffers => M::CSSetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSGetConstantBuffers => M::VSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSGetShaderResources => M::PSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSGetShader => M::PSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSGetSamplers => M::PSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSGetShader => M::VSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.PSGetConstantBuffers => M::PSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IAGetInputLayout => M::IAGetInputLayout);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IAGetVertexBuffers => M::IAGetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IAGetIndexBuffer => M::IAGetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSGetConstantBuffers => M::GSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSGetShader => M::GSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IAGetPrimitiveTopology => M::IAGetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSGetShaderResources => M::VSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.VSGetSamplers => M::VSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPredication => M::GetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSGetShaderResources => M::GSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GSGetSamplers => M::GSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMGetRenderTargets => M::OMGetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMGetRenderTargetsAndUnorderedAccessViews => M::OMGetRenderTargetsAndUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMGetBlendState => M::OMGetBlendState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMGetDepthStencilState => M::OMGetDepthStencilState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SOGetTargets => M::SOGetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSGetState => M::RSGetState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSGetViewports => M::RSGetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSGetScissorRects => M::RSGetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSGetShaderResources => M::HSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSGetShader => M::HSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSGetSamplers => M::HSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.HSGetConstantBuffers => M::HSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSGetShaderResources => M::DSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSGetShader => M::DSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSGetSamplers => M::DSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DSGetConstantBuffers => M::DSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSGetShaderResources => M::CSGetShaderResources);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSGetUnorderedAccessViews => M::CSGetUnorderedAccessViews);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSGetShader => M::CSGetShader);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSGetSamplers => M::CSGetSamplers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CSGetConstantBuffers => M::CSGetConstantBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Flush => M::Flush);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetContextFlags => M::GetContextFlags);
+    assert_slot!(Vtbl, base__.base__.base__.base__.FinishCommandList => M::FinishCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.CopySubresourceRegion1 => M::CopySubresourceRegion1);
+    assert_slot!(Vtbl, base__.base__.base__.UpdateSubresource1 => M::UpdateSubresource1);
+    assert_slot!(Vtbl, base__.base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.base__.DiscardView => M::DiscardView);
+    assert_slot!(Vtbl, base__.base__.base__.VSSetConstantBuffers1 => M::VSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.HSSetConstantBuffers1 => M::HSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.DSSetConstantBuffers1 => M::DSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.GSSetConstantBuffers1 => M::GSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.PSSetConstantBuffers1 => M::PSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.CSSetConstantBuffers1 => M::CSSetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.VSGetConstantBuffers1 => M::VSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.HSGetConstantBuffers1 => M::HSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.DSGetConstantBuffers1 => M::DSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.GSGetConstantBuffers1 => M::GSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.PSGetConstantBuffers1 => M::PSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.CSGetConstantBuffers1 => M::CSGetConstantBuffers1);
+    assert_slot!(Vtbl, base__.base__.base__.SwapDeviceContextState => M::SwapDeviceContextState);
+    assert_slot!(Vtbl, base__.base__.base__.ClearView => M::ClearView);
+    assert_slot!(Vtbl, base__.base__.base__.DiscardView1 => M::DiscardView1);
+    assert_slot!(Vtbl, base__.base__.UpdateTileMappings => M::UpdateTileMappings);
+    assert_slot!(Vtbl, base__.base__.CopyTileMappings => M::CopyTileMappings);
+    assert_slot!(Vtbl, base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.UpdateTiles => M::UpdateTiles);
+    assert_slot!(Vtbl, base__.base__.ResizeTilePool => M::ResizeTilePool);
+    assert_slot!(Vtbl, base__.base__.TiledResourceBarrier => M::TiledResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.IsAnnotationEnabled => M::IsAnnotationEnabled);
+    assert_slot!(Vtbl, base__.base__.SetMarkerInt => M::SetMarkerInt);
+    assert_slot!(Vtbl, base__.base__.BeginEventInt => M::BeginEventInt);
+    assert_slot!(Vtbl, base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.Flush1 => M::Flush1);
+    assert_slot!(Vtbl, base__.SetHardwareProtectionState => M::SetHardwareProtectionState);
+    assert_slot!(Vtbl, base__.GetHardwareProtectionState => M::GetHardwareProtectionState);
+    assert_slot!(Vtbl, Signal => M::Signal);
+    assert_slot!(Vtbl, Wait => M::Wait);
+};
+
+/// Typed signatures of [`DirectX11SwapchainMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain {
+    use std::ffi::c_void;
+
+    use super::DirectX11SwapchainMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11SwapchainMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX11DeviceMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device {
+    use std::ffi::c_void;
+
+    use super::DirectX11DeviceMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11DeviceMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_desc: *mut c_void, pp_ua_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, p_buffer_strides: *mut u32, num_strides: u32, rasterized_stream: u32, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateHullShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_hull_shader: *mut *mut c_void) -> HRESULT,
+        CreateDomainShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_domain_shader: *mut *mut c_void) -> HRESULT,
+        CreateComputeShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_compute_shader: *mut *mut c_void) -> HRESULT,
+        CreateClassLinkage(pp_linkage: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CreateDeferredContext(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+        GetCreationFlags() -> u32,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetImmediateContext(pp_immediate_context: *mut *mut c_void),
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+    }
+}
+
+/// Typed signatures of [`DirectX11ContextMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod context {
+    use std::ffi::c_void;
+
+    use super::DirectX11ContextMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11ContextMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetDevice(pp_device: *mut *mut c_void),
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        Map(p_resource: *mut c_void, subresource: u32, map_type: i32, map_flags: u32, p_mapped_resource: *mut c_void) -> HRESULT,
+        Unmap(p_resource: *mut c_void, subresource: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        Begin(p_async: *mut c_void),
+        End(p_async: *mut c_void),
+        GetData(p_async: *mut c_void, p_data: *mut c_void, data_size: u32, get_data_flags: u32) -> HRESULT,
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        DrawIndexedInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        DrawInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        DispatchIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        CopyStructureCount(p_dst_buffer: *mut c_void, dst_aligned_byte_offset: u32, p_src_view: *mut c_void),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearUnorderedAccessViewUint(p_unordered_access_view: *mut c_void, values: *mut u32),
+        ClearUnorderedAccessViewFloat(p_unordered_access_view: *mut c_void, values: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        SetResourceMinLOD(p_resource: *mut c_void, min_lod: f32),
+        GetResourceMinLOD(p_resource: *mut c_void) -> f32,
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        ExecuteCommandList(p_command_list: *mut c_void, restore_context_state: i32),
+        HSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSSetShader(p_hull_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        HSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSSetShader(p_domain_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSSetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        CSSetShader(p_compute_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        CSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(p_num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(p_num_rects: *mut u32, p_rects: *mut c_void),
+        HSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSGetShader(pp_hull_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        HSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSGetShader(pp_domain_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        DSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSGetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        CSGetShader(pp_compute_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        CSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        ClearState(),
+        Flush(),
+        GetType() -> i32,
+        GetContextFlags() -> u32,
+        FinishCommandList(restore_deferred_context_state: i32, pp_command_list: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX11Device1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device1 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Device1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Device1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_desc: *mut c_void, pp_ua_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, p_buffer_strides: *mut u32, num_strides: u32, rasterized_stream: u32, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateHullShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_hull_shader: *mut *mut c_void) -> HRESULT,
+        CreateDomainShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_domain_shader: *mut *mut c_void) -> HRESULT,
+        CreateComputeShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_compute_shader: *mut *mut c_void) -> HRESULT,
+        CreateClassLinkage(pp_linkage: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CreateDeferredContext(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+        GetCreationFlags() -> u32,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetImmediateContext(pp_immediate_context: *mut *mut c_void),
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetImmediateContext1(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext1(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        CreateBlendState1(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState1(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateDeviceContextState(flags: u32, p_feature_levels: *mut i32, feature_levels: u32, sdk_version: u32, emulated_interface: *mut c_void, p_chosen_feature_level: *mut i32, pp_context_state: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource1(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        OpenSharedResourceByName(lp_name: *mut u16, dw_desired_access: u32, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX11Device2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device2 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Device2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Device2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_desc: *mut c_void, pp_ua_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, p_buffer_strides: *mut u32, num_strides: u32, rasterized_stream: u32, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateHullShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_hull_shader: *mut *mut c_void) -> HRESULT,
+        CreateDomainShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_domain_shader: *mut *mut c_void) -> HRESULT,
+        CreateComputeShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_compute_shader: *mut *mut c_void) -> HRESULT,
+        CreateClassLinkage(pp_linkage: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CreateDeferredContext(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+        GetCreationFlags() -> u32,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetImmediateContext(pp_immediate_context: *mut *mut c_void),
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetImmediateContext1(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext1(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        CreateBlendState1(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState1(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateDeviceContextState(flags: u32, p_feature_levels: *mut i32, feature_levels: u32, sdk_version: u32, emulated_interface: *mut c_void, p_chosen_feature_level: *mut i32, pp_context_state: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource1(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        OpenSharedResourceByName(lp_name: *mut u16, dw_desired_access: u32, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext2(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext2(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        CheckMultisampleQualityLevels1(format: i32, sample_count: u32, flags: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX11Device3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device3 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Device3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Device3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_desc: *mut c_void, pp_ua_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, p_buffer_strides: *mut u32, num_strides: u32, rasterized_stream: u32, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateHullShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_hull_shader: *mut *mut c_void) -> HRESULT,
+        CreateDomainShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_domain_shader: *mut *mut c_void) -> HRESULT,
+        CreateComputeShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_compute_shader: *mut *mut c_void) -> HRESULT,
+        CreateClassLinkage(pp_linkage: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CreateDeferredContext(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+        GetCreationFlags() -> u32,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetImmediateContext(pp_immediate_context: *mut *mut c_void),
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetImmediateContext1(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext1(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        CreateBlendState1(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState1(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateDeviceContextState(flags: u32, p_feature_levels: *mut i32, feature_levels: u32, sdk_version: u32, emulated_interface: *mut c_void, p_chosen_feature_level: *mut i32, pp_context_state: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource1(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        OpenSharedResourceByName(lp_name: *mut u16, dw_desired_access: u32, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext2(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext2(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        CheckMultisampleQualityLevels1(format: i32, sample_count: u32, flags: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CreateTexture2D1(p_desc1: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D1(p_desc1: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState2(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_sr_view1: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_ua_view1: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_rt_view1: *mut *mut c_void) -> HRESULT,
+        CreateQuery1(p_query_desc1: *mut c_void, pp_query1: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext3(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext3(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        WriteToSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        ReadFromSubresource(p_dst_data: *mut c_void, dst_row_pitch: u32, dst_depth_pitch: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX11Device4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device4 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Device4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Device4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_desc: *mut c_void, pp_ua_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, p_buffer_strides: *mut u32, num_strides: u32, rasterized_stream: u32, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateHullShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_hull_shader: *mut *mut c_void) -> HRESULT,
+        CreateDomainShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_domain_shader: *mut *mut c_void) -> HRESULT,
+        CreateComputeShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_compute_shader: *mut *mut c_void) -> HRESULT,
+        CreateClassLinkage(pp_linkage: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CreateDeferredContext(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+        GetCreationFlags() -> u32,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetImmediateContext(pp_immediate_context: *mut *mut c_void),
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetImmediateContext1(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext1(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        CreateBlendState1(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState1(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateDeviceContextState(flags: u32, p_feature_levels: *mut i32, feature_levels: u32, sdk_version: u32, emulated_interface: *mut c_void, p_chosen_feature_level: *mut i32, pp_context_state: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource1(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        OpenSharedResourceByName(lp_name: *mut u16, dw_desired_access: u32, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext2(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext2(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        CheckMultisampleQualityLevels1(format: i32, sample_count: u32, flags: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CreateTexture2D1(p_desc1: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D1(p_desc1: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState2(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_sr_view1: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_ua_view1: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_rt_view1: *mut *mut c_void) -> HRESULT,
+        CreateQuery1(p_query_desc1: *mut c_void, pp_query1: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext3(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext3(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        WriteToSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        ReadFromSubresource(p_dst_data: *mut c_void, dst_row_pitch: u32, dst_depth_pitch: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        RegisterDeviceRemovedEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterDeviceRemoved(dw_cookie: u32),
+    }
+}
+
+/// Typed signatures of [`DirectX11Device5Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device5 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Device5Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Device5Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        CreateBuffer(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_buffer: *mut *mut c_void) -> HRESULT,
+        CreateTexture1D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture1d: *mut *mut c_void) -> HRESULT,
+        CreateTexture2D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D(p_desc: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, pp_sr_view: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_desc: *mut c_void, pp_ua_view: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, pp_rt_view: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, pp_depth_stencil_view: *mut *mut c_void) -> HRESULT,
+        CreateInputLayout(p_input_element_descs: *mut c_void, num_elements: u32, p_shader_bytecode_with_input_signature: *mut c_void, bytecode_length: usize, pp_input_layout: *mut *mut c_void) -> HRESULT,
+        CreateVertexShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_vertex_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreateGeometryShaderWithStreamOutput(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_so_declaration: *mut c_void, num_entries: u32, p_buffer_strides: *mut u32, num_strides: u32, rasterized_stream: u32, p_class_linkage: *mut c_void, pp_geometry_shader: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_pixel_shader: *mut *mut c_void) -> HRESULT,
+        CreateHullShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_hull_shader: *mut *mut c_void) -> HRESULT,
+        CreateDomainShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_domain_shader: *mut *mut c_void) -> HRESULT,
+        CreateComputeShader(p_shader_bytecode: *mut c_void, bytecode_length: usize, p_class_linkage: *mut c_void, pp_compute_shader: *mut *mut c_void) -> HRESULT,
+        CreateClassLinkage(pp_linkage: *mut *mut c_void) -> HRESULT,
+        CreateBlendState(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilState(p_depth_stencil_desc: *mut c_void, pp_depth_stencil_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateSamplerState(p_sampler_desc: *mut c_void, pp_sampler_state: *mut *mut c_void) -> HRESULT,
+        CreateQuery(p_query_desc: *mut c_void, pp_query: *mut *mut c_void) -> HRESULT,
+        CreatePredicate(p_predicate_desc: *mut c_void, pp_predicate: *mut *mut c_void) -> HRESULT,
+        CreateCounter(p_counter_desc: *mut c_void, pp_counter: *mut *mut c_void) -> HRESULT,
+        CreateDeferredContext(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        CheckFormatSupport(format: i32, p_format_support: *mut u32) -> HRESULT,
+        CheckMultisampleQualityLevels(format: i32, sample_count: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CheckCounterInfo(p_counter_info: *mut c_void),
+        CheckCounter(p_desc: *mut c_void, p_type: *mut i32, p_active_counters: *mut u32, sz_name: *mut u8, p_name_length: *mut u32, sz_units: *mut u8, p_units_length: *mut u32, sz_description: *mut u8, p_description_length: *mut u32) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        GetFeatureLevel() -> i32,
+        GetCreationFlags() -> u32,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetImmediateContext(pp_immediate_context: *mut *mut c_void),
+        SetExceptionMode(raise_flags: u32) -> HRESULT,
+        GetExceptionMode() -> u32,
+        GetImmediateContext1(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext1(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        CreateBlendState1(p_blend_state_desc: *mut c_void, pp_blend_state: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState1(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateDeviceContextState(flags: u32, p_feature_levels: *mut i32, feature_levels: u32, sdk_version: u32, emulated_interface: *mut c_void, p_chosen_feature_level: *mut i32, pp_context_state: *mut *mut c_void) -> HRESULT,
+        OpenSharedResource1(h_resource: *mut c_void, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        OpenSharedResourceByName(lp_name: *mut u16, dw_desired_access: u32, returned_interface: *mut c_void, pp_resource: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext2(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext2(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        CheckMultisampleQualityLevels1(format: i32, sample_count: u32, flags: u32, p_num_quality_levels: *mut u32) -> HRESULT,
+        CreateTexture2D1(p_desc1: *mut c_void, p_initial_data: *mut c_void, pp_texture2d: *mut *mut c_void) -> HRESULT,
+        CreateTexture3D1(p_desc1: *mut c_void, p_initial_data: *mut c_void, pp_texture3d: *mut *mut c_void) -> HRESULT,
+        CreateRasterizerState2(p_rasterizer_desc: *mut c_void, pp_rasterizer_state: *mut *mut c_void) -> HRESULT,
+        CreateShaderResourceView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_sr_view1: *mut *mut c_void) -> HRESULT,
+        CreateUnorderedAccessView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_ua_view1: *mut *mut c_void) -> HRESULT,
+        CreateRenderTargetView1(p_resource: *mut c_void, p_desc1: *mut c_void, pp_rt_view1: *mut *mut c_void) -> HRESULT,
+        CreateQuery1(p_query_desc1: *mut c_void, pp_query1: *mut *mut c_void) -> HRESULT,
+        GetImmediateContext3(pp_immediate_context: *mut *mut c_void),
+        CreateDeferredContext3(context_flags: u32, pp_deferred_context: *mut *mut c_void) -> HRESULT,
+        WriteToSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        ReadFromSubresource(p_dst_data: *mut c_void, dst_row_pitch: u32, dst_depth_pitch: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        RegisterDeviceRemovedEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterDeviceRemoved(dw_cookie: u32),
+        OpenSharedFence(h_fence: *mut c_void, returned_interface: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, returned_interface: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX11Context1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod context1 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Context1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Context1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetDevice(pp_device: *mut *mut c_void),
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        Map(p_resource: *mut c_void, subresource: u32, map_type: i32, map_flags: u32, p_mapped_resource: *mut c_void) -> HRESULT,
+        Unmap(p_resource: *mut c_void, subresource: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        Begin(p_async: *mut c_void),
+        End(p_async: *mut c_void),
+        GetData(p_async: *mut c_void, p_data: *mut c_void, data_size: u32, get_data_flags: u32) -> HRESULT,
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        DrawIndexedInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        DrawInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        DispatchIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        CopyStructureCount(p_dst_buffer: *mut c_void, dst_aligned_byte_offset: u32, p_src_view: *mut c_void),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearUnorderedAccessViewUint(p_unordered_access_view: *mut c_void, values: *mut u32),
+        ClearUnorderedAccessViewFloat(p_unordered_access_view: *mut c_void, values: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        SetResourceMinLOD(p_resource: *mut c_void, min_lod: f32),
+        GetResourceMinLOD(p_resource: *mut c_void) -> f32,
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        ExecuteCommandList(p_command_list: *mut c_void, restore_context_state: i32),
+        HSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSSetShader(p_hull_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        HSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSSetShader(p_domain_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSSetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        CSSetShader(p_compute_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        CSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(p_num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(p_num_rects: *mut u32, p_rects: *mut c_void),
+        HSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSGetShader(pp_hull_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        HSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSGetShader(pp_domain_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        DSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSGetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        CSGetShader(pp_compute_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        CSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        ClearState(),
+        Flush(),
+        GetType() -> i32,
+        GetContextFlags() -> u32,
+        FinishCommandList(restore_deferred_context_state: i32, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CopySubresourceRegion1(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void, copy_flags: u32),
+        UpdateSubresource1(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32, copy_flags: u32),
+        DiscardResource(p_resource: *mut c_void),
+        DiscardView(p_resource_view: *mut c_void),
+        VSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        VSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        SwapDeviceContextState(p_state: *mut c_void, pp_previous_state: *mut *mut c_void),
+        ClearView(p_view: *mut c_void, color: *mut f32, p_rect: *mut c_void, num_rects: u32),
+        DiscardView1(p_resource_view: *mut c_void, p_rects: *mut c_void, num_rects: u32),
+    }
+}
+
+/// Typed signatures of [`DirectX11Context2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod context2 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Context2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Context2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetDevice(pp_device: *mut *mut c_void),
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        Map(p_resource: *mut c_void, subresource: u32, map_type: i32, map_flags: u32, p_mapped_resource: *mut c_void) -> HRESULT,
+        Unmap(p_resource: *mut c_void, subresource: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        Begin(p_async: *mut c_void),
+        End(p_async: *mut c_void),
+        GetData(p_async: *mut c_void, p_data: *mut c_void, data_size: u32, get_data_flags: u32) -> HRESULT,
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        DrawIndexedInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        DrawInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        DispatchIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        CopyStructureCount(p_dst_buffer: *mut c_void, dst_aligned_byte_offset: u32, p_src_view: *mut c_void),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearUnorderedAccessViewUint(p_unordered_access_view: *mut c_void, values: *mut u32),
+        ClearUnorderedAccessViewFloat(p_unordered_access_view: *mut c_void, values: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        SetResourceMinLOD(p_resource: *mut c_void, min_lod: f32),
+        GetResourceMinLOD(p_resource: *mut c_void) -> f32,
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        ExecuteCommandList(p_command_list: *mut c_void, restore_context_state: i32),
+        HSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSSetShader(p_hull_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        HSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSSetShader(p_domain_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSSetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        CSSetShader(p_compute_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        CSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(p_num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(p_num_rects: *mut u32, p_rects: *mut c_void),
+        HSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSGetShader(pp_hull_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        HSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSGetShader(pp_domain_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        DSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSGetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        CSGetShader(pp_compute_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        CSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        ClearState(),
+        Flush(),
+        GetType() -> i32,
+        GetContextFlags() -> u32,
+        FinishCommandList(restore_deferred_context_state: i32, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CopySubresourceRegion1(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void, copy_flags: u32),
+        UpdateSubresource1(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32, copy_flags: u32),
+        DiscardResource(p_resource: *mut c_void),
+        DiscardView(p_resource_view: *mut c_void),
+        VSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        VSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        SwapDeviceContextState(p_state: *mut c_void, pp_previous_state: *mut *mut c_void),
+        ClearView(p_view: *mut c_void, color: *mut f32, p_rect: *mut c_void, num_rects: u32),
+        DiscardView1(p_resource_view: *mut c_void, p_rects: *mut c_void, num_rects: u32),
+        UpdateTileMappings(p_tiled_resource: *mut c_void, num_tiled_resource_regions: u32, p_tiled_resource_region_start_coordinates: *mut c_void, p_tiled_resource_region_sizes: *mut c_void, p_tile_pool: *mut c_void, num_ranges: u32, p_range_flags: *mut u32, p_tile_pool_start_offsets: *mut u32, p_range_tile_counts: *mut u32, flags: u32) -> HRESULT,
+        CopyTileMappings(p_dest_tiled_resource: *mut c_void, p_dest_region_start_coordinate: *mut c_void, p_source_tiled_resource: *mut c_void, p_source_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, flags: u32) -> HRESULT,
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: u32),
+        UpdateTiles(p_dest_tiled_resource: *mut c_void, p_dest_tile_region_start_coordinate: *mut c_void, p_dest_tile_region_size: *mut c_void, p_source_tile_data: *mut c_void, flags: u32),
+        ResizeTilePool(p_tile_pool: *mut c_void, new_size_in_bytes: u64) -> HRESULT,
+        TiledResourceBarrier(p_tiled_resource_or_view_access_before_barrier: *mut c_void, p_tiled_resource_or_view_access_after_barrier: *mut c_void),
+        IsAnnotationEnabled() -> i32,
+        SetMarkerInt(p_label: *mut u16, data: i32),
+        BeginEventInt(p_label: *mut u16, data: i32),
+        EndEvent(),
+    }
+}
+
+/// Typed signatures of [`DirectX11Context3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod context3 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Context3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Context3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetDevice(pp_device: *mut *mut c_void),
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        Map(p_resource: *mut c_void, subresource: u32, map_type: i32, map_flags: u32, p_mapped_resource: *mut c_void) -> HRESULT,
+        Unmap(p_resource: *mut c_void, subresource: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        Begin(p_async: *mut c_void),
+        End(p_async: *mut c_void),
+        GetData(p_async: *mut c_void, p_data: *mut c_void, data_size: u32, get_data_flags: u32) -> HRESULT,
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        DrawIndexedInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        DrawInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        DispatchIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        CopyStructureCount(p_dst_buffer: *mut c_void, dst_aligned_byte_offset: u32, p_src_view: *mut c_void),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearUnorderedAccessViewUint(p_unordered_access_view: *mut c_void, values: *mut u32),
+        ClearUnorderedAccessViewFloat(p_unordered_access_view: *mut c_void, values: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        SetResourceMinLOD(p_resource: *mut c_void, min_lod: f32),
+        GetResourceMinLOD(p_resource: *mut c_void) -> f32,
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        ExecuteCommandList(p_command_list: *mut c_void, restore_context_state: i32),
+        HSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSSetShader(p_hull_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        HSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSSetShader(p_domain_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSSetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        CSSetShader(p_compute_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        CSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(p_num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(p_num_rects: *mut u32, p_rects: *mut c_void),
+        HSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSGetShader(pp_hull_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        HSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSGetShader(pp_domain_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        DSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSGetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        CSGetShader(pp_compute_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        CSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        ClearState(),
+        Flush(),
+        GetType() -> i32,
+        GetContextFlags() -> u32,
+        FinishCommandList(restore_deferred_context_state: i32, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CopySubresourceRegion1(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void, copy_flags: u32),
+        UpdateSubresource1(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32, copy_flags: u32),
+        DiscardResource(p_resource: *mut c_void),
+        DiscardView(p_resource_view: *mut c_void),
+        VSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        VSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        SwapDeviceContextState(p_state: *mut c_void, pp_previous_state: *mut *mut c_void),
+        ClearView(p_view: *mut c_void, color: *mut f32, p_rect: *mut c_void, num_rects: u32),
+        DiscardView1(p_resource_view: *mut c_void, p_rects: *mut c_void, num_rects: u32),
+        UpdateTileMappings(p_tiled_resource: *mut c_void, num_tiled_resource_regions: u32, p_tiled_resource_region_start_coordinates: *mut c_void, p_tiled_resource_region_sizes: *mut c_void, p_tile_pool: *mut c_void, num_ranges: u32, p_range_flags: *mut u32, p_tile_pool_start_offsets: *mut u32, p_range_tile_counts: *mut u32, flags: u32) -> HRESULT,
+        CopyTileMappings(p_dest_tiled_resource: *mut c_void, p_dest_region_start_coordinate: *mut c_void, p_source_tiled_resource: *mut c_void, p_source_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, flags: u32) -> HRESULT,
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: u32),
+        UpdateTiles(p_dest_tiled_resource: *mut c_void, p_dest_tile_region_start_coordinate: *mut c_void, p_dest_tile_region_size: *mut c_void, p_source_tile_data: *mut c_void, flags: u32),
+        ResizeTilePool(p_tile_pool: *mut c_void, new_size_in_bytes: u64) -> HRESULT,
+        TiledResourceBarrier(p_tiled_resource_or_view_access_before_barrier: *mut c_void, p_tiled_resource_or_view_access_after_barrier: *mut c_void),
+        IsAnnotationEnabled() -> i32,
+        SetMarkerInt(p_label: *mut u16, data: i32),
+        BeginEventInt(p_label: *mut u16, data: i32),
+        EndEvent(),
+        Flush1(context_type: i32, h_event: *mut c_void),
+        SetHardwareProtectionState(hw_protection_enable: i32),
+        GetHardwareProtectionState(p_hw_protection_enable: *mut i32),
+    }
+}
+
+/// Typed signatures of [`DirectX11Context4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod context4 {
+    use std::ffi::c_void;
+
+    use super::DirectX11Context4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX11Context4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetDevice(pp_device: *mut *mut c_void),
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        VSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSSetShader(p_pixel_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        PSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSSetShader(p_vertex_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DrawIndexed(index_count: u32, start_index_location: u32, base_vertex_location: i32),
+        Draw(vertex_count: u32, start_vertex_location: u32),
+        Map(p_resource: *mut c_void, subresource: u32, map_type: i32, map_flags: u32, p_mapped_resource: *mut c_void) -> HRESULT,
+        Unmap(p_resource: *mut c_void, subresource: u32),
+        PSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IASetInputLayout(p_input_layout: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IASetIndexBuffer(p_index_buffer: *mut c_void, format: i32, offset: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        GSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSSetShader(p_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        IASetPrimitiveTopology(topology: i32),
+        VSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        Begin(p_async: *mut c_void),
+        End(p_async: *mut c_void),
+        GetData(p_async: *mut c_void, p_data: *mut c_void, data_size: u32, get_data_flags: u32) -> HRESULT,
+        SetPredication(p_predicate: *mut c_void, predicate_value: i32),
+        GSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMSetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void),
+        OMSetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, p_depth_stencil_view: *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        OMSetBlendState(p_blend_state: *mut c_void, blend_factor: *mut f32, sample_mask: u32),
+        OMSetDepthStencilState(p_depth_stencil_state: *mut c_void, stencil_ref: u32),
+        SOSetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void, p_offsets: *mut u32),
+        DrawAuto(),
+        DrawIndexedInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        DrawInstancedIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        DispatchIndirect(p_buffer_for_args: *mut c_void, aligned_byte_offset_for_args: u32),
+        RSSetState(p_rasterizer_state: *mut c_void),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        CopySubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        UpdateSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32),
+        CopyStructureCount(p_dst_buffer: *mut c_void, dst_aligned_byte_offset: u32, p_src_view: *mut c_void),
+        ClearRenderTargetView(p_render_target_view: *mut c_void, color_rgba: *mut f32),
+        ClearUnorderedAccessViewUint(p_unordered_access_view: *mut c_void, values: *mut u32),
+        ClearUnorderedAccessViewFloat(p_unordered_access_view: *mut c_void, values: *mut f32),
+        ClearDepthStencilView(p_depth_stencil_view: *mut c_void, clear_flags: u32, depth: f32, stencil: u8),
+        GenerateMips(p_shader_resource_view: *mut c_void),
+        SetResourceMinLOD(p_resource: *mut c_void, min_lod: f32),
+        GetResourceMinLOD(p_resource: *mut c_void) -> f32,
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        ExecuteCommandList(p_command_list: *mut c_void, restore_context_state: i32),
+        HSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSSetShader(p_hull_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        HSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSSetShader(p_domain_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        DSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSSetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSSetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void, p_uav_initial_counts: *mut u32),
+        CSSetShader(p_compute_shader: *mut c_void, pp_class_instances: *mut *mut c_void, num_class_instances: u32),
+        CSSetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSSetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        VSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        PSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        PSGetShader(pp_pixel_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        VSGetShader(pp_vertex_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        PSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        IAGetInputLayout(pp_input_layout: *mut *mut c_void),
+        IAGetVertexBuffers(start_slot: u32, num_buffers: u32, pp_vertex_buffers: *mut *mut c_void, p_strides: *mut u32, p_offsets: *mut u32),
+        IAGetIndexBuffer(p_index_buffer: *mut *mut c_void, format: *mut i32, offset: *mut u32),
+        GSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        GSGetShader(pp_geometry_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        IAGetPrimitiveTopology(p_topology: *mut i32),
+        VSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        VSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        GetPredication(pp_predicate: *mut *mut c_void, p_predicate_value: *mut i32),
+        GSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        GSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        OMGetRenderTargets(num_views: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void),
+        OMGetRenderTargetsAndUnorderedAccessViews(num_rt_vs: u32, pp_render_target_views: *mut *mut c_void, pp_depth_stencil_view: *mut *mut c_void, uav_start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        OMGetBlendState(pp_blend_state: *mut *mut c_void, blend_factor: *mut f32, p_sample_mask: *mut u32),
+        OMGetDepthStencilState(pp_depth_stencil_state: *mut *mut c_void, p_stencil_ref: *mut u32),
+        SOGetTargets(num_buffers: u32, pp_so_targets: *mut *mut c_void),
+        RSGetState(pp_rasterizer_state: *mut *mut c_void),
+        RSGetViewports(p_num_viewports: *mut u32, p_viewports: *mut c_void),
+        RSGetScissorRects(p_num_rects: *mut u32, p_rects: *mut c_void),
+        HSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        HSGetShader(pp_hull_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        HSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        HSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        DSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        DSGetShader(pp_domain_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        DSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        DSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        CSGetShaderResources(start_slot: u32, num_views: u32, pp_shader_resource_views: *mut *mut c_void),
+        CSGetUnorderedAccessViews(start_slot: u32, num_ua_vs: u32, pp_unordered_access_views: *mut *mut c_void),
+        CSGetShader(pp_compute_shader: *mut *mut c_void, pp_class_instances: *mut *mut c_void, p_num_class_instances: *mut u32),
+        CSGetSamplers(start_slot: u32, num_samplers: u32, pp_samplers: *mut *mut c_void),
+        CSGetConstantBuffers(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void),
+        ClearState(),
+        Flush(),
+        GetType() -> i32,
+        GetContextFlags() -> u32,
+        FinishCommandList(restore_deferred_context_state: i32, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CopySubresourceRegion1(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, dst_z: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_box: *mut c_void, copy_flags: u32),
+        UpdateSubresource1(p_dst_resource: *mut c_void, dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32, copy_flags: u32),
+        DiscardResource(p_resource: *mut c_void),
+        DiscardView(p_resource_view: *mut c_void),
+        VSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSSetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        VSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        HSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        DSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        GSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        PSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        CSGetConstantBuffers1(start_slot: u32, num_buffers: u32, pp_constant_buffers: *mut *mut c_void, p_first_constant: *mut u32, p_num_constants: *mut u32),
+        SwapDeviceContextState(p_state: *mut c_void, pp_previous_state: *mut *mut c_void),
+        ClearView(p_view: *mut c_void, color: *mut f32, p_rect: *mut c_void, num_rects: u32),
+        DiscardView1(p_resource_view: *mut c_void, p_rects: *mut c_void, num_rects: u32),
+        UpdateTileMappings(p_tiled_resource: *mut c_void, num_tiled_resource_regions: u32, p_tiled_resource_region_start_coordinates: *mut c_void, p_tiled_resource_region_sizes: *mut c_void, p_tile_pool: *mut c_void, num_ranges: u32, p_range_flags: *mut u32, p_tile_pool_start_offsets: *mut u32, p_range_tile_counts: *mut u32, flags: u32) -> HRESULT,
+        CopyTileMappings(p_dest_tiled_resource: *mut c_void, p_dest_region_start_coordinate: *mut c_void, p_source_tiled_resource: *mut c_void, p_source_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, flags: u32) -> HRESULT,
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: u32),
+        UpdateTiles(p_dest_tiled_resource: *mut c_void, p_dest_tile_region_start_coordinate: *mut c_void, p_dest_tile_region_size: *mut c_void, p_source_tile_data: *mut c_void, flags: u32),
+        ResizeTilePool(p_tile_pool: *mut c_void, new_size_in_bytes: u64) -> HRESULT,
+        TiledResourceBarrier(p_tiled_resource_or_view_access_before_barrier: *mut c_void, p_tiled_resource_or_view_access_after_barrier: *mut c_void),
+        IsAnnotationEnabled() -> i32,
+        SetMarkerInt(p_label: *mut u16, data: i32),
+        BeginEventInt(p_label: *mut u16, data: i32),
+        EndEvent(),
+        Flush1(context_type: i32, h_event: *mut c_void),
+        SetHardwareProtectionState(hw_protection_enable: i32),
+        GetHardwareProtectionState(p_hw_protection_enable: *mut i32),
+        Signal(p_fence: *mut c_void, value: u64) -> HRESULT,
+        Wait(p_fence: *mut c_void, value: u64) -> HRESULT,
+    }
+}
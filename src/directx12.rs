@@ -1,297 +1,305 @@
-use strum::{EnumCount, IntoEnumIterator};
-use strum_macros::{EnumCount, EnumIter};
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
+use windows::Win32::Foundation::HWND;
+#[cfg(windows)]
 use windows::Win32::Graphics::{
-    Direct3D::D3D_FEATURE_LEVEL_11_0,
     Direct3D12::{
-        D3D12CreateDevice, ID3D12CommandAllocator, ID3D12CommandList, ID3D12CommandQueue,
-        ID3D12Device, D3D12_COMMAND_LIST_TYPE_DIRECT, D3D12_COMMAND_QUEUE_DESC,
-        D3D12_COMMAND_QUEUE_FLAG_NONE,
+        D3D12CreateDevice, ID3D12CommandAllocator, ID3D12CommandQueue, ID3D12DescriptorHeap,
+        ID3D12Device, ID3D12Device1, ID3D12Device10, ID3D12Device2, ID3D12Device3, ID3D12Device4,
+        ID3D12Device5, ID3D12Device6, ID3D12Device7, ID3D12Device8, ID3D12Device9, ID3D12Fence,
+        ID3D12GraphicsCommandList, ID3D12GraphicsCommandList1, ID3D12GraphicsCommandList2,
+        ID3D12GraphicsCommandList3, ID3D12GraphicsCommandList4, ID3D12GraphicsCommandList5,
+        ID3D12GraphicsCommandList6, ID3D12GraphicsCommandList7, ID3D12Resource,
+        D3D12_COMMAND_LIST_TYPE_DIRECT, D3D12_COMMAND_QUEUE_DESC, D3D12_COMMAND_QUEUE_FLAG_NONE,
+        D3D12_DESCRIPTOR_HEAP_DESC, D3D12_DESCRIPTOR_HEAP_FLAG_NONE,
+        D3D12_DESCRIPTOR_HEAP_TYPE_RTV, D3D12_FENCE_FLAG_NONE, D3D12_HEAP_FLAG_NONE,
+        D3D12_HEAP_PROPERTIES, D3D12_HEAP_TYPE_DEFAULT, D3D12_RESOURCE_DESC,
+        D3D12_RESOURCE_DIMENSION_BUFFER, D3D12_RESOURCE_FLAG_NONE, D3D12_RESOURCE_STATE_COMMON,
+        D3D12_TEXTURE_LAYOUT_ROW_MAJOR,
+    },
+    Dxgi::{
+        Common::{DXGI_FORMAT_UNKNOWN, DXGI_SAMPLE_DESC},
+        CreateDXGIFactory, IDXGIAdapter, IDXGIFactory, IDXGIFactory4, IDXGISwapChain,
     },
-    Dxgi::{CreateDXGIFactory, IDXGIFactory, IDXGISwapChain},
 };
 
+#[cfg(windows)]
 use crate::{
-    get_process_window, swapchain_util::default_swapchain_descriptor, ShroudError, ShroudResult,
+    discovery::{self, Attempt, DriverType},
+    dxgi,
+    swapchain_util::default_swapchain_descriptor,
+    vtable,
+    window::{ProvidedWindow, WindowProvider},
+    DiscoveryOptions, WindowSelector,
+};
+use crate::{
+    dxgi::DxgiSwapchainVmt, method_table::versioned_vmt, snapshot::snapshot_serde, MethodTable,
+    RenderEngine, ShroudError, ShroudResult, Snapshot,
 };
 
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX12DeviceMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    GetPrivateData,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    SetName,
-    GetNodeCount,
-    CreateCommandQueue,
-    CreateCommandAllocator,
-    CreateGraphicsPipelineState,
-    CreateComputePipelineState,
-    CreateCommandList,
-    CheckFeatureSupport,
-    CreateDescriptorHeap,
-    GetDescriptorHandleIncrementSize,
-    CreateRootSignature,
-    CreateConstantBufferView,
-    CreateShaderResourceView,
-    CreateUnorderedAccessView,
-    CreateRenderTargetView,
-    CreateDepthStencilView,
-    CreateSampler,
-    CopyDescriptors,
-    CopyDescriptorsSimple,
-    GetResourceAllocationInfo,
-    GetCustomHeapProperties,
-    CreateCommittedResource,
-    CreateHeap,
-    CreatePlacedResource,
-    CreateReservedResource,
-    CreateSharedHandle,
-    OpenSharedHandle,
-    OpenSharedHandleByName,
-    MakeResident,
-    Evict,
-    CreateFence,
-    GetDeviceRemovedReason,
-    GetCopyableFootprints,
-    CreateQueryHeap,
-    SetStablePowerState,
-    CreateCommandSignature,
-    GetResourceTiling,
-    GetAdapterLuid,
-}
-
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX12CommandQueueMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    GetPrivateData,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    SetName,
-    GetDevice,
-    UpdateTileMappings,
-    CopyTileMappings,
-    ExecuteCommandLists,
-    SetMarker,
-    BeginEvent,
-    EndEvent,
-    Signal,
-    Wait,
-    GetTimestampFrequency,
-    GetClockCalibration,
-    GetDesc,
-}
-
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX12CommandAllocatorMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    GetPrivateData,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    SetName,
-    GetDevice,
-    Reset,
-}
-
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX12CommandListMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    GetPrivateData,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    SetName,
-    GetDevice,
-    GetType,
-    Close,
-    Reset,
-    ClearState,
-    DrawInstanced,
-    DrawIndexedInstanced,
-    Dispatch,
-    CopyBufferRegion,
-    CopyTextureRegion,
-    CopyResource,
-    CopyTiles,
-    ResolveSubresource,
-    IASetPrimitiveTopology,
-    RSSetViewports,
-    RSSetScissorRects,
-    OMSetBlendFactor,
-    OMSetStencilRef,
-    SetPipelineState,
-    ResourceBarrier,
-    ExecuteBundle,
-    SetDescriptorHeaps,
-    SetComputeRootSignature,
-    SetGraphicsRootSignature,
-    SetComputeRootDescriptorTable,
-    SetGraphicsRootDescriptorTable,
-    SetComputeRoot32BitConstant,
-    SetGraphicsRoot32BitConstant,
-    SetComputeRoot32BitConstants,
-    SetGraphicsRoot32BitConstants,
-    SetComputeRootConstantBufferView,
-    SetGraphicsRootConstantBufferView,
-    SetComputeRootShaderResourceView,
-    SetGraphicsRootShaderResourceView,
-    SetComputeRootUnorderedAccessView,
-    SetGraphicsRootUnorderedAccessView,
-    IASetIndexBuffer,
-    IASetVertexBuffers,
-    SOSetTargets,
-    OMSetRenderTargets,
-    ClearDepthStencilView,
-    ClearRenderTargetView,
-    ClearUnorderedAccessViewUint,
-    ClearUnorderedAccessViewFloat,
-    DiscardResource,
-    BeginQuery,
-    EndQuery,
-    ResolveQueryData,
-    SetPredication,
-    SetMarker,
-    BeginEvent,
-    EndEvent,
-    ExecuteIndirect,
+mod methods;
+pub use methods::*;
+
+versioned_vmt! {
+    /// Method table of a device at the highest `ID3D12Device` version it supports.
+    pub enum DirectX12DeviceVmt {
+        Device10(10, DirectX12Device10Methods, ID3D12Device10),
+        Device9(9, DirectX12Device9Methods, ID3D12Device9),
+        Device8(8, DirectX12Device8Methods, ID3D12Device8),
+        Device7(7, DirectX12Device7Methods, ID3D12Device7),
+        Device6(6, DirectX12Device6Methods, ID3D12Device6),
+        Device5(5, DirectX12Device5Methods, ID3D12Device5),
+        Device4(4, DirectX12Device4Methods, ID3D12Device4),
+        Device3(3, DirectX12Device3Methods, ID3D12Device3),
+        Device2(2, DirectX12Device2Methods, ID3D12Device2),
+        Device1(1, DirectX12Device1Methods, ID3D12Device1),
+    }
 }
 
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX12SwapchainMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    SetPrivateData,
-    SetPrivateDataInterface,
-    GetPrivateData,
-    GetParent,
-    GetDevice,
-    Present,
-    GetBuffer,
-    SetFullscreenState,
-    GetFullscreenState,
-    GetDesc,
-    ResizeBuffers,
-    ResizeTarget,
-    GetContainingOutput,
-    GetFrameStatistics,
-    GetLastPresentCount,
+versioned_vmt! {
+    /// Method table of a direct command list at the highest `ID3D12GraphicsCommandList`
+    /// version it supports.
+    pub enum DirectX12CommandListVmt {
+        CommandList7(7, DirectX12CommandList7Methods, ID3D12GraphicsCommandList7),
+        CommandList6(6, DirectX12CommandList6Methods, ID3D12GraphicsCommandList6),
+        CommandList5(5, DirectX12CommandList5Methods, ID3D12GraphicsCommandList5),
+        CommandList4(4, DirectX12CommandList4Methods, ID3D12GraphicsCommandList4),
+        CommandList3(3, DirectX12CommandList3Methods, ID3D12GraphicsCommandList3),
+        CommandList2(2, DirectX12CommandList2Methods, ID3D12GraphicsCommandList2),
+        CommandList1(1, DirectX12CommandList1Methods, ID3D12GraphicsCommandList1),
+    }
 }
 
 pub struct DirectX12Methods {
-    device_vmt: Vec<*const usize>,
-    command_queue_vmt: Vec<*const usize>,
-    command_allocator_vmt: Vec<*const usize>,
-    command_list_vmt: Vec<*const usize>,
-    swapchain_vmt: Vec<*const usize>,
+    device_vmt: MethodTable<DirectX12DeviceMethods>,
+    latest_device_vmt: Option<DirectX12DeviceVmt>,
+    command_queue_vmt: MethodTable<DirectX12CommandQueueMethods>,
+    command_allocator_vmt: MethodTable<DirectX12CommandAllocatorMethods>,
+    command_list_vmt: MethodTable<DirectX12CommandListMethods>,
+    latest_command_list_vmt: Option<DirectX12CommandListVmt>,
+    fence_vmt: MethodTable<DirectX12FenceMethods>,
+    resource_vmt: MethodTable<DirectX12ResourceMethods>,
+    descriptor_heap_vmt: MethodTable<DirectX12DescriptorHeapMethods>,
+    swapchain_vmt: MethodTable<DirectX12SwapchainMethods>,
+    latest_swapchain_vmt: Option<DxgiSwapchainVmt>,
 }
 
 impl DirectX12Methods {
-    pub fn device_vmt(&self) -> &Vec<*const usize> {
+    pub fn device_vmt(&self) -> &MethodTable<DirectX12DeviceMethods> {
         &self.device_vmt
     }
 
-    pub fn command_queue_vmt(&self) -> &Vec<*const usize> {
+    /// The device's table at the highest `ID3D12Device` version it supports,
+    /// `None` if it is only an `ID3D12Device`.
+    pub fn latest_device_vmt(&self) -> Option<&DirectX12DeviceVmt> {
+        self.latest_device_vmt.as_ref()
+    }
+
+    pub fn command_queue_vmt(&self) -> &MethodTable<DirectX12CommandQueueMethods> {
         &self.command_queue_vmt
     }
 
-    pub fn command_allocator_vmt(&self) -> &Vec<*const usize> {
+    pub fn command_allocator_vmt(&self) -> &MethodTable<DirectX12CommandAllocatorMethods> {
         &self.command_allocator_vmt
     }
 
-    pub fn command_list_vmt(&self) -> &Vec<*const usize> {
+    pub fn command_list_vmt(&self) -> &MethodTable<DirectX12CommandListMethods> {
         &self.command_list_vmt
     }
 
-    pub fn swapchain_vmt(&self) -> &Vec<*const usize> {
+    /// The command list's table at the highest `ID3D12GraphicsCommandList` version it
+    /// supports, `None` if it is only an `ID3D12GraphicsCommandList`.
+    pub fn latest_command_list_vmt(&self) -> Option<&DirectX12CommandListVmt> {
+        self.latest_command_list_vmt.as_ref()
+    }
+
+    pub fn fence_vmt(&self) -> &MethodTable<DirectX12FenceMethods> {
+        &self.fence_vmt
+    }
+
+    pub fn resource_vmt(&self) -> &MethodTable<DirectX12ResourceMethods> {
+        &self.resource_vmt
+    }
+
+    pub fn descriptor_heap_vmt(&self) -> &MethodTable<DirectX12DescriptorHeapMethods> {
+        &self.descriptor_heap_vmt
+    }
+
+    pub fn swapchain_vmt(&self) -> &MethodTable<DirectX12SwapchainMethods> {
         &self.swapchain_vmt
     }
+
+    /// The swap chain's table at the highest `IDXGISwapChain` version it supports,
+    /// `None` if it is only an `IDXGISwapChain`.
+    pub fn latest_swapchain_vmt(&self) -> Option<&DxgiSwapchainVmt> {
+        self.latest_swapchain_vmt.as_ref()
+    }
 }
 
+impl DirectX12Methods {
+    /// Host-independent record of every table, see [`Snapshot`].
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new(RenderEngine::DirectX12);
+        snapshot.push(&self.device_vmt);
+        if let Some(vmt) = &self.latest_device_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot.push(&self.command_queue_vmt);
+        snapshot.push(&self.command_allocator_vmt);
+        snapshot.push(&self.command_list_vmt);
+        if let Some(vmt) = &self.latest_command_list_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot.push(&self.fence_vmt);
+        snapshot.push(&self.resource_vmt);
+        snapshot.push(&self.descriptor_heap_vmt);
+        snapshot.push(&self.swapchain_vmt);
+        if let Some(vmt) = &self.latest_swapchain_vmt {
+            vmt.push_to(&mut snapshot);
+        }
+        snapshot
+    }
+}
+
+impl TryFrom<&Snapshot> for DirectX12Methods {
+    type Error = ShroudError;
+
+    fn try_from(snapshot: &Snapshot) -> ShroudResult<Self> {
+        snapshot.expect(RenderEngine::DirectX12)?;
+        Ok(Self {
+            device_vmt: snapshot.required_table()?,
+            latest_device_vmt: DirectX12DeviceVmt::from_snapshot(snapshot)?,
+            command_queue_vmt: snapshot.required_table()?,
+            command_allocator_vmt: snapshot.required_table()?,
+            command_list_vmt: snapshot.required_table()?,
+            latest_command_list_vmt: DirectX12CommandListVmt::from_snapshot(snapshot)?,
+            fence_vmt: snapshot.required_table()?,
+            resource_vmt: snapshot.required_table()?,
+            descriptor_heap_vmt: snapshot.required_table()?,
+            swapchain_vmt: snapshot.required_table()?,
+            latest_swapchain_vmt: DxgiSwapchainVmt::from_snapshot(snapshot)?,
+        })
+    }
+}
+
+snapshot_serde!(DirectX12Methods);
+
 impl std::fmt::Debug for DirectX12Methods {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "DirectX12 Method Table")?;
 
-        let mut index = 0;
         writeln!(f, "Device Virtual Method Table")?;
-        for (i, method) in DirectX12DeviceMethods::iter().enumerate() {
-            writeln!(f, "\t[{}] {:?} {:#?}", index, method, self.device_vmt[i])?;
+        write!(f, "{:?}", self.device_vmt)?;
+        writeln!(f)?;
 
-            index += 1;
+        if let Some(vmt) = &self.latest_device_vmt {
+            writeln!(f, "Device{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
-        writeln!(f)?;
 
         writeln!(f, "Command Queue Virtual Method Table")?;
-        for (i, method) in DirectX12CommandQueueMethods::iter().enumerate() {
-            writeln!(
-                f,
-                "\t[{}] {:?} {:#?}",
-                index, method, self.command_queue_vmt[i]
-            )?;
-
-            index += 1;
-        }
+        write!(f, "{:?}", self.command_queue_vmt)?;
         writeln!(f)?;
 
         writeln!(f, "Command Allocator Virtual Method Table")?;
-        for (i, method) in DirectX12CommandAllocatorMethods::iter().enumerate() {
-            writeln!(
-                f,
-                "\t[{}] {:?} {:#?}",
-                index, method, self.command_allocator_vmt[i]
-            )?;
-
-            index += 1;
-        }
+        write!(f, "{:?}", self.command_allocator_vmt)?;
         writeln!(f)?;
 
         writeln!(f, "Command List Virtual Method Table")?;
-        for (i, method) in DirectX12CommandListMethods::iter().enumerate() {
-            writeln!(
-                f,
-                "\t[{}] {:?} {:#?}",
-                index, method, self.command_list_vmt[i]
-            )?;
-
-            index += 1;
+        write!(f, "{:?}", self.command_list_vmt)?;
+        writeln!(f)?;
+
+        if let Some(vmt) = &self.latest_command_list_vmt {
+            writeln!(f, "Command List{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
+
+        writeln!(f, "Fence Virtual Method Table")?;
+        write!(f, "{:?}", self.fence_vmt)?;
+        writeln!(f)?;
+
+        writeln!(f, "Resource Virtual Method Table")?;
+        write!(f, "{:?}", self.resource_vmt)?;
+        writeln!(f)?;
+
+        writeln!(f, "Descriptor Heap Virtual Method Table")?;
+        write!(f, "{:?}", self.descriptor_heap_vmt)?;
         writeln!(f)?;
 
         writeln!(f, "Swapchain Virtual Method Table")?;
-        for (i, method) in DirectX12SwapchainMethods::iter().enumerate() {
-            writeln!(f, "\t[{}] {:?} {:#?}", index, method, self.swapchain_vmt[i])?;
+        write!(f, "{:?}", self.swapchain_vmt)?;
+        writeln!(f)?;
 
-            index += 1;
+        if let Some(vmt) = &self.latest_swapchain_vmt {
+            writeln!(f, "Swapchain{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
-        writeln!(f)?;
 
         Ok(())
     }
 }
 
+/// Discovers every table, creating the device for the window
+/// [`WindowSelector::default`] picks.
+#[cfg(windows)]
 pub fn methods() -> ShroudResult<DirectX12Methods> {
+    methods_for(&WindowSelector::default())
+}
+
+/// [`methods`], creating the device for the window `window` provides, such as one
+/// [`WindowSelector`] picks or a [`crate::TemporaryWindow`].
+#[cfg(windows)]
+pub fn methods_for(window: &impl WindowProvider) -> ShroudResult<DirectX12Methods> {
+    methods_with(window, &DiscoveryOptions::default())
+}
+
+/// [`methods_for`], creating the device on the adapter and at the minimum
+/// feature level `options` ask for, and falling back along its plan. WARP is
+/// the only driver besides hardware Direct3D 12 has.
+#[cfg(windows)]
+pub fn methods_with(
+    window: &impl WindowProvider,
+    options: &DiscoveryOptions,
+) -> ShroudResult<DirectX12Methods> {
+    let window = window.acquire()?;
+    let hwnd = HWND(window.handle() as *mut _);
+    let adapters = dxgi::adapters().unwrap_or_default();
+    let attempts = options.plan(RenderEngine::DirectX12, &adapters);
+    discovery::retry(RenderEngine::DirectX12, attempts, |attempt| {
+        discover(hwnd, attempt)
+    })
+}
+
+#[cfg(windows)]
+fn discover(hwnd: HWND, attempt: &Attempt) -> ShroudResult<DirectX12Methods> {
     // Initialize Factory
     let factory: IDXGIFactory =
         unsafe { CreateDXGIFactory().map_err(|e| ShroudError::DirectX12CreateFactory(e.code()))? };
 
-    // Initialize adapter
-    let adapter = unsafe {
-        factory
-            .EnumAdapters(0)
-            .map_err(|e| ShroudError::DirectX12EnumAdapters(e.code()))?
+    // Initialize adapter, the first one standing in for the default
+    let adapter: IDXGIAdapter = unsafe {
+        match attempt.driver {
+            DriverType::Warp => factory
+                .cast::<IDXGIFactory4>()
+                .and_then(|factory| factory.EnumWarpAdapter()),
+            _ => factory.EnumAdapters(attempt.adapter.unwrap_or(0)),
+        }
+        .map_err(|e| ShroudError::DirectX12EnumAdapters(e.code()))?
     };
 
     // Initialize device
+    let feature_level = *attempt
+        .feature_levels
+        .first()
+        .ok_or(ShroudError::Expectation("DirectX12 feature level planned"))?;
     let mut device = None;
     unsafe {
-        D3D12CreateDevice(&adapter, D3D_FEATURE_LEVEL_11_0, &mut device)
-            .map_err(|e| ShroudError::DirectX12CreateFactory(e.code()))?
+        D3D12CreateDevice(&adapter, feature_level.into(), &mut device)
+            .map_err(|e| ShroudError::DirectX12CreateDevice(e.code()))?
     };
     let device: ID3D12Device =
         device.ok_or(ShroudError::Expectation("DirectX12 device populated"))?;
@@ -319,70 +327,182 @@ pub fn methods() -> ShroudResult<DirectX12Methods> {
     }?;
 
     // Initialize command list
-    let command_list: ID3D12CommandList = unsafe {
+    let command_list: ID3D12GraphicsCommandList = unsafe {
         device
             .CreateCommandList(0, D3D12_COMMAND_LIST_TYPE_DIRECT, &command_allocator, None)
             .map_err(|e| ShroudError::DirectX12CreateCommandList(e.code()))
     }?;
 
-    // create default swap chain descriptor, and create d3d12 swapchain
-    let window = get_process_window().ok_or(ShroudError::Window)?;
-    let swapchain_desc = default_swapchain_descriptor(window);
-    let mut swapchain = None;
+    // Initialize fence
+    let fence: ID3D12Fence = unsafe {
+        device
+            .CreateFence(0, D3D12_FENCE_FLAG_NONE)
+            .map_err(|e| ShroudError::DirectX12CreateFence(e.code()))
+    }?;
 
+    // Initialize a small buffer resource
+    let heap_properties = D3D12_HEAP_PROPERTIES {
+        Type: D3D12_HEAP_TYPE_DEFAULT,
+        ..Default::default()
+    };
+    let resource_desc = D3D12_RESOURCE_DESC {
+        Dimension: D3D12_RESOURCE_DIMENSION_BUFFER,
+        Alignment: 0,
+        Width: 256,
+        Height: 1,
+        DepthOrArraySize: 1,
+        MipLevels: 1,
+        Format: DXGI_FORMAT_UNKNOWN,
+        SampleDesc: DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        },
+        Layout: D3D12_TEXTURE_LAYOUT_ROW_MAJOR,
+        Flags: D3D12_RESOURCE_FLAG_NONE,
+    };
+    let mut resource: Option<ID3D12Resource> = None;
     unsafe {
-        let res = factory.CreateSwapChain(&command_queue, &swapchain_desc, &mut swapchain);
-        if res.is_err() {
-            eprintln!("{:?}", res.message());
-            // return Err(ShroudError::DirectX12CreateSwapchain(res));
-        }
+        device
+            .CreateCommittedResource(
+                &heap_properties,
+                D3D12_HEAP_FLAG_NONE,
+                &resource_desc,
+                D3D12_RESOURCE_STATE_COMMON,
+                None,
+                &mut resource,
+            )
+            .map_err(|e| ShroudError::DirectX12CreateResource(e.code()))?
+    };
+    let resource = resource.ok_or(ShroudError::Expectation("DirectX12 resource populated"))?;
+
+    // Initialize descriptor heap
+    let heap_desc = D3D12_DESCRIPTOR_HEAP_DESC {
+        Type: D3D12_DESCRIPTOR_HEAP_TYPE_RTV,
+        NumDescriptors: 1,
+        Flags: D3D12_DESCRIPTOR_HEAP_FLAG_NONE,
+        NodeMask: 0,
     };
+    let descriptor_heap: ID3D12DescriptorHeap = unsafe {
+        device
+            .CreateDescriptorHeap(&heap_desc)
+            .map_err(|e| ShroudError::DirectX12CreateDescriptorHeap(e.code()))
+    }?;
+
+    // create default swap chain descriptor, and create d3d12 swapchain
+    let swapchain_desc = default_swapchain_descriptor(hwnd);
+    let mut swapchain = None;
+    unsafe { factory.CreateSwapChain(&command_queue, &swapchain_desc, &mut swapchain) }
+        .ok()
+        .map_err(|e| ShroudError::DirectX12CreateSwapchain(e.code()))?;
     let swapchain: IDXGISwapChain =
         swapchain.ok_or(ShroudError::Expectation("DirectX12 swapchain populated"))?;
 
-    let device_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(device).read(),
-            DirectX12DeviceMethods::COUNT,
-        )
-        .to_vec()
-    };
-    let command_queue_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(command_queue).read(),
-            DirectX12CommandQueueMethods::COUNT,
-        )
-        .to_vec()
-    };
-    let command_allocator_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(command_allocator).read(),
-            DirectX12CommandAllocatorMethods::COUNT,
-        )
-        .to_vec()
-    };
+    let device_vmt = unsafe { vtable::read::<DirectX12DeviceMethods>(device.as_raw())? };
+    let latest_device_vmt = DirectX12DeviceVmt::query(&device)?;
+    let command_queue_vmt =
+        unsafe { vtable::read::<DirectX12CommandQueueMethods>(command_queue.as_raw())? };
+    let command_allocator_vmt =
+        unsafe { vtable::read::<DirectX12CommandAllocatorMethods>(command_allocator.as_raw())? };
 
-    let command_list_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(command_list).read(),
-            DirectX12CommandListMethods::COUNT,
-        )
-        .to_vec()
-    };
+    let command_list_vmt =
+        unsafe { vtable::read::<DirectX12CommandListMethods>(command_list.as_raw())? };
+    let latest_command_list_vmt = DirectX12CommandListVmt::query(&command_list)?;
 
-    let swapchain_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(swapchain).read(),
-            DirectX12SwapchainMethods::COUNT,
-        )
-        .to_vec()
-    };
+    let fence_vmt = unsafe { vtable::read::<DirectX12FenceMethods>(fence.as_raw())? };
+    let resource_vmt = unsafe { vtable::read::<DirectX12ResourceMethods>(resource.as_raw())? };
+    let descriptor_heap_vmt =
+        unsafe { vtable::read::<DirectX12DescriptorHeapMethods>(descriptor_heap.as_raw())? };
+
+    let swapchain_vmt = unsafe { vtable::read::<DirectX12SwapchainMethods>(swapchain.as_raw())? };
+    let latest_swapchain_vmt = DxgiSwapchainVmt::query(&swapchain)?;
 
     Ok(DirectX12Methods {
         device_vmt,
+        latest_device_vmt,
         command_queue_vmt,
         command_allocator_vmt,
         command_list_vmt,
+        latest_command_list_vmt,
+        fence_vmt,
+        resource_vmt,
+        descriptor_heap_vmt,
         swapchain_vmt,
+        latest_swapchain_vmt,
     })
 }
+
+#[cfg(all(test, windows))]
+mod tests {
+    use std::{ffi::c_void, marker::PhantomData};
+
+    use windows::Win32::Graphics::Direct3D12::{ID3D12Device_Vtbl, D3D12_HEAP_TYPE};
+
+    use super::device;
+    use crate::Method;
+
+    /// A type as it is passed, pointers to anything being `*mut c_void`, so that
+    /// generated signatures compare to the `windows` crate's typed ones.
+    trait Abi {
+        type Abi;
+    }
+
+    impl<T> Abi for *mut T {
+        type Abi = *mut c_void;
+    }
+
+    impl<T> Abi for *const T {
+        type Abi = *mut c_void;
+    }
+
+    impl Abi for u32 {
+        type Abi = u32;
+    }
+
+    impl Abi for i32 {
+        type Abi = i32;
+    }
+
+    impl Abi for D3D12_HEAP_TYPE {
+        type Abi = i32;
+    }
+
+    macro_rules! abi_fn {
+        ($($param:ident),+) => {
+            impl<$($param: Abi),+> Abi for unsafe extern "system" fn($($param),+) {
+                type Abi = unsafe extern "system" fn($($param::Abi),+);
+            }
+        };
+    }
+
+    abi_fn!(A, B, C, D);
+    abi_fn!(A, B, C, D, E);
+
+    fn abi<T: Abi>(_: &T) -> PhantomData<T::Abi> {
+        PhantomData
+    }
+
+    fn generated<M: Method>() -> PhantomData<<M::Fn as Abi>::Abi>
+    where
+        M::Fn: Abi,
+    {
+        PhantomData
+    }
+
+    fn same<T>(_: PhantomData<T>, _: PhantomData<T>) {}
+
+    /// Struct returns take an out pointer right after `this`. Only type checked,
+    /// no vtable is ever read.
+    #[test]
+    fn struct_returns_follow_this() {
+        let _ = |vtbl: &ID3D12Device_Vtbl| {
+            same(
+                abi(&vtbl.GetResourceAllocationInfo),
+                generated::<device::GetResourceAllocationInfo>(),
+            );
+            same(
+                abi(&vtbl.GetCustomHeapProperties),
+                generated::<device::GetCustomHeapProperties>(),
+            );
+        };
+    }
+}
@@ -0,0 +1,4712 @@
+// Generated by `cargo xtask codegen` from xtask/winmd/Windows.Win32.winmd, do not edit.
+
+use strum_macros::{EnumCount, EnumIter};
+
+#[cfg(windows)]
+use crate::vtable::assert_slot;
+use crate::InterfaceMethods;
+
+/// `ID3D12Device` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12DeviceMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+}
+
+impl InterfaceMethods for DirectX12DeviceMethods {
+    const INTERFACE: &'static str = "ID3D12Device";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device_Vtbl as Vtbl;
+    use DirectX12DeviceMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.SetName => M::SetName);
+    assert_slot!(Vtbl, GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, Evict => M::Evict);
+    assert_slot!(Vtbl, CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, GetAdapterLuid => M::GetAdapterLuid);
+};
+
+/// `ID3D12CommandQueue` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandQueueMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    UpdateTileMappings,
+    CopyTileMappings,
+    ExecuteCommandLists,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    Signal,
+    Wait,
+    GetTimestampFrequency,
+    GetClockCalibration,
+    GetDesc,
+}
+
+impl InterfaceMethods for DirectX12CommandQueueMethods {
+    const INTERFACE: &'static str = "ID3D12CommandQueue";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12CommandQueue_Vtbl as Vtbl;
+    use DirectX12CommandQueueMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, UpdateTileMappings => M::UpdateTileMappings);
+    assert_slot!(Vtbl, CopyTileMappings => M::CopyTileMappings);
+    assert_slot!(Vtbl, ExecuteCommandLists => M::ExecuteCommandLists);
+    assert_slot!(Vtbl, SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, Signal => M::Signal);
+    assert_slot!(Vtbl, Wait => M::Wait);
+    assert_slot!(Vtbl, GetTimestampFrequency => M::GetTimestampFrequency);
+    assert_slot!(Vtbl, GetClockCalibration => M::GetClockCalibration);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+};
+
+/// `ID3D12CommandAllocator` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandAllocatorMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    Reset,
+}
+
+impl InterfaceMethods for DirectX12CommandAllocatorMethods {
+    const INTERFACE: &'static str = "ID3D12CommandAllocator";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12CommandAllocator_Vtbl as Vtbl;
+    use DirectX12CommandAllocatorMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, Reset => M::Reset);
+};
+
+/// `ID3D12GraphicsCommandList` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandListMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+}
+
+impl InterfaceMethods for DirectX12CommandListMethods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList_Vtbl as Vtbl;
+    use DirectX12CommandListMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.GetType => M::GetType);
+    assert_slot!(Vtbl, Close => M::Close);
+    assert_slot!(Vtbl, Reset => M::Reset);
+    assert_slot!(Vtbl, ClearState => M::ClearState);
+    assert_slot!(Vtbl, DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, ExecuteIndirect => M::ExecuteIndirect);
+};
+
+/// `IDXGISwapChain` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12SwapchainMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+}
+
+impl InterfaceMethods for DirectX12SwapchainMethods {
+    const INTERFACE: &'static str = "IDXGISwapChain";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain_Vtbl as Vtbl;
+    use DirectX12SwapchainMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, Present => M::Present);
+    assert_slot!(Vtbl, GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, GetLastPresentCount => M::GetLastPresentCount);
+};
+
+/// `ID3D12Device1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+}
+
+impl InterfaceMethods for DirectX12Device1Methods {
+    const INTERFACE: &'static str = "ID3D12Device1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device1_Vtbl as Vtbl;
+    use DirectX12Device1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, SetResidencyPriority => M::SetResidencyPriority);
+};
+
+/// `ID3D12Device2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+}
+
+impl InterfaceMethods for DirectX12Device2Methods {
+    const INTERFACE: &'static str = "ID3D12Device2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device2_Vtbl as Vtbl;
+    use DirectX12Device2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, CreatePipelineState => M::CreatePipelineState);
+};
+
+/// `ID3D12Device3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+}
+
+impl InterfaceMethods for DirectX12Device3Methods {
+    const INTERFACE: &'static str = "ID3D12Device3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device3_Vtbl as Vtbl;
+    use DirectX12Device3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, EnqueueMakeResident => M::EnqueueMakeResident);
+};
+
+/// `ID3D12Device4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+}
+
+impl InterfaceMethods for DirectX12Device4Methods {
+    const INTERFACE: &'static str = "ID3D12Device4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device4_Vtbl as Vtbl;
+    use DirectX12Device4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+};
+
+/// `ID3D12Device5` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device5Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+    CreateLifetimeTracker,
+    RemoveDevice,
+    EnumerateMetaCommands,
+    EnumerateMetaCommandParameters,
+    CreateMetaCommand,
+    CreateStateObject,
+    GetRaytracingAccelerationStructurePrebuildInfo,
+    CheckDriverMatchingIdentifier,
+}
+
+impl InterfaceMethods for DirectX12Device5Methods {
+    const INTERFACE: &'static str = "ID3D12Device5";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device5_Vtbl as Vtbl;
+    use DirectX12Device5Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, base__.CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, base__.CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, base__.CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, base__.CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, base__.CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, base__.GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+    assert_slot!(Vtbl, CreateLifetimeTracker => M::CreateLifetimeTracker);
+    assert_slot!(Vtbl, RemoveDevice => M::RemoveDevice);
+    assert_slot!(Vtbl, EnumerateMetaCommands => M::EnumerateMetaCommands);
+    assert_slot!(Vtbl, EnumerateMetaCommandParameters => M::EnumerateMetaCommandParameters);
+    assert_slot!(Vtbl, CreateMetaCommand => M::CreateMetaCommand);
+    assert_slot!(Vtbl, CreateStateObject => M::CreateStateObject);
+    assert_slot!(Vtbl, GetRaytracingAccelerationStructurePrebuildInfo => M::GetRaytracingAccelerationStructurePrebuildInfo);
+    assert_slot!(Vtbl, CheckDriverMatchingIdentifier => M::CheckDriverMatchingIdentifier);
+};
+
+/// `ID3D12Device6` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device6Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+    CreateLifetimeTracker,
+    RemoveDevice,
+    EnumerateMetaCommands,
+    EnumerateMetaCommandParameters,
+    CreateMetaCommand,
+    CreateStateObject,
+    GetRaytracingAccelerationStructurePrebuildInfo,
+    CheckDriverMatchingIdentifier,
+    SetBackgroundProcessingMode,
+}
+
+impl InterfaceMethods for DirectX12Device6Methods {
+    const INTERFACE: &'static str = "ID3D12Device6";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device6_Vtbl as Vtbl;
+    use DirectX12Device6Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.base__.base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.base__.base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, base__.base__.CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, base__.base__.CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, base__.base__.CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, base__.base__.CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, base__.base__.GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+    assert_slot!(Vtbl, base__.CreateLifetimeTracker => M::CreateLifetimeTracker);
+    assert_slot!(Vtbl, base__.RemoveDevice => M::RemoveDevice);
+    assert_slot!(Vtbl, base__.EnumerateMetaCommands => M::EnumerateMetaCommands);
+    assert_slot!(Vtbl, base__.EnumerateMetaCommandParameters => M::EnumerateMetaCommandParameters);
+    assert_slot!(Vtbl, base__.CreateMetaCommand => M::CreateMetaCommand);
+    assert_slot!(Vtbl, base__.CreateStateObject => M::CreateStateObject);
+    assert_slot!(Vtbl, base__.GetRaytracingAccelerationStructurePrebuildInfo => M::GetRaytracingAccelerationStructurePrebuildInfo);
+    assert_slot!(Vtbl, base__.CheckDriverMatchingIdentifier => M::CheckDriverMatchingIdentifier);
+    assert_slot!(Vtbl, SetBackgroundProcessingMode => M::SetBackgroundProcessingMode);
+};
+
+/// `ID3D12Device7` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device7Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+    CreateLifetimeTracker,
+    RemoveDevice,
+    EnumerateMetaCommands,
+    EnumerateMetaCommandParameters,
+    CreateMetaCommand,
+    CreateStateObject,
+    GetRaytracingAccelerationStructurePrebuildInfo,
+    CheckDriverMatchingIdentifier,
+    SetBackgroundProcessingMode,
+    AddToStateObject,
+    CreateProtectedResourceSession1,
+}
+
+impl InterfaceMethods for DirectX12Device7Methods {
+    const INTERFACE: &'static str = "ID3D12Device7";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device7_Vtbl as Vtbl;
+    use DirectX12Device7Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.base__.CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+    assert_slot!(Vtbl, base__.base__.CreateLifetimeTracker => M::CreateLifetimeTracker);
+    assert_slot!(Vtbl, base__.base__.RemoveDevice => M::RemoveDevice);
+    assert_slot!(Vtbl, base__.base__.EnumerateMetaCommands => M::EnumerateMetaCommands);
+    assert_slot!(Vtbl, base__.base__.EnumerateMetaCommandParameters => M::EnumerateMetaCommandParameters);
+    assert_slot!(Vtbl, base__.base__.CreateMetaCommand => M::CreateMetaCommand);
+    assert_slot!(Vtbl, base__.base__.CreateStateObject => M::CreateStateObject);
+    assert_slot!(Vtbl, base__.base__.GetRaytracingAccelerationStructurePrebuildInfo => M::GetRaytracingAccelerationStructurePrebuildInfo);
+    assert_slot!(Vtbl, base__.base__.CheckDriverMatchingIdentifier => M::CheckDriverMatchingIdentifier);
+    assert_slot!(Vtbl, base__.SetBackgroundProcessingMode => M::SetBackgroundProcessingMode);
+    assert_slot!(Vtbl, AddToStateObject => M::AddToStateObject);
+    assert_slot!(Vtbl, CreateProtectedResourceSession1 => M::CreateProtectedResourceSession1);
+};
+
+/// `ID3D12Device8` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device8Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+    CreateLifetimeTracker,
+    RemoveDevice,
+    EnumerateMetaCommands,
+    EnumerateMetaCommandParameters,
+    CreateMetaCommand,
+    CreateStateObject,
+    GetRaytracingAccelerationStructurePrebuildInfo,
+    CheckDriverMatchingIdentifier,
+    SetBackgroundProcessingMode,
+    AddToStateObject,
+    CreateProtectedResourceSession1,
+    GetResourceAllocationInfo2,
+    CreateCommittedResource2,
+    CreatePlacedResource1,
+    CreateSamplerFeedbackUnorderedAccessView,
+    GetCopyableFootprints1,
+}
+
+impl InterfaceMethods for DirectX12Device8Methods {
+    const INTERFACE: &'static str = "ID3D12Device8";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device8_Vtbl as Vtbl;
+    use DirectX12Device8Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+    assert_slot!(Vtbl, base__.base__.base__.CreateLifetimeTracker => M::CreateLifetimeTracker);
+    assert_slot!(Vtbl, base__.base__.base__.RemoveDevice => M::RemoveDevice);
+    assert_slot!(Vtbl, base__.base__.base__.EnumerateMetaCommands => M::EnumerateMetaCommands);
+    assert_slot!(Vtbl, base__.base__.base__.EnumerateMetaCommandParameters => M::EnumerateMetaCommandParameters);
+    assert_slot!(Vtbl, base__.base__.base__.CreateMetaCommand => M::CreateMetaCommand);
+    assert_slot!(Vtbl, base__.base__.base__.CreateStateObject => M::CreateStateObject);
+    assert_slot!(Vtbl, base__.base__.base__.GetRaytracingAccelerationStructurePrebuildInfo => M::GetRaytracingAccelerationStructurePrebuildInfo);
+    assert_slot!(Vtbl, base__.base__.base__.CheckDriverMatchingIdentifier => M::CheckDriverMatchingIdentifier);
+    assert_slot!(Vtbl, base__.base__.SetBackgroundProcessingMode => M::SetBackgroundProcessingMode);
+    assert_slot!(Vtbl, base__.AddToStateObject => M::AddToStateObject);
+    assert_slot!(Vtbl, base__.CreateProtectedResourceSession1 => M::CreateProtectedResourceSession1);
+    assert_slot!(Vtbl, GetResourceAllocationInfo2 => M::GetResourceAllocationInfo2);
+    assert_slot!(Vtbl, CreateCommittedResource2 => M::CreateCommittedResource2);
+    assert_slot!(Vtbl, CreatePlacedResource1 => M::CreatePlacedResource1);
+    assert_slot!(Vtbl, CreateSamplerFeedbackUnorderedAccessView => M::CreateSamplerFeedbackUnorderedAccessView);
+    assert_slot!(Vtbl, GetCopyableFootprints1 => M::GetCopyableFootprints1);
+};
+
+/// `ID3D12Device9` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device9Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+    CreateLifetimeTracker,
+    RemoveDevice,
+    EnumerateMetaCommands,
+    EnumerateMetaCommandParameters,
+    CreateMetaCommand,
+    CreateStateObject,
+    GetRaytracingAccelerationStructurePrebuildInfo,
+    CheckDriverMatchingIdentifier,
+    SetBackgroundProcessingMode,
+    AddToStateObject,
+    CreateProtectedResourceSession1,
+    GetResourceAllocationInfo2,
+    CreateCommittedResource2,
+    CreatePlacedResource1,
+    CreateSamplerFeedbackUnorderedAccessView,
+    GetCopyableFootprints1,
+    CreateShaderCacheSession,
+    ShaderCacheControl,
+    CreateCommandQueue1,
+}
+
+impl InterfaceMethods for DirectX12Device9Methods {
+    const INTERFACE: &'static str = "ID3D12Device9";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device9_Vtbl as Vtbl;
+    use DirectX12Device9Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateLifetimeTracker => M::CreateLifetimeTracker);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RemoveDevice => M::RemoveDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EnumerateMetaCommands => M::EnumerateMetaCommands);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EnumerateMetaCommandParameters => M::EnumerateMetaCommandParameters);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateMetaCommand => M::CreateMetaCommand);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateStateObject => M::CreateStateObject);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetRaytracingAccelerationStructurePrebuildInfo => M::GetRaytracingAccelerationStructurePrebuildInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckDriverMatchingIdentifier => M::CheckDriverMatchingIdentifier);
+    assert_slot!(Vtbl, base__.base__.base__.SetBackgroundProcessingMode => M::SetBackgroundProcessingMode);
+    assert_slot!(Vtbl, base__.base__.AddToStateObject => M::AddToStateObject);
+    assert_slot!(Vtbl, base__.base__.CreateProtectedResourceSession1 => M::CreateProtectedResourceSession1);
+    assert_slot!(Vtbl, base__.GetResourceAllocationInfo2 => M::GetResourceAllocationInfo2);
+    assert_slot!(Vtbl, base__.CreateCommittedResource2 => M::CreateCommittedResource2);
+    assert_slot!(Vtbl, base__.CreatePlacedResource1 => M::CreatePlacedResource1);
+    assert_slot!(Vtbl, base__.CreateSamplerFeedbackUnorderedAccessView => M::CreateSamplerFeedbackUnorderedAccessView);
+    assert_slot!(Vtbl, base__.GetCopyableFootprints1 => M::GetCopyableFootprints1);
+    assert_slot!(Vtbl, CreateShaderCacheSession => M::CreateShaderCacheSession);
+    assert_slot!(Vtbl, ShaderCacheControl => M::ShaderCacheControl);
+    assert_slot!(Vtbl, CreateCommandQueue1 => M::CreateCommandQueue1);
+};
+
+/// `ID3D12Device10` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12Device10Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetNodeCount,
+    CreateCommandQueue,
+    CreateCommandAllocator,
+    CreateGraphicsPipelineState,
+    CreateComputePipelineState,
+    CreateCommandList,
+    CheckFeatureSupport,
+    CreateDescriptorHeap,
+    GetDescriptorHandleIncrementSize,
+    CreateRootSignature,
+    CreateConstantBufferView,
+    CreateShaderResourceView,
+    CreateUnorderedAccessView,
+    CreateRenderTargetView,
+    CreateDepthStencilView,
+    CreateSampler,
+    CopyDescriptors,
+    CopyDescriptorsSimple,
+    GetResourceAllocationInfo,
+    GetCustomHeapProperties,
+    CreateCommittedResource,
+    CreateHeap,
+    CreatePlacedResource,
+    CreateReservedResource,
+    CreateSharedHandle,
+    OpenSharedHandle,
+    OpenSharedHandleByName,
+    MakeResident,
+    Evict,
+    CreateFence,
+    GetDeviceRemovedReason,
+    GetCopyableFootprints,
+    CreateQueryHeap,
+    SetStablePowerState,
+    CreateCommandSignature,
+    GetResourceTiling,
+    GetAdapterLuid,
+    CreatePipelineLibrary,
+    SetEventOnMultipleFenceCompletion,
+    SetResidencyPriority,
+    CreatePipelineState,
+    OpenExistingHeapFromAddress,
+    OpenExistingHeapFromFileMapping,
+    EnqueueMakeResident,
+    CreateCommandList1,
+    CreateProtectedResourceSession,
+    CreateCommittedResource1,
+    CreateHeap1,
+    CreateReservedResource1,
+    GetResourceAllocationInfo1,
+    CreateLifetimeTracker,
+    RemoveDevice,
+    EnumerateMetaCommands,
+    EnumerateMetaCommandParameters,
+    CreateMetaCommand,
+    CreateStateObject,
+    GetRaytracingAccelerationStructurePrebuildInfo,
+    CheckDriverMatchingIdentifier,
+    SetBackgroundProcessingMode,
+    AddToStateObject,
+    CreateProtectedResourceSession1,
+    GetResourceAllocationInfo2,
+    CreateCommittedResource2,
+    CreatePlacedResource1,
+    CreateSamplerFeedbackUnorderedAccessView,
+    GetCopyableFootprints1,
+    CreateShaderCacheSession,
+    ShaderCacheControl,
+    CreateCommandQueue1,
+    CreateCommittedResource3,
+    CreatePlacedResource2,
+    CreateReservedResource2,
+}
+
+impl InterfaceMethods for DirectX12Device10Methods {
+    const INTERFACE: &'static str = "ID3D12Device10";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Device10_Vtbl as Vtbl;
+    use DirectX12Device10Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetNodeCount => M::GetNodeCount);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandQueue => M::CreateCommandQueue);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandAllocator => M::CreateCommandAllocator);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateGraphicsPipelineState => M::CreateGraphicsPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateComputePipelineState => M::CreateComputePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandList => M::CreateCommandList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateDescriptorHeap => M::CreateDescriptorHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetDescriptorHandleIncrementSize => M::GetDescriptorHandleIncrementSize);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateRootSignature => M::CreateRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateConstantBufferView => M::CreateConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateShaderResourceView => M::CreateShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateUnorderedAccessView => M::CreateUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateRenderTargetView => M::CreateRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateDepthStencilView => M::CreateDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateSampler => M::CreateSampler);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CopyDescriptors => M::CopyDescriptors);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CopyDescriptorsSimple => M::CopyDescriptorsSimple);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetResourceAllocationInfo => M::GetResourceAllocationInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetCustomHeapProperties => M::GetCustomHeapProperties);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommittedResource => M::CreateCommittedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateHeap => M::CreateHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreatePlacedResource => M::CreatePlacedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateReservedResource => M::CreateReservedResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateSharedHandle => M::CreateSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.OpenSharedHandle => M::OpenSharedHandle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.OpenSharedHandleByName => M::OpenSharedHandleByName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.MakeResident => M::MakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.Evict => M::Evict);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateFence => M::CreateFence);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetDeviceRemovedReason => M::GetDeviceRemovedReason);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetCopyableFootprints => M::GetCopyableFootprints);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateQueryHeap => M::CreateQueryHeap);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetStablePowerState => M::SetStablePowerState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.CreateCommandSignature => M::CreateCommandSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetResourceTiling => M::GetResourceTiling);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetAdapterLuid => M::GetAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.CreatePipelineLibrary => M::CreatePipelineLibrary);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetEventOnMultipleFenceCompletion => M::SetEventOnMultipleFenceCompletion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetResidencyPriority => M::SetResidencyPriority);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.CreatePipelineState => M::CreatePipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OpenExistingHeapFromAddress => M::OpenExistingHeapFromAddress);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OpenExistingHeapFromFileMapping => M::OpenExistingHeapFromFileMapping);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.EnqueueMakeResident => M::EnqueueMakeResident);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommandList1 => M::CreateCommandList1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateProtectedResourceSession => M::CreateProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateCommittedResource1 => M::CreateCommittedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateHeap1 => M::CreateHeap1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateReservedResource1 => M::CreateReservedResource1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetResourceAllocationInfo1 => M::GetResourceAllocationInfo1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateLifetimeTracker => M::CreateLifetimeTracker);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RemoveDevice => M::RemoveDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EnumerateMetaCommands => M::EnumerateMetaCommands);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EnumerateMetaCommandParameters => M::EnumerateMetaCommandParameters);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateMetaCommand => M::CreateMetaCommand);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateStateObject => M::CreateStateObject);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetRaytracingAccelerationStructurePrebuildInfo => M::GetRaytracingAccelerationStructurePrebuildInfo);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CheckDriverMatchingIdentifier => M::CheckDriverMatchingIdentifier);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetBackgroundProcessingMode => M::SetBackgroundProcessingMode);
+    assert_slot!(Vtbl, base__.base__.base__.AddToStateObject => M::AddToStateObject);
+    assert_slot!(Vtbl, base__.base__.base__.CreateProtectedResourceSession1 => M::CreateProtectedResourceSession1);
+    assert_slot!(Vtbl, base__.base__.GetResourceAllocationInfo2 => M::GetResourceAllocationInfo2);
+    assert_slot!(Vtbl, base__.base__.CreateCommittedResource2 => M::CreateCommittedResource2);
+    assert_slot!(Vtbl, base__.base__.CreatePlacedResource1 => M::CreatePlacedResource1);
+    assert_slot!(Vtbl, base__.base__.CreateSamplerFeedbackUnorderedAccessView => M::CreateSamplerFeedbackUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.GetCopyableFootprints1 => M::GetCopyableFootprints1);
+    assert_slot!(Vtbl, base__.CreateShaderCacheSession => M::CreateShaderCacheSession);
+    assert_slot!(Vtbl, base__.ShaderCacheControl => M::ShaderCacheControl);
+    assert_slot!(Vtbl, base__.CreateCommandQueue1 => M::CreateCommandQueue1);
+    assert_slot!(Vtbl, CreateCommittedResource3 => M::CreateCommittedResource3);
+    assert_slot!(Vtbl, CreatePlacedResource2 => M::CreatePlacedResource2);
+    assert_slot!(Vtbl, CreateReservedResource2 => M::CreateReservedResource2);
+};
+
+/// `ID3D12GraphicsCommandList1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+}
+
+impl InterfaceMethods for DirectX12CommandList1Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList1_Vtbl as Vtbl;
+    use DirectX12CommandList1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, SetViewInstanceMask => M::SetViewInstanceMask);
+};
+
+/// `ID3D12GraphicsCommandList2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+    WriteBufferImmediate,
+}
+
+impl InterfaceMethods for DirectX12CommandList2Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList2_Vtbl as Vtbl;
+    use DirectX12CommandList2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, base__.AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, base__.AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, base__.OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, base__.SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, base__.ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, base__.SetViewInstanceMask => M::SetViewInstanceMask);
+    assert_slot!(Vtbl, WriteBufferImmediate => M::WriteBufferImmediate);
+};
+
+/// `ID3D12GraphicsCommandList3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+    WriteBufferImmediate,
+    SetProtectedResourceSession,
+}
+
+impl InterfaceMethods for DirectX12CommandList3Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList3_Vtbl as Vtbl;
+    use DirectX12CommandList3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.base__.base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.base__.base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.base__.base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.base__.base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.base__.base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.base__.base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.base__.base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.base__.base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, base__.base__.AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, base__.base__.AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, base__.base__.OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, base__.base__.SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, base__.base__.ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.SetViewInstanceMask => M::SetViewInstanceMask);
+    assert_slot!(Vtbl, base__.WriteBufferImmediate => M::WriteBufferImmediate);
+    assert_slot!(Vtbl, SetProtectedResourceSession => M::SetProtectedResourceSession);
+};
+
+/// `ID3D12GraphicsCommandList4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+    WriteBufferImmediate,
+    SetProtectedResourceSession,
+    BeginRenderPass,
+    EndRenderPass,
+    InitializeMetaCommand,
+    ExecuteMetaCommand,
+    BuildRaytracingAccelerationStructure,
+    EmitRaytracingAccelerationStructurePostbuildInfo,
+    CopyRaytracingAccelerationStructure,
+    SetPipelineState1,
+    DispatchRays,
+}
+
+impl InterfaceMethods for DirectX12CommandList4Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList4_Vtbl as Vtbl;
+    use DirectX12CommandList4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.base__.base__.base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, base__.base__.base__.AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, base__.base__.base__.OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, base__.base__.base__.SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, base__.base__.base__.ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.base__.SetViewInstanceMask => M::SetViewInstanceMask);
+    assert_slot!(Vtbl, base__.base__.WriteBufferImmediate => M::WriteBufferImmediate);
+    assert_slot!(Vtbl, base__.SetProtectedResourceSession => M::SetProtectedResourceSession);
+    assert_slot!(Vtbl, BeginRenderPass => M::BeginRenderPass);
+    assert_slot!(Vtbl, EndRenderPass => M::EndRenderPass);
+    assert_slot!(Vtbl, InitializeMetaCommand => M::InitializeMetaCommand);
+    assert_slot!(Vtbl, ExecuteMetaCommand => M::ExecuteMetaCommand);
+    assert_slot!(Vtbl, BuildRaytracingAccelerationStructure => M::BuildRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, EmitRaytracingAccelerationStructurePostbuildInfo => M::EmitRaytracingAccelerationStructurePostbuildInfo);
+    assert_slot!(Vtbl, CopyRaytracingAccelerationStructure => M::CopyRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, SetPipelineState1 => M::SetPipelineState1);
+    assert_slot!(Vtbl, DispatchRays => M::DispatchRays);
+};
+
+/// `ID3D12GraphicsCommandList5` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList5Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+    WriteBufferImmediate,
+    SetProtectedResourceSession,
+    BeginRenderPass,
+    EndRenderPass,
+    InitializeMetaCommand,
+    ExecuteMetaCommand,
+    BuildRaytracingAccelerationStructure,
+    EmitRaytracingAccelerationStructurePostbuildInfo,
+    CopyRaytracingAccelerationStructure,
+    SetPipelineState1,
+    DispatchRays,
+    RSSetShadingRate,
+    RSSetShadingRateImage,
+}
+
+impl InterfaceMethods for DirectX12CommandList5Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList5";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList5_Vtbl as Vtbl;
+    use DirectX12CommandList5Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, base__.base__.base__.base__.OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetViewInstanceMask => M::SetViewInstanceMask);
+    assert_slot!(Vtbl, base__.base__.base__.WriteBufferImmediate => M::WriteBufferImmediate);
+    assert_slot!(Vtbl, base__.base__.SetProtectedResourceSession => M::SetProtectedResourceSession);
+    assert_slot!(Vtbl, base__.BeginRenderPass => M::BeginRenderPass);
+    assert_slot!(Vtbl, base__.EndRenderPass => M::EndRenderPass);
+    assert_slot!(Vtbl, base__.InitializeMetaCommand => M::InitializeMetaCommand);
+    assert_slot!(Vtbl, base__.ExecuteMetaCommand => M::ExecuteMetaCommand);
+    assert_slot!(Vtbl, base__.BuildRaytracingAccelerationStructure => M::BuildRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, base__.EmitRaytracingAccelerationStructurePostbuildInfo => M::EmitRaytracingAccelerationStructurePostbuildInfo);
+    assert_slot!(Vtbl, base__.CopyRaytracingAccelerationStructure => M::CopyRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, base__.SetPipelineState1 => M::SetPipelineState1);
+    assert_slot!(Vtbl, base__.DispatchRays => M::DispatchRays);
+    assert_slot!(Vtbl, RSSetShadingRate => M::RSSetShadingRate);
+    assert_slot!(Vtbl, RSSetShadingRateImage => M::RSSetShadingRateImage);
+};
+
+/// `ID3D12GraphicsCommandList6` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList6Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+    WriteBufferImmediate,
+    SetProtectedResourceSession,
+    BeginRenderPass,
+    EndRenderPass,
+    InitializeMetaCommand,
+    ExecuteMetaCommand,
+    BuildRaytracingAccelerationStructure,
+    EmitRaytracingAccelerationStructurePostbuildInfo,
+    CopyRaytracingAccelerationStructure,
+    SetPipelineState1,
+    DispatchRays,
+    RSSetShadingRate,
+    RSSetShadingRateImage,
+    DispatchMesh,
+}
+
+impl InterfaceMethods for DirectX12CommandList6Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList6";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList6_Vtbl as Vtbl;
+    use DirectX12CommandList6Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetViewInstanceMask => M::SetViewInstanceMask);
+    assert_slot!(Vtbl, base__.base__.base__.base__.WriteBufferImmediate => M::WriteBufferImmediate);
+    assert_slot!(Vtbl, base__.base__.base__.SetProtectedResourceSession => M::SetProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.BeginRenderPass => M::BeginRenderPass);
+    assert_slot!(Vtbl, base__.base__.EndRenderPass => M::EndRenderPass);
+    assert_slot!(Vtbl, base__.base__.InitializeMetaCommand => M::InitializeMetaCommand);
+    assert_slot!(Vtbl, base__.base__.ExecuteMetaCommand => M::ExecuteMetaCommand);
+    assert_slot!(Vtbl, base__.base__.BuildRaytracingAccelerationStructure => M::BuildRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, base__.base__.EmitRaytracingAccelerationStructurePostbuildInfo => M::EmitRaytracingAccelerationStructurePostbuildInfo);
+    assert_slot!(Vtbl, base__.base__.CopyRaytracingAccelerationStructure => M::CopyRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, base__.base__.SetPipelineState1 => M::SetPipelineState1);
+    assert_slot!(Vtbl, base__.base__.DispatchRays => M::DispatchRays);
+    assert_slot!(Vtbl, base__.RSSetShadingRate => M::RSSetShadingRate);
+    assert_slot!(Vtbl, base__.RSSetShadingRateImage => M::RSSetShadingRateImage);
+    assert_slot!(Vtbl, DispatchMesh => M::DispatchMesh);
+};
+
+/// `ID3D12GraphicsCommandList7` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12CommandList7Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetType,
+    Close,
+    Reset,
+    ClearState,
+    DrawInstanced,
+    DrawIndexedInstanced,
+    Dispatch,
+    CopyBufferRegion,
+    CopyTextureRegion,
+    CopyResource,
+    CopyTiles,
+    ResolveSubresource,
+    IASetPrimitiveTopology,
+    RSSetViewports,
+    RSSetScissorRects,
+    OMSetBlendFactor,
+    OMSetStencilRef,
+    SetPipelineState,
+    ResourceBarrier,
+    ExecuteBundle,
+    SetDescriptorHeaps,
+    SetComputeRootSignature,
+    SetGraphicsRootSignature,
+    SetComputeRootDescriptorTable,
+    SetGraphicsRootDescriptorTable,
+    SetComputeRoot32BitConstant,
+    SetGraphicsRoot32BitConstant,
+    SetComputeRoot32BitConstants,
+    SetGraphicsRoot32BitConstants,
+    SetComputeRootConstantBufferView,
+    SetGraphicsRootConstantBufferView,
+    SetComputeRootShaderResourceView,
+    SetGraphicsRootShaderResourceView,
+    SetComputeRootUnorderedAccessView,
+    SetGraphicsRootUnorderedAccessView,
+    IASetIndexBuffer,
+    IASetVertexBuffers,
+    SOSetTargets,
+    OMSetRenderTargets,
+    ClearDepthStencilView,
+    ClearRenderTargetView,
+    ClearUnorderedAccessViewUint,
+    ClearUnorderedAccessViewFloat,
+    DiscardResource,
+    BeginQuery,
+    EndQuery,
+    ResolveQueryData,
+    SetPredication,
+    SetMarker,
+    BeginEvent,
+    EndEvent,
+    ExecuteIndirect,
+    AtomicCopyBufferUINT,
+    AtomicCopyBufferUINT64,
+    OMSetDepthBounds,
+    SetSamplePositions,
+    ResolveSubresourceRegion,
+    SetViewInstanceMask,
+    WriteBufferImmediate,
+    SetProtectedResourceSession,
+    BeginRenderPass,
+    EndRenderPass,
+    InitializeMetaCommand,
+    ExecuteMetaCommand,
+    BuildRaytracingAccelerationStructure,
+    EmitRaytracingAccelerationStructurePostbuildInfo,
+    CopyRaytracingAccelerationStructure,
+    SetPipelineState1,
+    DispatchRays,
+    RSSetShadingRate,
+    RSSetShadingRateImage,
+    DispatchMesh,
+    Barrier,
+}
+
+impl InterfaceMethods for DirectX12CommandList7Methods {
+    const INTERFACE: &'static str = "ID3D12GraphicsCommandList7";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12GraphicsCommandList7_Vtbl as Vtbl;
+    use DirectX12CommandList7Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetType => M::GetType);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Close => M::Close);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ClearState => M::ClearState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.DrawInstanced => M::DrawInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.DrawIndexedInstanced => M::DrawIndexedInstanced);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Dispatch => M::Dispatch);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CopyBufferRegion => M::CopyBufferRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CopyTextureRegion => M::CopyTextureRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CopyResource => M::CopyResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CopyTiles => M::CopyTiles);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ResolveSubresource => M::ResolveSubresource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.IASetPrimitiveTopology => M::IASetPrimitiveTopology);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.RSSetViewports => M::RSSetViewports);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.RSSetScissorRects => M::RSSetScissorRects);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OMSetBlendFactor => M::OMSetBlendFactor);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OMSetStencilRef => M::OMSetStencilRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPipelineState => M::SetPipelineState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ResourceBarrier => M::ResourceBarrier);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ExecuteBundle => M::ExecuteBundle);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetDescriptorHeaps => M::SetDescriptorHeaps);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRootSignature => M::SetComputeRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRootSignature => M::SetGraphicsRootSignature);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRootDescriptorTable => M::SetComputeRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRootDescriptorTable => M::SetGraphicsRootDescriptorTable);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRoot32BitConstant => M::SetComputeRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRoot32BitConstant => M::SetGraphicsRoot32BitConstant);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRoot32BitConstants => M::SetComputeRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRoot32BitConstants => M::SetGraphicsRoot32BitConstants);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRootConstantBufferView => M::SetComputeRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRootConstantBufferView => M::SetGraphicsRootConstantBufferView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRootShaderResourceView => M::SetComputeRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRootShaderResourceView => M::SetGraphicsRootShaderResourceView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetComputeRootUnorderedAccessView => M::SetComputeRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetGraphicsRootUnorderedAccessView => M::SetGraphicsRootUnorderedAccessView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.IASetIndexBuffer => M::IASetIndexBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.IASetVertexBuffers => M::IASetVertexBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SOSetTargets => M::SOSetTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.OMSetRenderTargets => M::OMSetRenderTargets);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ClearDepthStencilView => M::ClearDepthStencilView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ClearRenderTargetView => M::ClearRenderTargetView);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ClearUnorderedAccessViewUint => M::ClearUnorderedAccessViewUint);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ClearUnorderedAccessViewFloat => M::ClearUnorderedAccessViewFloat);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.DiscardResource => M::DiscardResource);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.BeginQuery => M::BeginQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.EndQuery => M::EndQuery);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ResolveQueryData => M::ResolveQueryData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPredication => M::SetPredication);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetMarker => M::SetMarker);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.BeginEvent => M::BeginEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.EndEvent => M::EndEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.ExecuteIndirect => M::ExecuteIndirect);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AtomicCopyBufferUINT => M::AtomicCopyBufferUINT);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AtomicCopyBufferUINT64 => M::AtomicCopyBufferUINT64);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.OMSetDepthBounds => M::OMSetDepthBounds);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetSamplePositions => M::SetSamplePositions);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ResolveSubresourceRegion => M::ResolveSubresourceRegion);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetViewInstanceMask => M::SetViewInstanceMask);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.WriteBufferImmediate => M::WriteBufferImmediate);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetProtectedResourceSession => M::SetProtectedResourceSession);
+    assert_slot!(Vtbl, base__.base__.base__.BeginRenderPass => M::BeginRenderPass);
+    assert_slot!(Vtbl, base__.base__.base__.EndRenderPass => M::EndRenderPass);
+    assert_slot!(Vtbl, base__.base__.base__.InitializeMetaCommand => M::InitializeMetaCommand);
+    assert_slot!(Vtbl, base__.base__.base__.ExecuteMetaCommand => M::ExecuteMetaCommand);
+    assert_slot!(Vtbl, base__.base__.base__.BuildRaytracingAccelerationStructure => M::BuildRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, base__.base__.base__.EmitRaytracingAccelerationStructurePostbuildInfo => M::EmitRaytracingAccelerationStructurePostbuildInfo);
+    assert_slot!(Vtbl, base__.base__.base__.CopyRaytracingAccelerationStructure => M::CopyRaytracingAccelerationStructure);
+    assert_slot!(Vtbl, base__.base__.base__.SetPipelineState1 => M::SetPipelineState1);
+    assert_slot!(Vtbl, base__.base__.base__.DispatchRays => M::DispatchRays);
+    assert_slot!(Vtbl, base__.base__.RSSetShadingRate => M::RSSetShadingRate);
+    assert_slot!(Vtbl, base__.base__.RSSetShadingRateImage => M::RSSetShadingRateImage);
+    assert_slot!(Vtbl, base__.DispatchMesh => M::DispatchMesh);
+    assert_slot!(Vtbl, Barrier => M::Barrier);
+};
+
+/// `ID3D12Fence` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12FenceMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetCompletedValue,
+    SetEventOnCompletion,
+    Signal,
+}
+
+impl InterfaceMethods for DirectX12FenceMethods {
+    const INTERFACE: &'static str = "ID3D12Fence";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Fence_Vtbl as Vtbl;
+    use DirectX12FenceMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, GetCompletedValue => M::GetCompletedValue);
+    assert_slot!(Vtbl, SetEventOnCompletion => M::SetEventOnCompletion);
+    assert_slot!(Vtbl, Signal => M::Signal);
+};
+
+/// `ID3D12Resource` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12ResourceMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    Map,
+    Unmap,
+    GetDesc,
+    GetGPUVirtualAddress,
+    WriteToSubresource,
+    ReadFromSubresource,
+    GetHeapProperties,
+}
+
+impl InterfaceMethods for DirectX12ResourceMethods {
+    const INTERFACE: &'static str = "ID3D12Resource";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12Resource_Vtbl as Vtbl;
+    use DirectX12ResourceMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, Map => M::Map);
+    assert_slot!(Vtbl, Unmap => M::Unmap);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, GetGPUVirtualAddress => M::GetGPUVirtualAddress);
+    assert_slot!(Vtbl, WriteToSubresource => M::WriteToSubresource);
+    assert_slot!(Vtbl, ReadFromSubresource => M::ReadFromSubresource);
+    assert_slot!(Vtbl, GetHeapProperties => M::GetHeapProperties);
+};
+
+/// `ID3D12DescriptorHeap` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX12DescriptorHeapMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    GetPrivateData,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    SetName,
+    GetDevice,
+    GetDesc,
+    GetCPUDescriptorHandleForHeapStart,
+    GetGPUDescriptorHandleForHeapStart,
+}
+
+impl InterfaceMethods for DirectX12DescriptorHeapMethods {
+    const INTERFACE: &'static str = "ID3D12DescriptorHeap";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D12::ID3D12DescriptorHeap_Vtbl as Vtbl;
+    use DirectX12DescriptorHeapMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.SetName => M::SetName);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, GetCPUDescriptorHandleForHeapStart => M::GetCPUDescriptorHandleForHeapStart);
+    assert_slot!(Vtbl, GetGPUDescriptorHandleForHeapStart => M::GetGPUDescriptorHandleForHeapStart);
+};
+
+/// Typed signatures of [`DirectX12DeviceMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device {
+    use std::ffi::c_void;
+
+    use super::DirectX12DeviceMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12DeviceMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandQueueMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_queue {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandQueueMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandQueueMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        UpdateTileMappings(p_resource: *mut c_void, num_resource_regions: u32, p_resource_region_start_coordinates: *mut c_void, p_resource_region_sizes: *mut c_void, p_heap: *mut c_void, num_ranges: u32, p_range_flags: *mut i32, p_heap_range_start_offsets: *mut u32, p_range_tile_counts: *mut u32, flags: i32),
+        CopyTileMappings(p_dst_resource: *mut c_void, p_dst_region_start_coordinate: *mut c_void, p_src_resource: *mut c_void, p_src_region_start_coordinate: *mut c_void, p_region_size: *mut c_void, flags: i32),
+        ExecuteCommandLists(num_command_lists: u32, pp_command_lists: *mut *mut c_void),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        Signal(p_fence: *mut c_void, value: u64) -> HRESULT,
+        Wait(p_fence: *mut c_void, value: u64) -> HRESULT,
+        GetTimestampFrequency(p_frequency: *mut u64) -> HRESULT,
+        GetClockCalibration(p_gpu_timestamp: *mut u64, p_cpu_timestamp: *mut u64) -> HRESULT,
+        GetDesc(result: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandAllocatorMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_allocator {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandAllocatorMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandAllocatorMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        Reset() -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandListMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandListMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandListMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+    }
+}
+
+/// Typed signatures of [`DirectX12SwapchainMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain {
+    use std::ffi::c_void;
+
+    use super::DirectX12SwapchainMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12SwapchainMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device1 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device2 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device3 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device4 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12Device5Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device5 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device5Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device5Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateLifetimeTracker(p_owner: *mut c_void, riid: *mut c_void, ppv_tracker: *mut *mut c_void) -> HRESULT,
+        RemoveDevice(),
+        EnumerateMetaCommands(p_num_meta_commands: *mut u32, p_descs: *mut c_void) -> HRESULT,
+        EnumerateMetaCommandParameters(command_id: *mut c_void, stage: i32, p_total_structure_size_in_bytes: *mut u32, p_parameter_count: *mut u32, p_parameter_descs: *mut c_void) -> HRESULT,
+        CreateMetaCommand(command_id: *mut c_void, node_mask: u32, p_creation_parameters_data: *mut c_void, creation_parameters_data_size_in_bytes: usize, riid: *mut c_void, pp_meta_command: *mut *mut c_void) -> HRESULT,
+        CreateStateObject(p_desc: *mut c_void, riid: *mut c_void, pp_state_object: *mut *mut c_void) -> HRESULT,
+        GetRaytracingAccelerationStructurePrebuildInfo(p_desc: *mut c_void, p_info: *mut c_void),
+        CheckDriverMatchingIdentifier(serialized_data_type: i32, p_identifier_to_check: *mut c_void) -> i32,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device6Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device6 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device6Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device6Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateLifetimeTracker(p_owner: *mut c_void, riid: *mut c_void, ppv_tracker: *mut *mut c_void) -> HRESULT,
+        RemoveDevice(),
+        EnumerateMetaCommands(p_num_meta_commands: *mut u32, p_descs: *mut c_void) -> HRESULT,
+        EnumerateMetaCommandParameters(command_id: *mut c_void, stage: i32, p_total_structure_size_in_bytes: *mut u32, p_parameter_count: *mut u32, p_parameter_descs: *mut c_void) -> HRESULT,
+        CreateMetaCommand(command_id: *mut c_void, node_mask: u32, p_creation_parameters_data: *mut c_void, creation_parameters_data_size_in_bytes: usize, riid: *mut c_void, pp_meta_command: *mut *mut c_void) -> HRESULT,
+        CreateStateObject(p_desc: *mut c_void, riid: *mut c_void, pp_state_object: *mut *mut c_void) -> HRESULT,
+        GetRaytracingAccelerationStructurePrebuildInfo(p_desc: *mut c_void, p_info: *mut c_void),
+        CheckDriverMatchingIdentifier(serialized_data_type: i32, p_identifier_to_check: *mut c_void) -> i32,
+        SetBackgroundProcessingMode(mode: i32, measurements_action: i32, h_event_to_signal_upon_completion: *mut c_void, pb_further_measurements_desired: *mut i32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device7Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device7 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device7Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device7Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateLifetimeTracker(p_owner: *mut c_void, riid: *mut c_void, ppv_tracker: *mut *mut c_void) -> HRESULT,
+        RemoveDevice(),
+        EnumerateMetaCommands(p_num_meta_commands: *mut u32, p_descs: *mut c_void) -> HRESULT,
+        EnumerateMetaCommandParameters(command_id: *mut c_void, stage: i32, p_total_structure_size_in_bytes: *mut u32, p_parameter_count: *mut u32, p_parameter_descs: *mut c_void) -> HRESULT,
+        CreateMetaCommand(command_id: *mut c_void, node_mask: u32, p_creation_parameters_data: *mut c_void, creation_parameters_data_size_in_bytes: usize, riid: *mut c_void, pp_meta_command: *mut *mut c_void) -> HRESULT,
+        CreateStateObject(p_desc: *mut c_void, riid: *mut c_void, pp_state_object: *mut *mut c_void) -> HRESULT,
+        GetRaytracingAccelerationStructurePrebuildInfo(p_desc: *mut c_void, p_info: *mut c_void),
+        CheckDriverMatchingIdentifier(serialized_data_type: i32, p_identifier_to_check: *mut c_void) -> i32,
+        SetBackgroundProcessingMode(mode: i32, measurements_action: i32, h_event_to_signal_upon_completion: *mut c_void, pb_further_measurements_desired: *mut i32) -> HRESULT,
+        AddToStateObject(p_addition: *mut c_void, p_state_object_to_grow_from: *mut c_void, riid: *mut c_void, pp_new_state_object: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession1(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device8Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device8 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device8Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device8Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateLifetimeTracker(p_owner: *mut c_void, riid: *mut c_void, ppv_tracker: *mut *mut c_void) -> HRESULT,
+        RemoveDevice(),
+        EnumerateMetaCommands(p_num_meta_commands: *mut u32, p_descs: *mut c_void) -> HRESULT,
+        EnumerateMetaCommandParameters(command_id: *mut c_void, stage: i32, p_total_structure_size_in_bytes: *mut u32, p_parameter_count: *mut u32, p_parameter_descs: *mut c_void) -> HRESULT,
+        CreateMetaCommand(command_id: *mut c_void, node_mask: u32, p_creation_parameters_data: *mut c_void, creation_parameters_data_size_in_bytes: usize, riid: *mut c_void, pp_meta_command: *mut *mut c_void) -> HRESULT,
+        CreateStateObject(p_desc: *mut c_void, riid: *mut c_void, pp_state_object: *mut *mut c_void) -> HRESULT,
+        GetRaytracingAccelerationStructurePrebuildInfo(p_desc: *mut c_void, p_info: *mut c_void),
+        CheckDriverMatchingIdentifier(serialized_data_type: i32, p_identifier_to_check: *mut c_void) -> i32,
+        SetBackgroundProcessingMode(mode: i32, measurements_action: i32, h_event_to_signal_upon_completion: *mut c_void, pb_further_measurements_desired: *mut i32) -> HRESULT,
+        AddToStateObject(p_addition: *mut c_void, p_state_object_to_grow_from: *mut c_void, riid: *mut c_void, pp_new_state_object: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession1(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo2(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateCommittedResource2(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource1(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSamplerFeedbackUnorderedAccessView(p_targeted_resource: *mut c_void, p_feedback_resource: *mut c_void, dest_descriptor: usize),
+        GetCopyableFootprints1(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+    }
+}
+
+/// Typed signatures of [`DirectX12Device9Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device9 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device9Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device9Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateLifetimeTracker(p_owner: *mut c_void, riid: *mut c_void, ppv_tracker: *mut *mut c_void) -> HRESULT,
+        RemoveDevice(),
+        EnumerateMetaCommands(p_num_meta_commands: *mut u32, p_descs: *mut c_void) -> HRESULT,
+        EnumerateMetaCommandParameters(command_id: *mut c_void, stage: i32, p_total_structure_size_in_bytes: *mut u32, p_parameter_count: *mut u32, p_parameter_descs: *mut c_void) -> HRESULT,
+        CreateMetaCommand(command_id: *mut c_void, node_mask: u32, p_creation_parameters_data: *mut c_void, creation_parameters_data_size_in_bytes: usize, riid: *mut c_void, pp_meta_command: *mut *mut c_void) -> HRESULT,
+        CreateStateObject(p_desc: *mut c_void, riid: *mut c_void, pp_state_object: *mut *mut c_void) -> HRESULT,
+        GetRaytracingAccelerationStructurePrebuildInfo(p_desc: *mut c_void, p_info: *mut c_void),
+        CheckDriverMatchingIdentifier(serialized_data_type: i32, p_identifier_to_check: *mut c_void) -> i32,
+        SetBackgroundProcessingMode(mode: i32, measurements_action: i32, h_event_to_signal_upon_completion: *mut c_void, pb_further_measurements_desired: *mut i32) -> HRESULT,
+        AddToStateObject(p_addition: *mut c_void, p_state_object_to_grow_from: *mut c_void, riid: *mut c_void, pp_new_state_object: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession1(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo2(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateCommittedResource2(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource1(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSamplerFeedbackUnorderedAccessView(p_targeted_resource: *mut c_void, p_feedback_resource: *mut c_void, dest_descriptor: usize),
+        GetCopyableFootprints1(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateShaderCacheSession(p_desc: *mut c_void, riid: *mut c_void, ppv_session: *mut *mut c_void) -> HRESULT,
+        ShaderCacheControl(kinds: i32, control: i32) -> HRESULT,
+        CreateCommandQueue1(p_desc: *mut c_void, creator_id: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12Device10Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device10 {
+    use std::ffi::c_void;
+
+    use super::DirectX12Device10Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12Device10Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetNodeCount() -> u32,
+        CreateCommandQueue(p_desc: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommandAllocator(r#type: i32, riid: *mut c_void, pp_command_allocator: *mut *mut c_void) -> HRESULT,
+        CreateGraphicsPipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateComputePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        CreateCommandList(node_mask: u32, r#type: i32, p_command_allocator: *mut c_void, p_initial_state: *mut c_void, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        CreateDescriptorHeap(p_descriptor_heap_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        GetDescriptorHandleIncrementSize(descriptor_heap_type: i32) -> u32,
+        CreateRootSignature(node_mask: u32, p_blob_with_root_signature: *mut c_void, blob_length_in_bytes: usize, riid: *mut c_void, ppv_root_signature: *mut *mut c_void) -> HRESULT,
+        CreateConstantBufferView(p_desc: *mut c_void, dest_descriptor: usize),
+        CreateShaderResourceView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateUnorderedAccessView(p_resource: *mut c_void, p_counter_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateRenderTargetView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateDepthStencilView(p_resource: *mut c_void, p_desc: *mut c_void, dest_descriptor: usize),
+        CreateSampler(p_desc: *mut c_void, dest_descriptor: usize),
+        CopyDescriptors(num_dest_descriptor_ranges: u32, p_dest_descriptor_range_starts: *mut c_void, p_dest_descriptor_range_sizes: *mut u32, num_src_descriptor_ranges: u32, p_src_descriptor_range_starts: *mut c_void, p_src_descriptor_range_sizes: *mut u32, descriptor_heaps_type: i32),
+        CopyDescriptorsSimple(num_descriptors: u32, dest_descriptor_range_start: usize, src_descriptor_range_start: usize, descriptor_heaps_type: i32),
+        GetResourceAllocationInfo(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void),
+        GetCustomHeapProperties(result: *mut c_void, node_mask: u32, heap_type: i32),
+        CreateCommittedResource(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSharedHandle(p_object: *mut c_void, p_attributes: *mut c_void, access: u32, name: *mut u16, p_handle: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandle(nt_handle: *mut c_void, riid: *mut c_void, ppv_obj: *mut *mut c_void) -> HRESULT,
+        OpenSharedHandleByName(name: *mut u16, access: u32, p_nt_handle: *mut *mut c_void) -> HRESULT,
+        MakeResident(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        Evict(num_objects: u32, pp_objects: *mut *mut c_void) -> HRESULT,
+        CreateFence(initial_value: u64, flags: i32, riid: *mut c_void, pp_fence: *mut *mut c_void) -> HRESULT,
+        GetDeviceRemovedReason() -> HRESULT,
+        GetCopyableFootprints(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateQueryHeap(p_desc: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        SetStablePowerState(enable: i32) -> HRESULT,
+        CreateCommandSignature(p_desc: *mut c_void, p_root_signature: *mut c_void, riid: *mut c_void, ppv_command_signature: *mut *mut c_void) -> HRESULT,
+        GetResourceTiling(p_tiled_resource: *mut c_void, p_num_tiles_for_entire_resource: *mut u32, p_packed_mip_desc: *mut c_void, p_standard_tile_shape_for_non_packed_mips: *mut c_void, p_num_subresource_tilings: *mut u32, first_subresource_tiling_to_get: u32, p_subresource_tilings_for_non_packed_mips: *mut c_void),
+        GetAdapterLuid(result: *mut c_void),
+        CreatePipelineLibrary(p_library_blob: *mut c_void, blob_length: usize, riid: *mut c_void, pp_pipeline_library: *mut *mut c_void) -> HRESULT,
+        SetEventOnMultipleFenceCompletion(pp_fences: *mut *mut c_void, p_fence_values: *mut u64, num_fences: u32, flags: i32, h_event: *mut c_void) -> HRESULT,
+        SetResidencyPriority(num_objects: u32, pp_objects: *mut *mut c_void, p_priorities: *mut i32) -> HRESULT,
+        CreatePipelineState(p_desc: *mut c_void, riid: *mut c_void, pp_pipeline_state: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromAddress(p_address: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        OpenExistingHeapFromFileMapping(h_file_mapping: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        EnqueueMakeResident(flags: i32, num_objects: u32, pp_objects: *mut *mut c_void, p_fence_to_signal: *mut c_void, fence_value_to_signal: u64) -> HRESULT,
+        CreateCommandList1(node_mask: u32, r#type: i32, flags: i32, riid: *mut c_void, pp_command_list: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource1(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateHeap1(p_desc: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_heap: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource1(p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo1(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateLifetimeTracker(p_owner: *mut c_void, riid: *mut c_void, ppv_tracker: *mut *mut c_void) -> HRESULT,
+        RemoveDevice(),
+        EnumerateMetaCommands(p_num_meta_commands: *mut u32, p_descs: *mut c_void) -> HRESULT,
+        EnumerateMetaCommandParameters(command_id: *mut c_void, stage: i32, p_total_structure_size_in_bytes: *mut u32, p_parameter_count: *mut u32, p_parameter_descs: *mut c_void) -> HRESULT,
+        CreateMetaCommand(command_id: *mut c_void, node_mask: u32, p_creation_parameters_data: *mut c_void, creation_parameters_data_size_in_bytes: usize, riid: *mut c_void, pp_meta_command: *mut *mut c_void) -> HRESULT,
+        CreateStateObject(p_desc: *mut c_void, riid: *mut c_void, pp_state_object: *mut *mut c_void) -> HRESULT,
+        GetRaytracingAccelerationStructurePrebuildInfo(p_desc: *mut c_void, p_info: *mut c_void),
+        CheckDriverMatchingIdentifier(serialized_data_type: i32, p_identifier_to_check: *mut c_void) -> i32,
+        SetBackgroundProcessingMode(mode: i32, measurements_action: i32, h_event_to_signal_upon_completion: *mut c_void, pb_further_measurements_desired: *mut i32) -> HRESULT,
+        AddToStateObject(p_addition: *mut c_void, p_state_object_to_grow_from: *mut c_void, riid: *mut c_void, pp_new_state_object: *mut *mut c_void) -> HRESULT,
+        CreateProtectedResourceSession1(p_desc: *mut c_void, riid: *mut c_void, pp_session: *mut *mut c_void) -> HRESULT,
+        GetResourceAllocationInfo2(result: *mut c_void, visible_mask: u32, num_resource_descs: u32, p_resource_descs: *mut c_void, p_resource_allocation_info1: *mut c_void),
+        CreateCommittedResource2(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_resource_state: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource1(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_state: i32, p_optimized_clear_value: *mut c_void, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateSamplerFeedbackUnorderedAccessView(p_targeted_resource: *mut c_void, p_feedback_resource: *mut c_void, dest_descriptor: usize),
+        GetCopyableFootprints1(p_resource_desc: *mut c_void, first_subresource: u32, num_subresources: u32, base_offset: u64, p_layouts: *mut c_void, p_num_rows: *mut u32, p_row_size_in_bytes: *mut u64, p_total_bytes: *mut u64),
+        CreateShaderCacheSession(p_desc: *mut c_void, riid: *mut c_void, ppv_session: *mut *mut c_void) -> HRESULT,
+        ShaderCacheControl(kinds: i32, control: i32) -> HRESULT,
+        CreateCommandQueue1(p_desc: *mut c_void, creator_id: *mut c_void, riid: *mut c_void, pp_command_queue: *mut *mut c_void) -> HRESULT,
+        CreateCommittedResource3(p_heap_properties: *mut c_void, heap_flags: i32, p_desc: *mut c_void, initial_layout: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, num_castable_formats: u32, p_castable_formats: *mut i32, riid_resource: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreatePlacedResource2(p_heap: *mut c_void, heap_offset: u64, p_desc: *mut c_void, initial_layout: i32, p_optimized_clear_value: *mut c_void, num_castable_formats: u32, p_castable_formats: *mut i32, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+        CreateReservedResource2(p_desc: *mut c_void, initial_layout: i32, p_optimized_clear_value: *mut c_void, p_protected_session: *mut c_void, num_castable_formats: u32, p_castable_formats: *mut i32, riid: *mut c_void, ppv_resource: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list1 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list2 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+        WriteBufferImmediate(count: u32, p_params: *mut c_void, p_modes: *mut i32),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list3 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+        WriteBufferImmediate(count: u32, p_params: *mut c_void, p_modes: *mut i32),
+        SetProtectedResourceSession(p_protected_resource_session: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list4 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+        WriteBufferImmediate(count: u32, p_params: *mut c_void, p_modes: *mut i32),
+        SetProtectedResourceSession(p_protected_resource_session: *mut c_void),
+        BeginRenderPass(num_render_targets: u32, p_render_targets: *mut c_void, p_depth_stencil: *mut c_void, flags: i32),
+        EndRenderPass(),
+        InitializeMetaCommand(p_meta_command: *mut c_void, p_initialization_parameters_data: *mut c_void, initialization_parameters_data_size_in_bytes: usize),
+        ExecuteMetaCommand(p_meta_command: *mut c_void, p_execution_parameters_data: *mut c_void, execution_parameters_data_size_in_bytes: usize),
+        BuildRaytracingAccelerationStructure(p_desc: *mut c_void, num_postbuild_info_descs: u32, p_postbuild_info_descs: *mut c_void),
+        EmitRaytracingAccelerationStructurePostbuildInfo(p_desc: *mut c_void, num_source_acceleration_structures: u32, p_source_acceleration_structure_data: *mut u64),
+        CopyRaytracingAccelerationStructure(dest_acceleration_structure_data: u64, source_acceleration_structure_data: u64, mode: i32),
+        SetPipelineState1(p_state_object: *mut c_void),
+        DispatchRays(p_desc: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList5Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list5 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList5Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList5Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+        WriteBufferImmediate(count: u32, p_params: *mut c_void, p_modes: *mut i32),
+        SetProtectedResourceSession(p_protected_resource_session: *mut c_void),
+        BeginRenderPass(num_render_targets: u32, p_render_targets: *mut c_void, p_depth_stencil: *mut c_void, flags: i32),
+        EndRenderPass(),
+        InitializeMetaCommand(p_meta_command: *mut c_void, p_initialization_parameters_data: *mut c_void, initialization_parameters_data_size_in_bytes: usize),
+        ExecuteMetaCommand(p_meta_command: *mut c_void, p_execution_parameters_data: *mut c_void, execution_parameters_data_size_in_bytes: usize),
+        BuildRaytracingAccelerationStructure(p_desc: *mut c_void, num_postbuild_info_descs: u32, p_postbuild_info_descs: *mut c_void),
+        EmitRaytracingAccelerationStructurePostbuildInfo(p_desc: *mut c_void, num_source_acceleration_structures: u32, p_source_acceleration_structure_data: *mut u64),
+        CopyRaytracingAccelerationStructure(dest_acceleration_structure_data: u64, source_acceleration_structure_data: u64, mode: i32),
+        SetPipelineState1(p_state_object: *mut c_void),
+        DispatchRays(p_desc: *mut c_void),
+        RSSetShadingRate(base_shading_rate: i32, combiners: *mut i32),
+        RSSetShadingRateImage(shading_rate_image: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList6Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list6 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList6Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList6Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+        WriteBufferImmediate(count: u32, p_params: *mut c_void, p_modes: *mut i32),
+        SetProtectedResourceSession(p_protected_resource_session: *mut c_void),
+        BeginRenderPass(num_render_targets: u32, p_render_targets: *mut c_void, p_depth_stencil: *mut c_void, flags: i32),
+        EndRenderPass(),
+        InitializeMetaCommand(p_meta_command: *mut c_void, p_initialization_parameters_data: *mut c_void, initialization_parameters_data_size_in_bytes: usize),
+        ExecuteMetaCommand(p_meta_command: *mut c_void, p_execution_parameters_data: *mut c_void, execution_parameters_data_size_in_bytes: usize),
+        BuildRaytracingAccelerationStructure(p_desc: *mut c_void, num_postbuild_info_descs: u32, p_postbuild_info_descs: *mut c_void),
+        EmitRaytracingAccelerationStructurePostbuildInfo(p_desc: *mut c_void, num_source_acceleration_structures: u32, p_source_acceleration_structure_data: *mut u64),
+        CopyRaytracingAccelerationStructure(dest_acceleration_structure_data: u64, source_acceleration_structure_data: u64, mode: i32),
+        SetPipelineState1(p_state_object: *mut c_void),
+        DispatchRays(p_desc: *mut c_void),
+        RSSetShadingRate(base_shading_rate: i32, combiners: *mut i32),
+        RSSetShadingRateImage(shading_rate_image: *mut c_void),
+        DispatchMesh(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+    }
+}
+
+/// Typed signatures of [`DirectX12CommandList7Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod command_list7 {
+    use std::ffi::c_void;
+
+    use super::DirectX12CommandList7Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12CommandList7Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetType() -> i32,
+        Close() -> HRESULT,
+        Reset(p_allocator: *mut c_void, p_initial_state: *mut c_void) -> HRESULT,
+        ClearState(p_pipeline_state: *mut c_void),
+        DrawInstanced(vertex_count_per_instance: u32, instance_count: u32, start_vertex_location: u32, start_instance_location: u32),
+        DrawIndexedInstanced(index_count_per_instance: u32, instance_count: u32, start_index_location: u32, base_vertex_location: i32, start_instance_location: u32),
+        Dispatch(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        CopyBufferRegion(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, num_bytes: u64),
+        CopyTextureRegion(p_dst: *mut c_void, dst_x: u32, dst_y: u32, dst_z: u32, p_src: *mut c_void, p_src_box: *mut c_void),
+        CopyResource(p_dst_resource: *mut c_void, p_src_resource: *mut c_void),
+        CopyTiles(p_tiled_resource: *mut c_void, p_tile_region_start_coordinate: *mut c_void, p_tile_region_size: *mut c_void, p_buffer: *mut c_void, buffer_start_offset_in_bytes: u64, flags: i32),
+        ResolveSubresource(p_dst_resource: *mut c_void, dst_subresource: u32, p_src_resource: *mut c_void, src_subresource: u32, format: i32),
+        IASetPrimitiveTopology(primitive_topology: i32),
+        RSSetViewports(num_viewports: u32, p_viewports: *mut c_void),
+        RSSetScissorRects(num_rects: u32, p_rects: *mut c_void),
+        OMSetBlendFactor(blend_factor: *mut f32),
+        OMSetStencilRef(stencil_ref: u32),
+        SetPipelineState(p_pipeline_state: *mut c_void),
+        ResourceBarrier(num_barriers: u32, p_barriers: *mut c_void),
+        ExecuteBundle(p_command_list: *mut c_void),
+        SetDescriptorHeaps(num_descriptor_heaps: u32, pp_descriptor_heaps: *mut *mut c_void),
+        SetComputeRootSignature(p_root_signature: *mut c_void),
+        SetGraphicsRootSignature(p_root_signature: *mut c_void),
+        SetComputeRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetGraphicsRootDescriptorTable(root_parameter_index: u32, base_descriptor: u64),
+        SetComputeRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstant(root_parameter_index: u32, src_data: u32, dest_offset_in32_bit_values: u32),
+        SetComputeRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetGraphicsRoot32BitConstants(root_parameter_index: u32, num32_bit_values_to_set: u32, p_src_data: *mut c_void, dest_offset_in32_bit_values: u32),
+        SetComputeRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootConstantBufferView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootShaderResourceView(root_parameter_index: u32, buffer_location: u64),
+        SetComputeRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        SetGraphicsRootUnorderedAccessView(root_parameter_index: u32, buffer_location: u64),
+        IASetIndexBuffer(p_view: *mut c_void),
+        IASetVertexBuffers(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        SOSetTargets(start_slot: u32, num_views: u32, p_views: *mut c_void),
+        OMSetRenderTargets(num_render_target_descriptors: u32, p_render_target_descriptors: *mut c_void, r_ts_single_handle_to_descriptor_range: i32, p_depth_stencil_descriptor: *mut c_void),
+        ClearDepthStencilView(depth_stencil_view: usize, clear_flags: i32, depth: f32, stencil: u8, num_rects: u32, p_rects: *mut c_void),
+        ClearRenderTargetView(render_target_view: usize, color_rgba: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewUint(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut u32, num_rects: u32, p_rects: *mut c_void),
+        ClearUnorderedAccessViewFloat(view_gpu_handle_in_current_heap: u64, view_cpu_handle: usize, p_resource: *mut c_void, values: *mut f32, num_rects: u32, p_rects: *mut c_void),
+        DiscardResource(p_resource: *mut c_void, p_region: *mut c_void),
+        BeginQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        EndQuery(p_query_heap: *mut c_void, r#type: i32, index: u32),
+        ResolveQueryData(p_query_heap: *mut c_void, r#type: i32, start_index: u32, num_queries: u32, p_destination_buffer: *mut c_void, aligned_destination_buffer_offset: u64),
+        SetPredication(p_buffer: *mut c_void, aligned_buffer_offset: u64, operation: i32),
+        SetMarker(metadata: u32, p_data: *mut c_void, size: u32),
+        BeginEvent(metadata: u32, p_data: *mut c_void, size: u32),
+        EndEvent(),
+        ExecuteIndirect(p_command_signature: *mut c_void, max_command_count: u32, p_argument_buffer: *mut c_void, argument_buffer_offset: u64, p_count_buffer: *mut c_void, count_buffer_offset: u64),
+        AtomicCopyBufferUINT(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        AtomicCopyBufferUINT64(p_dst_buffer: *mut c_void, dst_offset: u64, p_src_buffer: *mut c_void, src_offset: u64, dependencies: u32, pp_dependent_resources: *mut *mut c_void, p_dependent_subresource_ranges: *mut c_void),
+        OMSetDepthBounds(min: f32, max: f32),
+        SetSamplePositions(num_samples_per_pixel: u32, num_pixels: u32, p_sample_positions: *mut c_void),
+        ResolveSubresourceRegion(p_dst_resource: *mut c_void, dst_subresource: u32, dst_x: u32, dst_y: u32, p_src_resource: *mut c_void, src_subresource: u32, p_src_rect: *mut c_void, format: i32, resolve_mode: i32),
+        SetViewInstanceMask(mask: u32),
+        WriteBufferImmediate(count: u32, p_params: *mut c_void, p_modes: *mut i32),
+        SetProtectedResourceSession(p_protected_resource_session: *mut c_void),
+        BeginRenderPass(num_render_targets: u32, p_render_targets: *mut c_void, p_depth_stencil: *mut c_void, flags: i32),
+        EndRenderPass(),
+        InitializeMetaCommand(p_meta_command: *mut c_void, p_initialization_parameters_data: *mut c_void, initialization_parameters_data_size_in_bytes: usize),
+        ExecuteMetaCommand(p_meta_command: *mut c_void, p_execution_parameters_data: *mut c_void, execution_parameters_data_size_in_bytes: usize),
+        BuildRaytracingAccelerationStructure(p_desc: *mut c_void, num_postbuild_info_descs: u32, p_postbuild_info_descs: *mut c_void),
+        EmitRaytracingAccelerationStructurePostbuildInfo(p_desc: *mut c_void, num_source_acceleration_structures: u32, p_source_acceleration_structure_data: *mut u64),
+        CopyRaytracingAccelerationStructure(dest_acceleration_structure_data: u64, source_acceleration_structure_data: u64, mode: i32),
+        SetPipelineState1(p_state_object: *mut c_void),
+        DispatchRays(p_desc: *mut c_void),
+        RSSetShadingRate(base_shading_rate: i32, combiners: *mut i32),
+        RSSetShadingRateImage(shading_rate_image: *mut c_void),
+        DispatchMesh(thread_group_count_x: u32, thread_group_count_y: u32, thread_group_count_z: u32),
+        Barrier(num_barrier_groups: u32, p_barrier_groups: *mut c_void),
+    }
+}
+
+/// Typed signatures of [`DirectX12FenceMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod fence {
+    use std::ffi::c_void;
+
+    use super::DirectX12FenceMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12FenceMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetCompletedValue() -> u64,
+        SetEventOnCompletion(value: u64, h_event: *mut c_void) -> HRESULT,
+        Signal(value: u64) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12ResourceMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod resource {
+    use std::ffi::c_void;
+
+    use super::DirectX12ResourceMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12ResourceMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        Map(subresource: u32, p_read_range: *mut c_void, pp_data: *mut *mut c_void) -> HRESULT,
+        Unmap(subresource: u32, p_written_range: *mut c_void),
+        GetDesc(result: *mut c_void),
+        GetGPUVirtualAddress() -> u64,
+        WriteToSubresource(dst_subresource: u32, p_dst_box: *mut c_void, p_src_data: *mut c_void, src_row_pitch: u32, src_depth_pitch: u32) -> HRESULT,
+        ReadFromSubresource(p_dst_data: *mut c_void, dst_row_pitch: u32, dst_depth_pitch: u32, src_subresource: u32, p_src_box: *mut c_void) -> HRESULT,
+        GetHeapProperties(p_heap_properties: *mut c_void, p_heap_flags: *mut i32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX12DescriptorHeapMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod descriptor_heap {
+    use std::ffi::c_void;
+
+    use super::DirectX12DescriptorHeapMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX12DescriptorHeapMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        GetPrivateData(guid: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateData(guid: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(guid: *mut c_void, p_data: *mut c_void) -> HRESULT,
+        SetName(name: *mut u16) -> HRESULT,
+        GetDevice(riid: *mut c_void, ppv_device: *mut *mut c_void) -> HRESULT,
+        GetDesc(result: *mut c_void),
+        GetCPUDescriptorHandleForHeapStart(result: *mut c_void),
+        GetGPUDescriptorHandleForHeapStart(result: *mut c_void),
+    }
+}
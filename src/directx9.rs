@@ -1,168 +1,212 @@
-use strum::{EnumCount, IntoEnumIterator};
-use strum_macros::{EnumCount, EnumIter};
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
 use windows::Win32::{
-    Foundation::{FALSE, TRUE},
+    Foundation::{FALSE, HWND, LUID, TRUE},
     Graphics::Direct3D9::{
-        Direct3DCreate9Ex, D3DADAPTER_DEFAULT, D3DCREATE_DISABLE_DRIVER_MANAGEMENT,
-        D3DCREATE_SOFTWARE_VERTEXPROCESSING, D3DDEVTYPE_NULLREF, D3DFMT_UNKNOWN,
-        D3DMULTISAMPLE_NONE, D3DPRESENT_PARAMETERS, D3DSWAPEFFECT_DISCARD, D3D_SDK_VERSION,
+        Direct3DCreate9, Direct3DCreate9Ex, IDirect3D9Ex, IDirect3DDevice9Ex, D3DADAPTER_DEFAULT,
+        D3DADAPTER_IDENTIFIER9, D3DCREATE_DISABLE_DRIVER_MANAGEMENT,
+        D3DCREATE_SOFTWARE_VERTEXPROCESSING, D3DDEVTYPE, D3DDEVTYPE_HAL, D3DDEVTYPE_NULLREF,
+        D3DDEVTYPE_REF, D3DFMT_UNKNOWN, D3DMULTISAMPLE_NONE, D3DPRESENT_PARAMETERS,
+        D3DSWAPEFFECT_DISCARD, D3D_SDK_VERSION,
     },
 };
 
-use crate::{get_process_window, ShroudError, ShroudResult};
-
-#[derive(Debug, EnumIter, EnumCount)]
-pub enum DirectX9DeviceMethods {
-    QueryInterface,
-    AddRef,
-    Release,
-    TestCooperativeLevel,
-    GetAvailableTextureMem,
-    EvictManagedResources,
-    GetDirect3D,
-    GetDeviceCaps,
-    GetDisplayMode,
-    GetCreationParameters,
-    SetCursorProperties,
-    SetCursorPosition,
-    ShowCursor,
-    CreateAdditionalSwapChain,
-    GetSwapChain,
-    GetNumberOfSwapChains,
-    Reset,
-    Present,
-    GetBackBuffer,
-    GetRasterStatus,
-    SetDialogBoxMode,
-    SetGammaRamp,
-    GetGammaRamp,
-    CreateTexture,
-    CreateVolumeTexture,
-    CreateCubeTexture,
-    CreateVertexBuffer,
-    CreateIndexBuffer,
-    CreateRenderTarget,
-    CreateDepthStencilSurface,
-    UpdateSurface,
-    UpdateTexture,
-    GetRenderTargetData,
-    GetFrontBufferData,
-    StretchRect,
-    ColorFill,
-    CreateOffscreenPlainSurface,
-    SetRenderTarget,
-    GetRenderTarget,
-    SetDepthStencilSurface,
-    GetDepthStencilSurface,
-    BeginScene,
-    EndScene,
-    Clear,
-    SetTransform,
-    GetTransform,
-    MultiplyTransform,
-    SetViewport,
-    GetViewport,
-    SetMaterial,
-    GetMaterial,
-    SetLight,
-    GetLight,
-    LightEnable,
-    GetLightEnable,
-    SetClipPlane,
-    GetClipPlane,
-    SetRenderState,
-    GetRenderState,
-    CreateStateBlock,
-    BeginStateBlock,
-    EndStateBlock,
-    SetClipStatus,
-    GetClipStatus,
-    GetTexture,
-    SetTexture,
-    GetTextureStageState,
-    SetTextureStageState,
-    GetSamplerState,
-    SetSamplerState,
-    ValidateDevice,
-    SetPaletteEntries,
-    GetPaletteEntries,
-    SetCurrentTexturePalette,
-    GetCurrentTexturePalette,
-    SetScissorRect,
-    GetScissorRect,
-    SetSoftwareVertexProcessing,
-    GetSoftwareVertexProcessing,
-    SetNPatchMode,
-    GetNPatchMode,
-    DrawPrimitive,
-    DrawIndexedPrimitive,
-    DrawPrimitiveUP,
-    DrawIndexedPrimitiveUP,
-    ProcessVertices,
-    CreateVertexDeclaration,
-    SetVertexDeclaration,
-    GetVertexDeclaration,
-    SetFVF,
-    GetFVF,
-    CreateVertexShader,
-    SetVertexShader,
-    GetVertexShader,
-    SetVertexShaderConstantF,
-    GetVertexShaderConstantF,
-    SetVertexShaderConstantI,
-    GetVertexShaderConstantI,
-    SetVertexShaderConstantB,
-    GetVertexShaderConstantB,
-    SetStreamSource,
-    GetStreamSource,
-    SetStreamSourceFreq,
-    GetStreamSourceFreq,
-    SetIndices,
-    GetIndices,
-    CreatePixelShader,
-    SetPixelShader,
-    GetPixelShader,
-    SetPixelShaderConstantF,
-    GetPixelShaderConstantF,
-    SetPixelShaderConstantI,
-    GetPixelShaderConstantI,
-    SetPixelShaderConstantB,
-    GetPixelShaderConstantB,
-    DrawRectPatch,
-    DrawTriPatch,
-    DeletePatch,
-    CreateQuery,
-}
+#[cfg(windows)]
+use crate::{
+    discovery::{self, AdapterInfo, Attempt, DriverType, Luid},
+    vtable,
+    window::{ProvidedWindow, WindowProvider},
+    DiscoveryOptions, WindowSelector,
+};
+use crate::{
+    snapshot::snapshot_serde, MethodTable, RenderEngine, ShroudError, ShroudResult, Snapshot,
+};
+
+mod methods;
+pub use methods::*;
 
 pub struct DirectX9Methods {
-    device_vmt: Vec<*const usize>,
+    device_vmt: MethodTable<DirectX9DeviceMethods>,
+    device_ex_vmt: Option<MethodTable<DirectX9DeviceExMethods>>,
+    direct3d_vmt: MethodTable<DirectX9Direct3DMethods>,
+    direct3d_ex_vmt: MethodTable<DirectX9Direct3DExMethods>,
+    swapchain_vmt: MethodTable<DirectX9SwapchainMethods>,
 }
 
 impl DirectX9Methods {
-    pub fn device_vmt(&self) -> &Vec<*const usize> {
+    pub fn device_vmt(&self) -> &MethodTable<DirectX9DeviceMethods> {
         &self.device_vmt
     }
+
+    /// Table of an `IDirect3DDevice9Ex`, with `PresentEx`, `ResetEx`, `WaitForVBlank`, ...
+    /// `None` when no device type of the attempt could be created as one, e.g.
+    /// on GPU-less machines where only `NULLREF` devices exist.
+    pub fn device_ex_vmt(&self) -> Option<&MethodTable<DirectX9DeviceExMethods>> {
+        self.device_ex_vmt.as_ref()
+    }
+
+    /// Table of the `IDirect3D9` returned by `Direct3DCreate9`.
+    pub fn direct3d_vmt(&self) -> &MethodTable<DirectX9Direct3DMethods> {
+        &self.direct3d_vmt
+    }
+
+    /// Table of the `IDirect3D9Ex` returned by `Direct3DCreate9Ex`.
+    pub fn direct3d_ex_vmt(&self) -> &MethodTable<DirectX9Direct3DExMethods> {
+        &self.direct3d_ex_vmt
+    }
+
+    /// Table of the device's implicit swap chain, which additional swap chains share.
+    pub fn swapchain_vmt(&self) -> &MethodTable<DirectX9SwapchainMethods> {
+        &self.swapchain_vmt
+    }
 }
 
+impl DirectX9Methods {
+    /// Host-independent record of every table, see [`Snapshot`].
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new(RenderEngine::DirectX9);
+        snapshot.push(&self.device_vmt);
+        if let Some(vmt) = &self.device_ex_vmt {
+            snapshot.push(vmt);
+        }
+        snapshot.push(&self.direct3d_vmt);
+        snapshot.push(&self.direct3d_ex_vmt);
+        snapshot.push(&self.swapchain_vmt);
+        snapshot
+    }
+}
+
+impl TryFrom<&Snapshot> for DirectX9Methods {
+    type Error = ShroudError;
+
+    fn try_from(snapshot: &Snapshot) -> ShroudResult<Self> {
+        snapshot.expect(RenderEngine::DirectX9)?;
+        Ok(Self {
+            device_vmt: snapshot.required_table()?,
+            device_ex_vmt: snapshot.table()?,
+            direct3d_vmt: snapshot.required_table()?,
+            direct3d_ex_vmt: snapshot.required_table()?,
+            swapchain_vmt: snapshot.required_table()?,
+        })
+    }
+}
+
+snapshot_serde!(DirectX9Methods);
+
 impl std::fmt::Debug for DirectX9Methods {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "DirectX9 Method Table")?;
         writeln!(f, "Devices Virtual Method Table")?;
-        for (i, method) in DirectX9DeviceMethods::iter().enumerate() {
-            writeln!(f, "\t[{}] {:?} {:#?}", i, method, self.device_vmt[i])?;
+        write!(f, "{:?}", self.device_vmt)?;
+        writeln!(f)?;
+
+        if let Some(vmt) = &self.device_ex_vmt {
+            writeln!(f, "DeviceEx Virtual Method Table")?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
         }
+
+        writeln!(f, "Direct3D Virtual Method Table")?;
+        write!(f, "{:?}", self.direct3d_vmt)?;
+        writeln!(f)?;
+
+        writeln!(f, "Direct3DEx Virtual Method Table")?;
+        write!(f, "{:?}", self.direct3d_ex_vmt)?;
+        writeln!(f)?;
+
+        writeln!(f, "Swapchain Virtual Method Table")?;
+        write!(f, "{:?}", self.swapchain_vmt)?;
         writeln!(f)?;
         Ok(())
     }
 }
 
+/// Discovers every table, creating the device for the window
+/// [`WindowSelector::default`] picks.
+#[cfg(windows)]
 pub fn methods() -> ShroudResult<DirectX9Methods> {
-    let window = get_process_window().ok_or(ShroudError::Window)?;
-    // let swapchain_desc = default_swapchain_descriptor(window);
-    // let feature_level: *mut D3D_FEATURE_LEVEL = std::ptr::null_mut();
+    methods_for(&WindowSelector::default())
+}
+
+/// [`methods`], creating the device for the window `window` provides, such as one
+/// [`WindowSelector`] picks or a [`crate::TemporaryWindow`].
+#[cfg(windows)]
+pub fn methods_for(window: &impl WindowProvider) -> ShroudResult<DirectX9Methods> {
+    methods_with(window, &DiscoveryOptions::default())
+}
+
+/// [`methods_for`], creating the device with the device type and adapter
+/// `options` ask for, and falling back along its plan. Direct3D 9 has no
+/// feature levels and no WARP.
+#[cfg(windows)]
+pub fn methods_with(
+    window: &impl WindowProvider,
+    options: &DiscoveryOptions,
+) -> ShroudResult<DirectX9Methods> {
+    let window = window.acquire()?;
+    let hwnd = HWND(window.handle() as *mut _);
+
+    let direct3d_9 = unsafe { Direct3DCreate9Ex(D3D_SDK_VERSION) }
+        .map_err(|e| ShroudError::DirectX9Create(e.code()))?;
+    let attempts = options.plan(RenderEngine::DirectX9, &adapters_of(&direct3d_9));
+    discovery::retry(RenderEngine::DirectX9, attempts, |attempt| {
+        discover(&direct3d_9, hwnd, attempt)
+    })
+}
 
+/// Adapters `IDirect3D9` enumerates, which
+/// [`crate::discovery::AdapterSelector`]s pick from for DirectX9.
+#[cfg(windows)]
+pub fn adapters() -> ShroudResult<Vec<AdapterInfo>> {
     let direct3d_9 = unsafe { Direct3DCreate9Ex(D3D_SDK_VERSION) }
         .map_err(|e| ShroudError::DirectX9Create(e.code()))?;
+    Ok(adapters_of(&direct3d_9))
+}
+
+#[cfg(windows)]
+fn adapters_of(direct3d_9: &IDirect3D9Ex) -> Vec<AdapterInfo> {
+    (0..unsafe { direct3d_9.GetAdapterCount() })
+        .filter_map(|index| {
+            let mut identifier = D3DADAPTER_IDENTIFIER9::default();
+            unsafe { direct3d_9.GetAdapterIdentifier(index, 0, &mut identifier) }.ok()?;
+            let mut luid = LUID::default();
+            unsafe { direct3d_9.GetAdapterLUID(index, &mut luid) }.ok()?;
+
+            let description: Vec<u8> = identifier
+                .Description
+                .iter()
+                .take_while(|&&c| c != 0)
+                .map(|&c| c as u8)
+                .collect();
+            Some(AdapterInfo {
+                index,
+                luid: Luid {
+                    low: luid.LowPart,
+                    high: luid.HighPart,
+                },
+                vendor_id: identifier.VendorId,
+                device_id: identifier.DeviceId,
+                description: String::from_utf8_lossy(&description).into_owned(),
+                software: false,
+            })
+        })
+        .collect()
+}
+
+#[cfg(windows)]
+fn discover(
+    direct3d_9: &IDirect3D9Ex,
+    hwnd: HWND,
+    attempt: &Attempt,
+) -> ShroudResult<DirectX9Methods> {
+    let adapter = attempt.adapter.unwrap_or(D3DADAPTER_DEFAULT);
+    let device_type: D3DDEVTYPE = match attempt.driver {
+        DriverType::Hardware => D3DDEVTYPE_HAL,
+        DriverType::Reference => D3DDEVTYPE_REF,
+        DriverType::Null => D3DDEVTYPE_NULLREF,
+        DriverType::Warp => return Err(ShroudError::NoDiscoveryAttempt(RenderEngine::DirectX9)),
+    };
 
     let mut present_params = D3DPRESENT_PARAMETERS {
         BackBufferWidth: 0,
@@ -172,7 +216,7 @@ pub fn methods() -> ShroudResult<DirectX9Methods> {
         MultiSampleType: D3DMULTISAMPLE_NONE,
         MultiSampleQuality: 0,
         SwapEffect: D3DSWAPEFFECT_DISCARD,
-        hDeviceWindow: window,
+        hDeviceWindow: hwnd,
         Windowed: TRUE,
         EnableAutoDepthStencil: FALSE,
         AutoDepthStencilFormat: D3DFMT_UNKNOWN,
@@ -185,9 +229,9 @@ pub fn methods() -> ShroudResult<DirectX9Methods> {
     unsafe {
         direct3d_9
             .CreateDevice(
-                D3DADAPTER_DEFAULT,
-                D3DDEVTYPE_NULLREF,
-                window,
+                adapter,
+                device_type,
+                hwnd,
                 (D3DCREATE_SOFTWARE_VERTEXPROCESSING | D3DCREATE_DISABLE_DRIVER_MANAGEMENT) as u32,
                 &mut present_params,
                 &mut device,
@@ -195,14 +239,57 @@ pub fn methods() -> ShroudResult<DirectX9Methods> {
             .map_err(|e| ShroudError::DirectX9CreateDevice(e.code()))?
     };
 
-    let device = device.ok_or(ShroudError::Expectation("Dx11 Context created"))?;
-    let device_vmt = unsafe {
-        std::slice::from_raw_parts(
-            std::mem::transmute::<_, *const *const *const usize>(device).read(),
-            DirectX9DeviceMethods::COUNT,
-        )
-        .to_vec()
+    let device = device.ok_or(ShroudError::Expectation("Dx9 Device created"))?;
+    let device_vmt = unsafe { vtable::read::<DirectX9DeviceMethods>(device.as_raw())? };
+
+    // Only devices made by CreateDeviceEx answer to IDirect3DDevice9Ex. Some
+    // runtimes only create those for HAL, which GPU-less machines lack, so the
+    // Ex table is left out rather than failing discovery.
+    let device_ex = device.cast::<IDirect3DDevice9Ex>().ok().or_else(|| {
+        let mut device_types = vec![device_type];
+        if device_type != D3DDEVTYPE_HAL {
+            device_types.push(D3DDEVTYPE_HAL);
+        }
+        device_types.into_iter().find_map(|device_type| {
+            let mut device_ex = None;
+            unsafe {
+                direct3d_9.CreateDeviceEx(
+                    adapter,
+                    device_type,
+                    hwnd,
+                    (D3DCREATE_SOFTWARE_VERTEXPROCESSING | D3DCREATE_DISABLE_DRIVER_MANAGEMENT)
+                        as u32,
+                    &mut present_params,
+                    std::ptr::null_mut(),
+                    &mut device_ex,
+                )
+            }
+            .ok()?;
+            device_ex
+        })
+    });
+    let device_ex_vmt = match device_ex {
+        Some(device_ex) => {
+            Some(unsafe { vtable::read::<DirectX9DeviceExMethods>(device_ex.as_raw())? })
+        }
+        None => None,
     };
 
-    Ok(DirectX9Methods { device_vmt })
+    let swapchain = unsafe { device.GetSwapChain(0) }
+        .map_err(|e| ShroudError::DirectX9GetSwapchain(e.code()))?;
+    let swapchain_vmt = unsafe { vtable::read::<DirectX9SwapchainMethods>(swapchain.as_raw())? };
+
+    let direct3d = unsafe { Direct3DCreate9(D3D_SDK_VERSION) }
+        .ok_or(ShroudError::Expectation("Dx9 Direct3D created"))?;
+    let direct3d_vmt = unsafe { vtable::read::<DirectX9Direct3DMethods>(direct3d.as_raw())? };
+    let direct3d_ex_vmt =
+        unsafe { vtable::read::<DirectX9Direct3DExMethods>(direct3d_9.as_raw())? };
+
+    Ok(DirectX9Methods {
+        device_vmt,
+        device_ex_vmt,
+        direct3d_vmt,
+        direct3d_ex_vmt,
+        swapchain_vmt,
+    })
 }
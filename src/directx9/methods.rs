@@ -0,0 +1,1053 @@
+// Generated by `cargo xtask codegen` from xtask/winmd/Windows.Win32.winmd, do not edit.
+
+use strum_macros::{EnumCount, EnumIter};
+
+#[cfg(windows)]
+use crate::vtable::assert_slot;
+use crate::InterfaceMethods;
+
+/// `IDirect3DDevice9` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX9DeviceMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    TestCooperativeLevel,
+    GetAvailableTextureMem,
+    EvictManagedResources,
+    GetDirect3D,
+    GetDeviceCaps,
+    GetDisplayMode,
+    GetCreationParameters,
+    SetCursorProperties,
+    SetCursorPosition,
+    ShowCursor,
+    CreateAdditionalSwapChain,
+    GetSwapChain,
+    GetNumberOfSwapChains,
+    Reset,
+    Present,
+    GetBackBuffer,
+    GetRasterStatus,
+    SetDialogBoxMode,
+    SetGammaRamp,
+    GetGammaRamp,
+    CreateTexture,
+    CreateVolumeTexture,
+    CreateCubeTexture,
+    CreateVertexBuffer,
+    CreateIndexBuffer,
+    CreateRenderTarget,
+    CreateDepthStencilSurface,
+    UpdateSurface,
+    UpdateTexture,
+    GetRenderTargetData,
+    GetFrontBufferData,
+    StretchRect,
+    ColorFill,
+    CreateOffscreenPlainSurface,
+    SetRenderTarget,
+    GetRenderTarget,
+    SetDepthStencilSurface,
+    GetDepthStencilSurface,
+    BeginScene,
+    EndScene,
+    Clear,
+    SetTransform,
+    GetTransform,
+    MultiplyTransform,
+    SetViewport,
+    GetViewport,
+    SetMaterial,
+    GetMaterial,
+    SetLight,
+    GetLight,
+    LightEnable,
+    GetLightEnable,
+    SetClipPlane,
+    GetClipPlane,
+    SetRenderState,
+    GetRenderState,
+    CreateStateBlock,
+    BeginStateBlock,
+    EndStateBlock,
+    SetClipStatus,
+    GetClipStatus,
+    GetTexture,
+    SetTexture,
+    GetTextureStageState,
+    SetTextureStageState,
+    GetSamplerState,
+    SetSamplerState,
+    ValidateDevice,
+    SetPaletteEntries,
+    GetPaletteEntries,
+    SetCurrentTexturePalette,
+    GetCurrentTexturePalette,
+    SetScissorRect,
+    GetScissorRect,
+    SetSoftwareVertexProcessing,
+    GetSoftwareVertexProcessing,
+    SetNPatchMode,
+    GetNPatchMode,
+    DrawPrimitive,
+    DrawIndexedPrimitive,
+    DrawPrimitiveUP,
+    DrawIndexedPrimitiveUP,
+    ProcessVertices,
+    CreateVertexDeclaration,
+    SetVertexDeclaration,
+    GetVertexDeclaration,
+    SetFVF,
+    GetFVF,
+    CreateVertexShader,
+    SetVertexShader,
+    GetVertexShader,
+    SetVertexShaderConstantF,
+    GetVertexShaderConstantF,
+    SetVertexShaderConstantI,
+    GetVertexShaderConstantI,
+    SetVertexShaderConstantB,
+    GetVertexShaderConstantB,
+    SetStreamSource,
+    GetStreamSource,
+    SetStreamSourceFreq,
+    GetStreamSourceFreq,
+    SetIndices,
+    GetIndices,
+    CreatePixelShader,
+    SetPixelShader,
+    GetPixelShader,
+    SetPixelShaderConstantF,
+    GetPixelShaderConstantF,
+    SetPixelShaderConstantI,
+    GetPixelShaderConstantI,
+    SetPixelShaderConstantB,
+    GetPixelShaderConstantB,
+    DrawRectPatch,
+    DrawTriPatch,
+    DeletePatch,
+    CreateQuery,
+}
+
+impl InterfaceMethods for DirectX9DeviceMethods {
+    const INTERFACE: &'static str = "IDirect3DDevice9";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D9::IDirect3DDevice9_Vtbl as Vtbl;
+    use DirectX9DeviceMethods as M;
+
+    assert_slot!(Vtbl, base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.Release => M::Release);
+    assert_slot!(Vtbl, TestCooperativeLevel => M::TestCooperativeLevel);
+    assert_slot!(Vtbl, GetAvailableTextureMem => M::GetAvailableTextureMem);
+    assert_slot!(Vtbl, EvictManagedResources => M::EvictManagedResources);
+    assert_slot!(Vtbl, GetDirect3D => M::GetDirect3D);
+    assert_slot!(Vtbl, GetDeviceCaps => M::GetDeviceCaps);
+    assert_slot!(Vtbl, GetDisplayMode => M::GetDisplayMode);
+    assert_slot!(Vtbl, GetCreationParameters => M::GetCreationParameters);
+    assert_slot!(Vtbl, SetCursorProperties => M::SetCursorProperties);
+    assert_slot!(Vtbl, SetCursorPosition => M::SetCursorPosition);
+    assert_slot!(Vtbl, ShowCursor => M::ShowCursor);
+    assert_slot!(Vtbl, CreateAdditionalSwapChain => M::CreateAdditionalSwapChain);
+    assert_slot!(Vtbl, GetSwapChain => M::GetSwapChain);
+    assert_slot!(Vtbl, GetNumberOfSwapChains => M::GetNumberOfSwapChains);
+    assert_slot!(Vtbl, Reset => M::Reset);
+    assert_slot!(Vtbl, Present => M::Present);
+    assert_slot!(Vtbl, GetBackBuffer => M::GetBackBuffer);
+    assert_slot!(Vtbl, GetRasterStatus => M::GetRasterStatus);
+    assert_slot!(Vtbl, SetDialogBoxMode => M::SetDialogBoxMode);
+    assert_slot!(Vtbl, SetGammaRamp => M::SetGammaRamp);
+    assert_slot!(Vtbl, GetGammaRamp => M::GetGammaRamp);
+    assert_slot!(Vtbl, CreateTexture => M::CreateTexture);
+    assert_slot!(Vtbl, CreateVolumeTexture => M::CreateVolumeTexture);
+    assert_slot!(Vtbl, CreateCubeTexture => M::CreateCubeTexture);
+    assert_slot!(Vtbl, CreateVertexBuffer => M::CreateVertexBuffer);
+    assert_slot!(Vtbl, CreateIndexBuffer => M::CreateIndexBuffer);
+    assert_slot!(Vtbl, CreateRenderTarget => M::CreateRenderTarget);
+    assert_slot!(Vtbl, CreateDepthStencilSurface => M::CreateDepthStencilSurface);
+    assert_slot!(Vtbl, UpdateSurface => M::UpdateSurface);
+    assert_slot!(Vtbl, UpdateTexture => M::UpdateTexture);
+    assert_slot!(Vtbl, GetRenderTargetData => M::GetRenderTargetData);
+    assert_slot!(Vtbl, GetFrontBufferData => M::GetFrontBufferData);
+    assert_slot!(Vtbl, StretchRect => M::StretchRect);
+    assert_slot!(Vtbl, ColorFill => M::ColorFill);
+    assert_slot!(Vtbl, CreateOffscreenPlainSurface => M::CreateOffscreenPlainSurface);
+    assert_slot!(Vtbl, SetRenderTarget => M::SetRenderTarget);
+    assert_slot!(Vtbl, GetRenderTarget => M::GetRenderTarget);
+    assert_slot!(Vtbl, SetDepthStencilSurface => M::SetDepthStencilSurface);
+    assert_slot!(Vtbl, GetDepthStencilSurface => M::GetDepthStencilSurface);
+    assert_slot!(Vtbl, BeginScene => M::BeginScene);
+    assert_slot!(Vtbl, EndScene => M::EndScene);
+    assert_slot!(Vtbl, Clear => M::Clear);
+    assert_slot!(Vtbl, SetTransform => M::SetTransform);
+    assert_slot!(Vtbl, GetTransform => M::GetTransform);
+    assert_slot!(Vtbl, MultiplyTransform => M::MultiplyTransform);
+    assert_slot!(Vtbl, SetViewport => M::SetViewport);
+    assert_slot!(Vtbl, GetViewport => M::GetViewport);
+    assert_slot!(Vtbl, SetMaterial => M::SetMaterial);
+    assert_slot!(Vtbl, GetMaterial => M::GetMaterial);
+    assert_slot!(Vtbl, SetLight => M::SetLight);
+    assert_slot!(Vtbl, GetLight => M::GetLight);
+    assert_slot!(Vtbl, LightEnable => M::LightEnable);
+    assert_slot!(Vtbl, GetLightEnable => M::GetLightEnable);
+    assert_slot!(Vtbl, SetClipPlane => M::SetClipPlane);
+    assert_slot!(Vtbl, GetClipPlane => M::GetClipPlane);
+    assert_slot!(Vtbl, SetRenderState => M::SetRenderState);
+    assert_slot!(Vtbl, GetRenderState => M::GetRenderState);
+    assert_slot!(Vtbl, CreateStateBlock => M::CreateStateBlock);
+    assert_slot!(Vtbl, BeginStateBlock => M::BeginStateBlock);
+    assert_slot!(Vtbl, EndStateBlock => M::EndStateBlock);
+    assert_slot!(Vtbl, SetClipStatus => M::SetClipStatus);
+    assert_slot!(Vtbl, GetClipStatus => M::GetClipStatus);
+    assert_slot!(Vtbl, GetTexture => M::GetTexture);
+    assert_slot!(Vtbl, SetTexture => M::SetTexture);
+    assert_slot!(Vtbl, GetTextureStageState => M::GetTextureStageState);
+    assert_slot!(Vtbl, SetTextureStageState => M::SetTextureStageState);
+    assert_slot!(Vtbl, GetSamplerState => M::GetSamplerState);
+    assert_slot!(Vtbl, SetSamplerState => M::SetSamplerState);
+    assert_slot!(Vtbl, ValidateDevice => M::ValidateDevice);
+    assert_slot!(Vtbl, SetPaletteEntries => M::SetPaletteEntries);
+    assert_slot!(Vtbl, GetPaletteEntries => M::GetPaletteEntries);
+    assert_slot!(Vtbl, SetCurrentTexturePalette => M::SetCurrentTexturePalette);
+    assert_slot!(Vtbl, GetCurrentTexturePalette => M::GetCurrentTexturePalette);
+    assert_slot!(Vtbl, SetScissorRect => M::SetScissorRect);
+    assert_slot!(Vtbl, GetScissorRect => M::GetScissorRect);
+    assert_slot!(Vtbl, SetSoftwareVertexProcessing => M::SetSoftwareVertexProcessing);
+    assert_slot!(Vtbl, GetSoftwareVertexProcessing => M::GetSoftwareVertexProcessing);
+    assert_slot!(Vtbl, SetNPatchMode => M::SetNPatchMode);
+    assert_slot!(Vtbl, GetNPatchMode => M::GetNPatchMode);
+    assert_slot!(Vtbl, DrawPrimitive => M::DrawPrimitive);
+    assert_slot!(Vtbl, DrawIndexedPrimitive => M::DrawIndexedPrimitive);
+    assert_slot!(Vtbl, DrawPrimitiveUP => M::DrawPrimitiveUP);
+    assert_slot!(Vtbl, DrawIndexedPrimitiveUP => M::DrawIndexedPrimitiveUP);
+    assert_slot!(Vtbl, ProcessVertices => M::ProcessVertices);
+    assert_slot!(Vtbl, CreateVertexDeclaration => M::CreateVertexDeclaration);
+    assert_slot!(Vtbl, SetVertexDeclaration => M::SetVertexDeclaration);
+    assert_slot!(Vtbl, GetVertexDeclaration => M::GetVertexDeclaration);
+    assert_slot!(Vtbl, SetFVF => M::SetFVF);
+    assert_slot!(Vtbl, GetFVF => M::GetFVF);
+    assert_slot!(Vtbl, CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, SetVertexShader => M::SetVertexShader);
+    assert_slot!(Vtbl, GetVertexShader => M::GetVertexShader);
+    assert_slot!(Vtbl, SetVertexShaderConstantF => M::SetVertexShaderConstantF);
+    assert_slot!(Vtbl, GetVertexShaderConstantF => M::GetVertexShaderConstantF);
+    assert_slot!(Vtbl, SetVertexShaderConstantI => M::SetVertexShaderConstantI);
+    assert_slot!(Vtbl, GetVertexShaderConstantI => M::GetVertexShaderConstantI);
+    assert_slot!(Vtbl, SetVertexShaderConstantB => M::SetVertexShaderConstantB);
+    assert_slot!(Vtbl, GetVertexShaderConstantB => M::GetVertexShaderConstantB);
+    assert_slot!(Vtbl, SetStreamSource => M::SetStreamSource);
+    assert_slot!(Vtbl, GetStreamSource => M::GetStreamSource);
+    assert_slot!(Vtbl, SetStreamSourceFreq => M::SetStreamSourceFreq);
+    assert_slot!(Vtbl, GetStreamSourceFreq => M::GetStreamSourceFreq);
+    assert_slot!(Vtbl, SetIndices => M::SetIndices);
+    assert_slot!(Vtbl, GetIndices => M::GetIndices);
+    assert_slot!(Vtbl, CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, SetPixelShader => M::SetPixelShader);
+    assert_slot!(Vtbl, GetPixelShader => M::GetPixelShader);
+    assert_slot!(Vtbl, SetPixelShaderConstantF => M::SetPixelShaderConstantF);
+    assert_slot!(Vtbl, GetPixelShaderConstantF => M::GetPixelShaderConstantF);
+    assert_slot!(Vtbl, SetPixelShaderConstantI => M::SetPixelShaderConstantI);
+    assert_slot!(Vtbl, GetPixelShaderConstantI => M::GetPixelShaderConstantI);
+    assert_slot!(Vtbl, SetPixelShaderConstantB => M::SetPixelShaderConstantB);
+    assert_slot!(Vtbl, GetPixelShaderConstantB => M::GetPixelShaderConstantB);
+    assert_slot!(Vtbl, DrawRectPatch => M::DrawRectPatch);
+    assert_slot!(Vtbl, DrawTriPatch => M::DrawTriPatch);
+    assert_slot!(Vtbl, DeletePatch => M::DeletePatch);
+    assert_slot!(Vtbl, CreateQuery => M::CreateQuery);
+};
+
+/// `IDirect3DDevice9Ex` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX9DeviceExMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    TestCooperativeLevel,
+    GetAvailableTextureMem,
+    EvictManagedResources,
+    GetDirect3D,
+    GetDeviceCaps,
+    GetDisplayMode,
+    GetCreationParameters,
+    SetCursorProperties,
+    SetCursorPosition,
+    ShowCursor,
+    CreateAdditionalSwapChain,
+    GetSwapChain,
+    GetNumberOfSwapChains,
+    Reset,
+    Present,
+    GetBackBuffer,
+    GetRasterStatus,
+    SetDialogBoxMode,
+    SetGammaRamp,
+    GetGammaRamp,
+    CreateTexture,
+    CreateVolumeTexture,
+    CreateCubeTexture,
+    CreateVertexBuffer,
+    CreateIndexBuffer,
+    CreateRenderTarget,
+    CreateDepthStencilSurface,
+    UpdateSurface,
+    UpdateTexture,
+    GetRenderTargetData,
+    GetFrontBufferData,
+    StretchRect,
+    ColorFill,
+    CreateOffscreenPlainSurface,
+    SetRenderTarget,
+    GetRenderTarget,
+    SetDepthStencilSurface,
+    GetDepthStencilSurface,
+    BeginScene,
+    EndScene,
+    Clear,
+    SetTransform,
+    GetTransform,
+    MultiplyTransform,
+    SetViewport,
+    GetViewport,
+    SetMaterial,
+    GetMaterial,
+    SetLight,
+    GetLight,
+    LightEnable,
+    GetLightEnable,
+    SetClipPlane,
+    GetClipPlane,
+    SetRenderState,
+    GetRenderState,
+    CreateStateBlock,
+    BeginStateBlock,
+    EndStateBlock,
+    SetClipStatus,
+    GetClipStatus,
+    GetTexture,
+    SetTexture,
+    GetTextureStageState,
+    SetTextureStageState,
+    GetSamplerState,
+    SetSamplerState,
+    ValidateDevice,
+    SetPaletteEntries,
+    GetPaletteEntries,
+    SetCurrentTexturePalette,
+    GetCurrentTexturePalette,
+    SetScissorRect,
+    GetScissorRect,
+    SetSoftwareVertexProcessing,
+    GetSoftwareVertexProcessing,
+    SetNPatchMode,
+    GetNPatchMode,
+    DrawPrimitive,
+    DrawIndexedPrimitive,
+    DrawPrimitiveUP,
+    DrawIndexedPrimitiveUP,
+    ProcessVertices,
+    CreateVertexDeclaration,
+    SetVertexDeclaration,
+    GetVertexDeclaration,
+    SetFVF,
+    GetFVF,
+    CreateVertexShader,
+    SetVertexShader,
+    GetVertexShader,
+    SetVertexShaderConstantF,
+    GetVertexShaderConstantF,
+    SetVertexShaderConstantI,
+    GetVertexShaderConstantI,
+    SetVertexShaderConstantB,
+    GetVertexShaderConstantB,
+    SetStreamSource,
+    GetStreamSource,
+    SetStreamSourceFreq,
+    GetStreamSourceFreq,
+    SetIndices,
+    GetIndices,
+    CreatePixelShader,
+    SetPixelShader,
+    GetPixelShader,
+    SetPixelShaderConstantF,
+    GetPixelShaderConstantF,
+    SetPixelShaderConstantI,
+    GetPixelShaderConstantI,
+    SetPixelShaderConstantB,
+    GetPixelShaderConstantB,
+    DrawRectPatch,
+    DrawTriPatch,
+    DeletePatch,
+    CreateQuery,
+    SetConvolutionMonoKernel,
+    ComposeRects,
+    PresentEx,
+    GetGPUThreadPriority,
+    SetGPUThreadPriority,
+    WaitForVBlank,
+    CheckResourceResidency,
+    SetMaximumFrameLatency,
+    GetMaximumFrameLatency,
+    CheckDeviceState,
+    CreateRenderTargetEx,
+    CreateOffscreenPlainSurfaceEx,
+    CreateDepthStencilSurfaceEx,
+    ResetEx,
+    GetDisplayModeEx,
+}
+
+impl InterfaceMethods for DirectX9DeviceExMethods {
+    const INTERFACE: &'static str = "IDirect3DDevice9Ex";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D9::IDirect3DDevice9Ex_Vtbl as Vtbl;
+    use DirectX9DeviceExMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.TestCooperativeLevel => M::TestCooperativeLevel);
+    assert_slot!(Vtbl, base__.GetAvailableTextureMem => M::GetAvailableTextureMem);
+    assert_slot!(Vtbl, base__.EvictManagedResources => M::EvictManagedResources);
+    assert_slot!(Vtbl, base__.GetDirect3D => M::GetDirect3D);
+    assert_slot!(Vtbl, base__.GetDeviceCaps => M::GetDeviceCaps);
+    assert_slot!(Vtbl, base__.GetDisplayMode => M::GetDisplayMode);
+    assert_slot!(Vtbl, base__.GetCreationParameters => M::GetCreationParameters);
+    assert_slot!(Vtbl, base__.SetCursorProperties => M::SetCursorProperties);
+    assert_slot!(Vtbl, base__.SetCursorPosition => M::SetCursorPosition);
+    assert_slot!(Vtbl, base__.ShowCursor => M::ShowCursor);
+    assert_slot!(Vtbl, base__.CreateAdditionalSwapChain => M::CreateAdditionalSwapChain);
+    assert_slot!(Vtbl, base__.GetSwapChain => M::GetSwapChain);
+    assert_slot!(Vtbl, base__.GetNumberOfSwapChains => M::GetNumberOfSwapChains);
+    assert_slot!(Vtbl, base__.Reset => M::Reset);
+    assert_slot!(Vtbl, base__.Present => M::Present);
+    assert_slot!(Vtbl, base__.GetBackBuffer => M::GetBackBuffer);
+    assert_slot!(Vtbl, base__.GetRasterStatus => M::GetRasterStatus);
+    assert_slot!(Vtbl, base__.SetDialogBoxMode => M::SetDialogBoxMode);
+    assert_slot!(Vtbl, base__.SetGammaRamp => M::SetGammaRamp);
+    assert_slot!(Vtbl, base__.GetGammaRamp => M::GetGammaRamp);
+    assert_slot!(Vtbl, base__.CreateTexture => M::CreateTexture);
+    assert_slot!(Vtbl, base__.CreateVolumeTexture => M::CreateVolumeTexture);
+    assert_slot!(Vtbl, base__.CreateCubeTexture => M::CreateCubeTexture);
+    assert_slot!(Vtbl, base__.CreateVertexBuffer => M::CreateVertexBuffer);
+    assert_slot!(Vtbl, base__.CreateIndexBuffer => M::CreateIndexBuffer);
+    assert_slot!(Vtbl, base__.CreateRenderTarget => M::CreateRenderTarget);
+    assert_slot!(Vtbl, base__.CreateDepthStencilSurface => M::CreateDepthStencilSurface);
+    assert_slot!(Vtbl, base__.UpdateSurface => M::UpdateSurface);
+    assert_slot!(Vtbl, base__.UpdateTexture => M::UpdateTexture);
+    assert_slot!(Vtbl, base__.GetRenderTargetData => M::GetRenderTargetData);
+    assert_slot!(Vtbl, base__.GetFrontBufferData => M::GetFrontBufferData);
+    assert_slot!(Vtbl, base__.StretchRect => M::StretchRect);
+    assert_slot!(Vtbl, base__.ColorFill => M::ColorFill);
+    assert_slot!(Vtbl, base__.CreateOffscreenPlainSurface => M::CreateOffscreenPlainSurface);
+    assert_slot!(Vtbl, base__.SetRenderTarget => M::SetRenderTarget);
+    assert_slot!(Vtbl, base__.GetRenderTarget => M::GetRenderTarget);
+    assert_slot!(Vtbl, base__.SetDepthStencilSurface => M::SetDepthStencilSurface);
+    assert_slot!(Vtbl, base__.GetDepthStencilSurface => M::GetDepthStencilSurface);
+    assert_slot!(Vtbl, base__.BeginScene => M::BeginScene);
+    assert_slot!(Vtbl, base__.EndScene => M::EndScene);
+    assert_slot!(Vtbl, base__.Clear => M::Clear);
+    assert_slot!(Vtbl, base__.SetTransform => M::SetTransform);
+    assert_slot!(Vtbl, base__.GetTransform => M::GetTransform);
+    assert_slot!(Vtbl, base__.MultiplyTransform => M::MultiplyTransform);
+    assert_slot!(Vtbl, base__.SetViewport => M::SetViewport);
+    assert_slot!(Vtbl, base__.GetViewport => M::GetViewport);
+    assert_slot!(Vtbl, base__.SetMaterial => M::SetMaterial);
+    assert_slot!(Vtbl, base__.GetMaterial => M::GetMaterial);
+    assert_slot!(Vtbl, base__.SetLight => M::SetLight);
+    assert_slot!(Vtbl, base__.GetLight => M::GetLight);
+    assert_slot!(Vtbl, base__.LightEnable => M::LightEnable);
+    assert_slot!(Vtbl, base__.GetLightEnable => M::GetLightEnable);
+    assert_slot!(Vtbl, base__.SetClipPlane => M::SetClipPlane);
+    assert_slot!(Vtbl, base__.GetClipPlane => M::GetClipPlane);
+    assert_slot!(Vtbl, base__.SetRenderState => M::SetRenderState);
+    assert_slot!(Vtbl, base__.GetRenderState => M::GetRenderState);
+    assert_slot!(Vtbl, base__.CreateStateBlock => M::CreateStateBlock);
+    assert_slot!(Vtbl, base__.BeginStateBlock => M::BeginStateBlock);
+    assert_slot!(Vtbl, base__.EndStateBlock => M::EndStateBlock);
+    assert_slot!(Vtbl, base__.SetClipStatus => M::SetClipStatus);
+    assert_slot!(Vtbl, base__.GetClipStatus => M::GetClipStatus);
+    assert_slot!(Vtbl, base__.GetTexture => M::GetTexture);
+    assert_slot!(Vtbl, base__.SetTexture => M::SetTexture);
+    assert_slot!(Vtbl, base__.GetTextureStageState => M::GetTextureStageState);
+    assert_slot!(Vtbl, base__.SetTextureStageState => M::SetTextureStageState);
+    assert_slot!(Vtbl, base__.GetSamplerState => M::GetSamplerState);
+    assert_slot!(Vtbl, base__.SetSamplerState => M::SetSamplerState);
+    assert_slot!(Vtbl, base__.ValidateDevice => M::ValidateDevice);
+    assert_slot!(Vtbl, base__.SetPaletteEntries => M::SetPaletteEntries);
+    assert_slot!(Vtbl, base__.GetPaletteEntries => M::GetPaletteEntries);
+    assert_slot!(Vtbl, base__.SetCurrentTexturePalette => M::SetCurrentTexturePalette);
+    assert_slot!(Vtbl, base__.GetCurrentTexturePalette => M::GetCurrentTexturePalette);
+    assert_slot!(Vtbl, base__.SetScissorRect => M::SetScissorRect);
+    assert_slot!(Vtbl, base__.GetScissorRect => M::GetScissorRect);
+    assert_slot!(Vtbl, base__.SetSoftwareVertexProcessing => M::SetSoftwareVertexProcessing);
+    assert_slot!(Vtbl, base__.GetSoftwareVertexProcessing => M::GetSoftwareVertexProcessing);
+    assert_slot!(Vtbl, base__.SetNPatchMode => M::SetNPatchMode);
+    assert_slot!(Vtbl, base__.GetNPatchMode => M::GetNPatchMode);
+    assert_slot!(Vtbl, base__.DrawPrimitive => M::DrawPrimitive);
+    assert_slot!(Vtbl, base__.DrawIndexedPrimitive => M::DrawIndexedPrimitive);
+    assert_slot!(Vtbl, base__.DrawPrimitiveUP => M::DrawPrimitiveUP);
+    assert_slot!(Vtbl, base__.DrawIndexedPrimitiveUP => M::DrawIndexedPrimitiveUP);
+    assert_slot!(Vtbl, base__.ProcessVertices => M::ProcessVertices);
+    assert_slot!(Vtbl, base__.CreateVertexDeclaration => M::CreateVertexDeclaration);
+    assert_slot!(Vtbl, base__.SetVertexDeclaration => M::SetVertexDeclaration);
+    assert_slot!(Vtbl, base__.GetVertexDeclaration => M::GetVertexDeclaration);
+    assert_slot!(Vtbl, base__.SetFVF => M::SetFVF);
+    assert_slot!(Vtbl, base__.GetFVF => M::GetFVF);
+    assert_slot!(Vtbl, base__.CreateVertexShader => M::CreateVertexShader);
+    assert_slot!(Vtbl, base__.SetVertexShader => M::SetVertexShader);
+    assert_slot!(Vtbl, base__.GetVertexShader => M::GetVertexShader);
+    assert_slot!(Vtbl, base__.SetVertexShaderConstantF => M::SetVertexShaderConstantF);
+    assert_slot!(Vtbl, base__.GetVertexShaderConstantF => M::GetVertexShaderConstantF);
+    assert_slot!(Vtbl, base__.SetVertexShaderConstantI => M::SetVertexShaderConstantI);
+    assert_slot!(Vtbl, base__.GetVertexShaderConstantI => M::GetVertexShaderConstantI);
+    assert_slot!(Vtbl, base__.SetVertexShaderConstantB => M::SetVertexShaderConstantB);
+    assert_slot!(Vtbl, base__.GetVertexShaderConstantB => M::GetVertexShaderConstantB);
+    assert_slot!(Vtbl, base__.SetStreamSource => M::SetStreamSource);
+    assert_slot!(Vtbl, base__.GetStreamSource => M::GetStreamSource);
+    assert_slot!(Vtbl, base__.SetStreamSourceFreq => M::SetStreamSourceFreq);
+    assert_slot!(Vtbl, base__.GetStreamSourceFreq => M::GetStreamSourceFreq);
+    assert_slot!(Vtbl, base__.SetIndices => M::SetIndices);
+    assert_slot!(Vtbl, base__.GetIndices => M::GetIndices);
+    assert_slot!(Vtbl, base__.CreatePixelShader => M::CreatePixelShader);
+    assert_slot!(Vtbl, base__.SetPixelShader => M::SetPixelShader);
+    assert_slot!(Vtbl, base__.GetPixelShader => M::GetPixelShader);
+    assert_slot!(Vtbl, base__.SetPixelShaderConstantF => M::SetPixelShaderConstantF);
+    assert_slot!(Vtbl, base__.GetPixelShaderConstantF => M::GetPixelShaderConstantF);
+    assert_slot!(Vtbl, base__.SetPixelShaderConstantI => M::SetPixelShaderConstantI);
+    assert_slot!(Vtbl, base__.GetPixelShaderConstantI => M::GetPixelShaderConstantI);
+    assert_slot!(Vtbl, base__.SetPixelShaderConstantB => M::SetPixelShaderConstantB);
+    assert_slot!(Vtbl, base__.GetPixelShaderConstantB => M::GetPixelShaderConstantB);
+    assert_slot!(Vtbl, base__.DrawRectPatch => M::DrawRectPatch);
+    assert_slot!(Vtbl, base__.DrawTriPatch => M::DrawTriPatch);
+    assert_slot!(Vtbl, base__.DeletePatch => M::DeletePatch);
+    assert_slot!(Vtbl, base__.CreateQuery => M::CreateQuery);
+    assert_slot!(Vtbl, SetConvolutionMonoKernel => M::SetConvolutionMonoKernel);
+    assert_slot!(Vtbl, ComposeRects => M::ComposeRects);
+    assert_slot!(Vtbl, PresentEx => M::PresentEx);
+    assert_slot!(Vtbl, GetGPUThreadPriority => M::GetGPUThreadPriority);
+    assert_slot!(Vtbl, SetGPUThreadPriority => M::SetGPUThreadPriority);
+    assert_slot!(Vtbl, WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, CheckResourceResidency => M::CheckResourceResidency);
+    assert_slot!(Vtbl, SetMaximumFrameLatency => M::SetMaximumFrameLatency);
+    assert_slot!(Vtbl, GetMaximumFrameLatency => M::GetMaximumFrameLatency);
+    assert_slot!(Vtbl, CheckDeviceState => M::CheckDeviceState);
+    assert_slot!(Vtbl, CreateRenderTargetEx => M::CreateRenderTargetEx);
+    assert_slot!(Vtbl, CreateOffscreenPlainSurfaceEx => M::CreateOffscreenPlainSurfaceEx);
+    assert_slot!(Vtbl, CreateDepthStencilSurfaceEx => M::CreateDepthStencilSurfaceEx);
+    assert_slot!(Vtbl, ResetEx => M::ResetEx);
+    assert_slot!(Vtbl, GetDisplayModeEx => M::GetDisplayModeEx);
+};
+
+/// `IDirect3D9` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX9Direct3DMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    RegisterSoftwareDevice,
+    GetAdapterCount,
+    GetAdapterIdentifier,
+    GetAdapterModeCount,
+    EnumAdapterModes,
+    GetAdapterDisplayMode,
+    CheckDeviceType,
+    CheckDeviceFormat,
+    CheckDeviceMultiSampleType,
+    CheckDepthStencilMatch,
+    CheckDeviceFormatConversion,
+    GetDeviceCaps,
+    GetAdapterMonitor,
+    CreateDevice,
+}
+
+impl InterfaceMethods for DirectX9Direct3DMethods {
+    const INTERFACE: &'static str = "IDirect3D9";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D9::IDirect3D9_Vtbl as Vtbl;
+    use DirectX9Direct3DMethods as M;
+
+    assert_slot!(Vtbl, base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.Release => M::Release);
+    assert_slot!(Vtbl, RegisterSoftwareDevice => M::RegisterSoftwareDevice);
+    assert_slot!(Vtbl, GetAdapterCount => M::GetAdapterCount);
+    assert_slot!(Vtbl, GetAdapterIdentifier => M::GetAdapterIdentifier);
+    assert_slot!(Vtbl, GetAdapterModeCount => M::GetAdapterModeCount);
+    assert_slot!(Vtbl, EnumAdapterModes => M::EnumAdapterModes);
+    assert_slot!(Vtbl, GetAdapterDisplayMode => M::GetAdapterDisplayMode);
+    assert_slot!(Vtbl, CheckDeviceType => M::CheckDeviceType);
+    assert_slot!(Vtbl, CheckDeviceFormat => M::CheckDeviceFormat);
+    assert_slot!(Vtbl, CheckDeviceMultiSampleType => M::CheckDeviceMultiSampleType);
+    assert_slot!(Vtbl, CheckDepthStencilMatch => M::CheckDepthStencilMatch);
+    assert_slot!(Vtbl, CheckDeviceFormatConversion => M::CheckDeviceFormatConversion);
+    assert_slot!(Vtbl, GetDeviceCaps => M::GetDeviceCaps);
+    assert_slot!(Vtbl, GetAdapterMonitor => M::GetAdapterMonitor);
+    assert_slot!(Vtbl, CreateDevice => M::CreateDevice);
+};
+
+/// `IDirect3D9Ex` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX9Direct3DExMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    RegisterSoftwareDevice,
+    GetAdapterCount,
+    GetAdapterIdentifier,
+    GetAdapterModeCount,
+    EnumAdapterModes,
+    GetAdapterDisplayMode,
+    CheckDeviceType,
+    CheckDeviceFormat,
+    CheckDeviceMultiSampleType,
+    CheckDepthStencilMatch,
+    CheckDeviceFormatConversion,
+    GetDeviceCaps,
+    GetAdapterMonitor,
+    CreateDevice,
+    GetAdapterModeCountEx,
+    EnumAdapterModesEx,
+    GetAdapterDisplayModeEx,
+    CreateDeviceEx,
+    GetAdapterLUID,
+}
+
+impl InterfaceMethods for DirectX9Direct3DExMethods {
+    const INTERFACE: &'static str = "IDirect3D9Ex";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D9::IDirect3D9Ex_Vtbl as Vtbl;
+    use DirectX9Direct3DExMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.RegisterSoftwareDevice => M::RegisterSoftwareDevice);
+    assert_slot!(Vtbl, base__.GetAdapterCount => M::GetAdapterCount);
+    assert_slot!(Vtbl, base__.GetAdapterIdentifier => M::GetAdapterIdentifier);
+    assert_slot!(Vtbl, base__.GetAdapterModeCount => M::GetAdapterModeCount);
+    assert_slot!(Vtbl, base__.EnumAdapterModes => M::EnumAdapterModes);
+    assert_slot!(Vtbl, base__.GetAdapterDisplayMode => M::GetAdapterDisplayMode);
+    assert_slot!(Vtbl, base__.CheckDeviceType => M::CheckDeviceType);
+    assert_slot!(Vtbl, base__.CheckDeviceFormat => M::CheckDeviceFormat);
+    assert_slot!(Vtbl, base__.CheckDeviceMultiSampleType => M::CheckDeviceMultiSampleType);
+    assert_slot!(Vtbl, base__.CheckDepthStencilMatch => M::CheckDepthStencilMatch);
+    assert_slot!(Vtbl, base__.CheckDeviceFormatConversion => M::CheckDeviceFormatConversion);
+    assert_slot!(Vtbl, base__.GetDeviceCaps => M::GetDeviceCaps);
+    assert_slot!(Vtbl, base__.GetAdapterMonitor => M::GetAdapterMonitor);
+    assert_slot!(Vtbl, base__.CreateDevice => M::CreateDevice);
+    assert_slot!(Vtbl, GetAdapterModeCountEx => M::GetAdapterModeCountEx);
+    assert_slot!(Vtbl, EnumAdapterModesEx => M::EnumAdapterModesEx);
+    assert_slot!(Vtbl, GetAdapterDisplayModeEx => M::GetAdapterDisplayModeEx);
+    assert_slot!(Vtbl, CreateDeviceEx => M::CreateDeviceEx);
+    assert_slot!(Vtbl, GetAdapterLUID => M::GetAdapterLUID);
+};
+
+/// `IDirect3DSwapChain9` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DirectX9SwapchainMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    Present,
+    GetFrontBufferData,
+    GetBackBuffer,
+    GetRasterStatus,
+    GetDisplayMode,
+    GetDevice,
+    GetPresentParameters,
+}
+
+impl InterfaceMethods for DirectX9SwapchainMethods {
+    const INTERFACE: &'static str = "IDirect3DSwapChain9";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Direct3D9::IDirect3DSwapChain9_Vtbl as Vtbl;
+    use DirectX9SwapchainMethods as M;
+
+    assert_slot!(Vtbl, base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.Release => M::Release);
+    assert_slot!(Vtbl, Present => M::Present);
+    assert_slot!(Vtbl, GetFrontBufferData => M::GetFrontBufferData);
+    assert_slot!(Vtbl, GetBackBuffer => M::GetBackBuffer);
+    assert_slot!(Vtbl, GetRasterStatus => M::GetRasterStatus);
+    assert_slot!(Vtbl, GetDisplayMode => M::GetDisplayMode);
+    assert_slot!(Vtbl, GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, GetPresentParameters => M::GetPresentParameters);
+};
+
+/// Typed signatures of [`DirectX9DeviceMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device {
+    use std::ffi::c_void;
+
+    use super::DirectX9DeviceMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX9DeviceMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        TestCooperativeLevel() -> HRESULT,
+        GetAvailableTextureMem() -> u32,
+        EvictManagedResources() -> HRESULT,
+        GetDirect3D(pp_d3d9: *mut *mut c_void) -> HRESULT,
+        GetDeviceCaps(p_caps: *mut c_void) -> HRESULT,
+        GetDisplayMode(i_swap_chain: u32, p_mode: *mut c_void) -> HRESULT,
+        GetCreationParameters(p_parameters: *mut c_void) -> HRESULT,
+        SetCursorProperties(x_hot_spot: u32, y_hot_spot: u32, p_cursor_bitmap: *mut c_void) -> HRESULT,
+        SetCursorPosition(x: i32, y: i32, flags: u32),
+        ShowCursor(b_show: i32) -> i32,
+        CreateAdditionalSwapChain(p_presentation_parameters: *mut c_void, p_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSwapChain(i_swap_chain: u32, p_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetNumberOfSwapChains() -> u32,
+        Reset(p_presentation_parameters: *mut c_void) -> HRESULT,
+        Present(p_source_rect: *mut c_void, p_dest_rect: *mut c_void, h_dest_window_override: *mut c_void, p_dirty_region: *mut c_void) -> HRESULT,
+        GetBackBuffer(i_swap_chain: u32, i_back_buffer: u32, r#type: i32, pp_back_buffer: *mut *mut c_void) -> HRESULT,
+        GetRasterStatus(i_swap_chain: u32, p_raster_status: *mut c_void) -> HRESULT,
+        SetDialogBoxMode(b_enable_dialogs: i32) -> HRESULT,
+        SetGammaRamp(i_swap_chain: u32, flags: u32, p_ramp: *mut c_void),
+        GetGammaRamp(i_swap_chain: u32, p_ramp: *mut c_void),
+        CreateTexture(width: u32, height: u32, levels: u32, usage: u32, format: u32, pool: i32, pp_texture: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateVolumeTexture(width: u32, height: u32, depth: u32, levels: u32, usage: u32, format: u32, pool: i32, pp_volume_texture: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateCubeTexture(edge_length: u32, levels: u32, usage: u32, format: u32, pool: i32, pp_cube_texture: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateVertexBuffer(length: u32, usage: u32, fvf: u32, pool: i32, pp_vertex_buffer: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateIndexBuffer(length: u32, usage: u32, format: u32, pool: i32, pp_index_buffer: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateRenderTarget(width: u32, height: u32, format: u32, multi_sample: i32, multisample_quality: u32, lockable: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilSurface(width: u32, height: u32, format: u32, multi_sample: i32, multisample_quality: u32, discard: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        UpdateSurface(p_source_surface: *mut c_void, p_source_rect: *mut c_void, p_destination_surface: *mut c_void, p_dest_point: *mut c_void) -> HRESULT,
+        UpdateTexture(p_source_texture: *mut c_void, p_destination_texture: *mut c_void) -> HRESULT,
+        GetRenderTargetData(p_render_target: *mut c_void, p_dest_surface: *mut c_void) -> HRESULT,
+        GetFrontBufferData(i_swap_chain: u32, p_dest_surface: *mut c_void) -> HRESULT,
+        StretchRect(p_source_surface: *mut c_void, p_source_rect: *mut c_void, p_dest_surface: *mut c_void, p_dest_rect: *mut c_void, filter: i32) -> HRESULT,
+        ColorFill(p_surface: *mut c_void, p_rect: *mut c_void, color: u32) -> HRESULT,
+        CreateOffscreenPlainSurface(width: u32, height: u32, format: u32, pool: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        SetRenderTarget(render_target_index: u32, p_render_target: *mut c_void) -> HRESULT,
+        GetRenderTarget(render_target_index: u32, pp_render_target: *mut *mut c_void) -> HRESULT,
+        SetDepthStencilSurface(p_new_z_stencil: *mut c_void) -> HRESULT,
+        GetDepthStencilSurface(pp_z_stencil_surface: *mut *mut c_void) -> HRESULT,
+        BeginScene() -> HRESULT,
+        EndScene() -> HRESULT,
+        Clear(count: u32, p_rects: *mut c_void, flags: u32, color: u32, z: f32, stencil: u32) -> HRESULT,
+        SetTransform(state: i32, p_matrix: *mut c_void) -> HRESULT,
+        GetTransform(state: i32, p_matrix: *mut c_void) -> HRESULT,
+        MultiplyTransform(param0: i32, param1: *mut c_void) -> HRESULT,
+        SetViewport(p_viewport: *mut c_void) -> HRESULT,
+        GetViewport(p_viewport: *mut c_void) -> HRESULT,
+        SetMaterial(p_material: *mut c_void) -> HRESULT,
+        GetMaterial(p_material: *mut c_void) -> HRESULT,
+        SetLight(index: u32, param1: *mut c_void) -> HRESULT,
+        GetLight(index: u32, param1: *mut c_void) -> HRESULT,
+        LightEnable(index: u32, enable: i32) -> HRESULT,
+        GetLightEnable(index: u32, p_enable: *mut i32) -> HRESULT,
+        SetClipPlane(index: u32, p_plane: *mut f32) -> HRESULT,
+        GetClipPlane(index: u32, p_plane: *mut f32) -> HRESULT,
+        SetRenderState(state: i32, value: u32) -> HRESULT,
+        GetRenderState(state: i32, p_value: *mut u32) -> HRESULT,
+        CreateStateBlock(r#type: i32, pp_sb: *mut *mut c_void) -> HRESULT,
+        BeginStateBlock() -> HRESULT,
+        EndStateBlock(pp_sb: *mut *mut c_void) -> HRESULT,
+        SetClipStatus(p_clip_status: *mut c_void) -> HRESULT,
+        GetClipStatus(p_clip_status: *mut c_void) -> HRESULT,
+        GetTexture(stage: u32, pp_texture: *mut *mut c_void) -> HRESULT,
+        SetTexture(stage: u32, p_texture: *mut c_void) -> HRESULT,
+        GetTextureStageState(stage: u32, r#type: i32, p_value: *mut u32) -> HRESULT,
+        SetTextureStageState(stage: u32, r#type: i32, value: u32) -> HRESULT,
+        GetSamplerState(sampler: u32, r#type: i32, p_value: *mut u32) -> HRESULT,
+        SetSamplerState(sampler: u32, r#type: i32, value: u32) -> HRESULT,
+        ValidateDevice(p_num_passes: *mut u32) -> HRESULT,
+        SetPaletteEntries(palette_number: u32, p_entries: *mut c_void) -> HRESULT,
+        GetPaletteEntries(palette_number: u32, p_entries: *mut c_void) -> HRESULT,
+        SetCurrentTexturePalette(palette_number: u32) -> HRESULT,
+        GetCurrentTexturePalette(palette_number: *mut u32) -> HRESULT,
+        SetScissorRect(p_rect: *mut c_void) -> HRESULT,
+        GetScissorRect(p_rect: *mut c_void) -> HRESULT,
+        SetSoftwareVertexProcessing(b_software: i32) -> HRESULT,
+        GetSoftwareVertexProcessing() -> i32,
+        SetNPatchMode(n_segments: f32) -> HRESULT,
+        GetNPatchMode() -> f32,
+        DrawPrimitive(primitive_type: i32, start_vertex: u32, primitive_count: u32) -> HRESULT,
+        DrawIndexedPrimitive(param0: i32, base_vertex_index: i32, min_vertex_index: u32, num_vertices: u32, start_index: u32, prim_count: u32) -> HRESULT,
+        DrawPrimitiveUP(primitive_type: i32, primitive_count: u32, p_vertex_stream_zero_data: *mut c_void, vertex_stream_zero_stride: u32) -> HRESULT,
+        DrawIndexedPrimitiveUP(primitive_type: i32, min_vertex_index: u32, num_vertices: u32, primitive_count: u32, p_index_data: *mut c_void, index_data_format: u32, p_vertex_stream_zero_data: *mut c_void, vertex_stream_zero_stride: u32) -> HRESULT,
+        ProcessVertices(src_start_index: u32, dest_index: u32, vertex_count: u32, p_dest_buffer: *mut c_void, p_vertex_decl: *mut c_void, flags: u32) -> HRESULT,
+        CreateVertexDeclaration(p_vertex_elements: *mut c_void, pp_decl: *mut *mut c_void) -> HRESULT,
+        SetVertexDeclaration(p_decl: *mut c_void) -> HRESULT,
+        GetVertexDeclaration(pp_decl: *mut *mut c_void) -> HRESULT,
+        SetFVF(fvf: u32) -> HRESULT,
+        GetFVF(p_fvf: *mut u32) -> HRESULT,
+        CreateVertexShader(p_function: *mut u32, pp_shader: *mut *mut c_void) -> HRESULT,
+        SetVertexShader(p_shader: *mut c_void) -> HRESULT,
+        GetVertexShader(pp_shader: *mut *mut c_void) -> HRESULT,
+        SetVertexShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        GetVertexShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        SetVertexShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        GetVertexShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        SetVertexShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        GetVertexShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        SetStreamSource(stream_number: u32, p_stream_data: *mut c_void, offset_in_bytes: u32, stride: u32) -> HRESULT,
+        GetStreamSource(stream_number: u32, pp_stream_data: *mut *mut c_void, p_offset_in_bytes: *mut u32, p_stride: *mut u32) -> HRESULT,
+        SetStreamSourceFreq(stream_number: u32, setting: u32) -> HRESULT,
+        GetStreamSourceFreq(stream_number: u32, p_setting: *mut u32) -> HRESULT,
+        SetIndices(p_index_data: *mut c_void) -> HRESULT,
+        GetIndices(pp_index_data: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_function: *mut u32, pp_shader: *mut *mut c_void) -> HRESULT,
+        SetPixelShader(p_shader: *mut c_void) -> HRESULT,
+        GetPixelShader(pp_shader: *mut *mut c_void) -> HRESULT,
+        SetPixelShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        GetPixelShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        SetPixelShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        GetPixelShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        SetPixelShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        GetPixelShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        DrawRectPatch(handle: u32, p_num_segs: *mut f32, p_rect_patch_info: *mut c_void) -> HRESULT,
+        DrawTriPatch(handle: u32, p_num_segs: *mut f32, p_tri_patch_info: *mut c_void) -> HRESULT,
+        DeletePatch(handle: u32) -> HRESULT,
+        CreateQuery(r#type: i32, pp_query: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX9DeviceExMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod device_ex {
+    use std::ffi::c_void;
+
+    use super::DirectX9DeviceExMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX9DeviceExMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        TestCooperativeLevel() -> HRESULT,
+        GetAvailableTextureMem() -> u32,
+        EvictManagedResources() -> HRESULT,
+        GetDirect3D(pp_d3d9: *mut *mut c_void) -> HRESULT,
+        GetDeviceCaps(p_caps: *mut c_void) -> HRESULT,
+        GetDisplayMode(i_swap_chain: u32, p_mode: *mut c_void) -> HRESULT,
+        GetCreationParameters(p_parameters: *mut c_void) -> HRESULT,
+        SetCursorProperties(x_hot_spot: u32, y_hot_spot: u32, p_cursor_bitmap: *mut c_void) -> HRESULT,
+        SetCursorPosition(x: i32, y: i32, flags: u32),
+        ShowCursor(b_show: i32) -> i32,
+        CreateAdditionalSwapChain(p_presentation_parameters: *mut c_void, p_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSwapChain(i_swap_chain: u32, p_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetNumberOfSwapChains() -> u32,
+        Reset(p_presentation_parameters: *mut c_void) -> HRESULT,
+        Present(p_source_rect: *mut c_void, p_dest_rect: *mut c_void, h_dest_window_override: *mut c_void, p_dirty_region: *mut c_void) -> HRESULT,
+        GetBackBuffer(i_swap_chain: u32, i_back_buffer: u32, r#type: i32, pp_back_buffer: *mut *mut c_void) -> HRESULT,
+        GetRasterStatus(i_swap_chain: u32, p_raster_status: *mut c_void) -> HRESULT,
+        SetDialogBoxMode(b_enable_dialogs: i32) -> HRESULT,
+        SetGammaRamp(i_swap_chain: u32, flags: u32, p_ramp: *mut c_void),
+        GetGammaRamp(i_swap_chain: u32, p_ramp: *mut c_void),
+        CreateTexture(width: u32, height: u32, levels: u32, usage: u32, format: u32, pool: i32, pp_texture: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateVolumeTexture(width: u32, height: u32, depth: u32, levels: u32, usage: u32, format: u32, pool: i32, pp_volume_texture: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateCubeTexture(edge_length: u32, levels: u32, usage: u32, format: u32, pool: i32, pp_cube_texture: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateVertexBuffer(length: u32, usage: u32, fvf: u32, pool: i32, pp_vertex_buffer: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateIndexBuffer(length: u32, usage: u32, format: u32, pool: i32, pp_index_buffer: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateRenderTarget(width: u32, height: u32, format: u32, multi_sample: i32, multisample_quality: u32, lockable: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        CreateDepthStencilSurface(width: u32, height: u32, format: u32, multi_sample: i32, multisample_quality: u32, discard: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        UpdateSurface(p_source_surface: *mut c_void, p_source_rect: *mut c_void, p_destination_surface: *mut c_void, p_dest_point: *mut c_void) -> HRESULT,
+        UpdateTexture(p_source_texture: *mut c_void, p_destination_texture: *mut c_void) -> HRESULT,
+        GetRenderTargetData(p_render_target: *mut c_void, p_dest_surface: *mut c_void) -> HRESULT,
+        GetFrontBufferData(i_swap_chain: u32, p_dest_surface: *mut c_void) -> HRESULT,
+        StretchRect(p_source_surface: *mut c_void, p_source_rect: *mut c_void, p_dest_surface: *mut c_void, p_dest_rect: *mut c_void, filter: i32) -> HRESULT,
+        ColorFill(p_surface: *mut c_void, p_rect: *mut c_void, color: u32) -> HRESULT,
+        CreateOffscreenPlainSurface(width: u32, height: u32, format: u32, pool: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void) -> HRESULT,
+        SetRenderTarget(render_target_index: u32, p_render_target: *mut c_void) -> HRESULT,
+        GetRenderTarget(render_target_index: u32, pp_render_target: *mut *mut c_void) -> HRESULT,
+        SetDepthStencilSurface(p_new_z_stencil: *mut c_void) -> HRESULT,
+        GetDepthStencilSurface(pp_z_stencil_surface: *mut *mut c_void) -> HRESULT,
+        BeginScene() -> HRESULT,
+        EndScene() -> HRESULT,
+        Clear(count: u32, p_rects: *mut c_void, flags: u32, color: u32, z: f32, stencil: u32) -> HRESULT,
+        SetTransform(state: i32, p_matrix: *mut c_void) -> HRESULT,
+        GetTransform(state: i32, p_matrix: *mut c_void) -> HRESULT,
+        MultiplyTransform(param0: i32, param1: *mut c_void) -> HRESULT,
+        SetViewport(p_viewport: *mut c_void) -> HRESULT,
+        GetViewport(p_viewport: *mut c_void) -> HRESULT,
+        SetMaterial(p_material: *mut c_void) -> HRESULT,
+        GetMaterial(p_material: *mut c_void) -> HRESULT,
+        SetLight(index: u32, param1: *mut c_void) -> HRESULT,
+        GetLight(index: u32, param1: *mut c_void) -> HRESULT,
+        LightEnable(index: u32, enable: i32) -> HRESULT,
+        GetLightEnable(index: u32, p_enable: *mut i32) -> HRESULT,
+        SetClipPlane(index: u32, p_plane: *mut f32) -> HRESULT,
+        GetClipPlane(index: u32, p_plane: *mut f32) -> HRESULT,
+        SetRenderState(state: i32, value: u32) -> HRESULT,
+        GetRenderState(state: i32, p_value: *mut u32) -> HRESULT,
+        CreateStateBlock(r#type: i32, pp_sb: *mut *mut c_void) -> HRESULT,
+        BeginStateBlock() -> HRESULT,
+        EndStateBlock(pp_sb: *mut *mut c_void) -> HRESULT,
+        SetClipStatus(p_clip_status: *mut c_void) -> HRESULT,
+        GetClipStatus(p_clip_status: *mut c_void) -> HRESULT,
+        GetTexture(stage: u32, pp_texture: *mut *mut c_void) -> HRESULT,
+        SetTexture(stage: u32, p_texture: *mut c_void) -> HRESULT,
+        GetTextureStageState(stage: u32, r#type: i32, p_value: *mut u32) -> HRESULT,
+        SetTextureStageState(stage: u32, r#type: i32, value: u32) -> HRESULT,
+        GetSamplerState(sampler: u32, r#type: i32, p_value: *mut u32) -> HRESULT,
+        SetSamplerState(sampler: u32, r#type: i32, value: u32) -> HRESULT,
+        ValidateDevice(p_num_passes: *mut u32) -> HRESULT,
+        SetPaletteEntries(palette_number: u32, p_entries: *mut c_void) -> HRESULT,
+        GetPaletteEntries(palette_number: u32, p_entries: *mut c_void) -> HRESULT,
+        SetCurrentTexturePalette(palette_number: u32) -> HRESULT,
+        GetCurrentTexturePalette(palette_number: *mut u32) -> HRESULT,
+        SetScissorRect(p_rect: *mut c_void) -> HRESULT,
+        GetScissorRect(p_rect: *mut c_void) -> HRESULT,
+        SetSoftwareVertexProcessing(b_software: i32) -> HRESULT,
+        GetSoftwareVertexProcessing() -> i32,
+        SetNPatchMode(n_segments: f32) -> HRESULT,
+        GetNPatchMode() -> f32,
+        DrawPrimitive(primitive_type: i32, start_vertex: u32, primitive_count: u32) -> HRESULT,
+        DrawIndexedPrimitive(param0: i32, base_vertex_index: i32, min_vertex_index: u32, num_vertices: u32, start_index: u32, prim_count: u32) -> HRESULT,
+        DrawPrimitiveUP(primitive_type: i32, primitive_count: u32, p_vertex_stream_zero_data: *mut c_void, vertex_stream_zero_stride: u32) -> HRESULT,
+        DrawIndexedPrimitiveUP(primitive_type: i32, min_vertex_index: u32, num_vertices: u32, primitive_count: u32, p_index_data: *mut c_void, index_data_format: u32, p_vertex_stream_zero_data: *mut c_void, vertex_stream_zero_stride: u32) -> HRESULT,
+        ProcessVertices(src_start_index: u32, dest_index: u32, vertex_count: u32, p_dest_buffer: *mut c_void, p_vertex_decl: *mut c_void, flags: u32) -> HRESULT,
+        CreateVertexDeclaration(p_vertex_elements: *mut c_void, pp_decl: *mut *mut c_void) -> HRESULT,
+        SetVertexDeclaration(p_decl: *mut c_void) -> HRESULT,
+        GetVertexDeclaration(pp_decl: *mut *mut c_void) -> HRESULT,
+        SetFVF(fvf: u32) -> HRESULT,
+        GetFVF(p_fvf: *mut u32) -> HRESULT,
+        CreateVertexShader(p_function: *mut u32, pp_shader: *mut *mut c_void) -> HRESULT,
+        SetVertexShader(p_shader: *mut c_void) -> HRESULT,
+        GetVertexShader(pp_shader: *mut *mut c_void) -> HRESULT,
+        SetVertexShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        GetVertexShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        SetVertexShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        GetVertexShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        SetVertexShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        GetVertexShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        SetStreamSource(stream_number: u32, p_stream_data: *mut c_void, offset_in_bytes: u32, stride: u32) -> HRESULT,
+        GetStreamSource(stream_number: u32, pp_stream_data: *mut *mut c_void, p_offset_in_bytes: *mut u32, p_stride: *mut u32) -> HRESULT,
+        SetStreamSourceFreq(stream_number: u32, setting: u32) -> HRESULT,
+        GetStreamSourceFreq(stream_number: u32, p_setting: *mut u32) -> HRESULT,
+        SetIndices(p_index_data: *mut c_void) -> HRESULT,
+        GetIndices(pp_index_data: *mut *mut c_void) -> HRESULT,
+        CreatePixelShader(p_function: *mut u32, pp_shader: *mut *mut c_void) -> HRESULT,
+        SetPixelShader(p_shader: *mut c_void) -> HRESULT,
+        GetPixelShader(pp_shader: *mut *mut c_void) -> HRESULT,
+        SetPixelShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        GetPixelShaderConstantF(start_register: u32, p_constant_data: *mut f32, vector4f_count: u32) -> HRESULT,
+        SetPixelShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        GetPixelShaderConstantI(start_register: u32, p_constant_data: *mut i32, vector4i_count: u32) -> HRESULT,
+        SetPixelShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        GetPixelShaderConstantB(start_register: u32, p_constant_data: *mut i32, bool_count: u32) -> HRESULT,
+        DrawRectPatch(handle: u32, p_num_segs: *mut f32, p_rect_patch_info: *mut c_void) -> HRESULT,
+        DrawTriPatch(handle: u32, p_num_segs: *mut f32, p_tri_patch_info: *mut c_void) -> HRESULT,
+        DeletePatch(handle: u32) -> HRESULT,
+        CreateQuery(r#type: i32, pp_query: *mut *mut c_void) -> HRESULT,
+        SetConvolutionMonoKernel(width: u32, height: u32, rows: *mut f32, columns: *mut f32) -> HRESULT,
+        ComposeRects(p_src: *mut c_void, p_dst: *mut c_void, p_src_rect_descs: *mut c_void, num_rects: u32, p_dst_rect_descs: *mut c_void, operation: i32, xoffset: i32, yoffset: i32) -> HRESULT,
+        PresentEx(p_source_rect: *mut c_void, p_dest_rect: *mut c_void, h_dest_window_override: *mut c_void, p_dirty_region: *mut c_void, dw_flags: u32) -> HRESULT,
+        GetGPUThreadPriority(p_priority: *mut i32) -> HRESULT,
+        SetGPUThreadPriority(priority: i32) -> HRESULT,
+        WaitForVBlank(i_swap_chain: u32) -> HRESULT,
+        CheckResourceResidency(p_resource_array: *mut *mut c_void, num_resources: u32) -> HRESULT,
+        SetMaximumFrameLatency(max_latency: u32) -> HRESULT,
+        GetMaximumFrameLatency(p_max_latency: *mut u32) -> HRESULT,
+        CheckDeviceState(h_destination_window: *mut c_void) -> HRESULT,
+        CreateRenderTargetEx(width: u32, height: u32, format: u32, multi_sample: i32, multisample_quality: u32, lockable: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void, usage: u32) -> HRESULT,
+        CreateOffscreenPlainSurfaceEx(width: u32, height: u32, format: u32, pool: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void, usage: u32) -> HRESULT,
+        CreateDepthStencilSurfaceEx(width: u32, height: u32, format: u32, multi_sample: i32, multisample_quality: u32, discard: i32, pp_surface: *mut *mut c_void, p_shared_handle: *mut *mut c_void, usage: u32) -> HRESULT,
+        ResetEx(p_presentation_parameters: *mut c_void, p_fullscreen_display_mode: *mut c_void) -> HRESULT,
+        GetDisplayModeEx(i_swap_chain: u32, p_mode: *mut c_void, p_rotation: *mut i32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX9Direct3DMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod direct3d {
+    use std::ffi::c_void;
+
+    use super::DirectX9Direct3DMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX9Direct3DMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        RegisterSoftwareDevice(p_initialize_function: *mut c_void) -> HRESULT,
+        GetAdapterCount() -> u32,
+        GetAdapterIdentifier(adapter: u32, flags: u32, p_identifier: *mut c_void) -> HRESULT,
+        GetAdapterModeCount(adapter: u32, format: u32) -> u32,
+        EnumAdapterModes(adapter: u32, format: u32, mode: u32, p_mode: *mut c_void) -> HRESULT,
+        GetAdapterDisplayMode(adapter: u32, p_mode: *mut c_void) -> HRESULT,
+        CheckDeviceType(adapter: u32, dev_type: i32, adapter_format: u32, back_buffer_format: u32, b_windowed: i32) -> HRESULT,
+        CheckDeviceFormat(adapter: u32, device_type: i32, adapter_format: u32, usage: u32, r_type: i32, check_format: u32) -> HRESULT,
+        CheckDeviceMultiSampleType(adapter: u32, device_type: i32, surface_format: u32, windowed: i32, multi_sample_type: i32, p_quality_levels: *mut u32) -> HRESULT,
+        CheckDepthStencilMatch(adapter: u32, device_type: i32, adapter_format: u32, render_target_format: u32, depth_stencil_format: u32) -> HRESULT,
+        CheckDeviceFormatConversion(adapter: u32, device_type: i32, source_format: u32, target_format: u32) -> HRESULT,
+        GetDeviceCaps(adapter: u32, device_type: i32, p_caps: *mut c_void) -> HRESULT,
+        GetAdapterMonitor(adapter: u32) -> *mut c_void,
+        CreateDevice(adapter: u32, device_type: i32, h_focus_window: *mut c_void, behavior_flags: u32, p_presentation_parameters: *mut c_void, pp_returned_device_interface: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX9Direct3DExMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod direct3d_ex {
+    use std::ffi::c_void;
+
+    use super::DirectX9Direct3DExMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX9Direct3DExMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        RegisterSoftwareDevice(p_initialize_function: *mut c_void) -> HRESULT,
+        GetAdapterCount() -> u32,
+        GetAdapterIdentifier(adapter: u32, flags: u32, p_identifier: *mut c_void) -> HRESULT,
+        GetAdapterModeCount(adapter: u32, format: u32) -> u32,
+        EnumAdapterModes(adapter: u32, format: u32, mode: u32, p_mode: *mut c_void) -> HRESULT,
+        GetAdapterDisplayMode(adapter: u32, p_mode: *mut c_void) -> HRESULT,
+        CheckDeviceType(adapter: u32, dev_type: i32, adapter_format: u32, back_buffer_format: u32, b_windowed: i32) -> HRESULT,
+        CheckDeviceFormat(adapter: u32, device_type: i32, adapter_format: u32, usage: u32, r_type: i32, check_format: u32) -> HRESULT,
+        CheckDeviceMultiSampleType(adapter: u32, device_type: i32, surface_format: u32, windowed: i32, multi_sample_type: i32, p_quality_levels: *mut u32) -> HRESULT,
+        CheckDepthStencilMatch(adapter: u32, device_type: i32, adapter_format: u32, render_target_format: u32, depth_stencil_format: u32) -> HRESULT,
+        CheckDeviceFormatConversion(adapter: u32, device_type: i32, source_format: u32, target_format: u32) -> HRESULT,
+        GetDeviceCaps(adapter: u32, device_type: i32, p_caps: *mut c_void) -> HRESULT,
+        GetAdapterMonitor(adapter: u32) -> *mut c_void,
+        CreateDevice(adapter: u32, device_type: i32, h_focus_window: *mut c_void, behavior_flags: u32, p_presentation_parameters: *mut c_void, pp_returned_device_interface: *mut *mut c_void) -> HRESULT,
+        GetAdapterModeCountEx(adapter: u32, p_filter: *mut c_void) -> u32,
+        EnumAdapterModesEx(adapter: u32, p_filter: *mut c_void, mode: u32, p_mode: *mut c_void) -> HRESULT,
+        GetAdapterDisplayModeEx(adapter: u32, p_mode: *mut c_void, p_rotation: *mut i32) -> HRESULT,
+        CreateDeviceEx(adapter: u32, device_type: i32, h_focus_window: *mut c_void, behavior_flags: u32, p_presentation_parameters: *mut c_void, p_fullscreen_display_mode: *mut c_void, pp_returned_device_interface: *mut *mut c_void) -> HRESULT,
+        GetAdapterLUID(adapter: u32, p_luid: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DirectX9SwapchainMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain {
+    use std::ffi::c_void;
+
+    use super::DirectX9SwapchainMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DirectX9SwapchainMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        Present(p_source_rect: *mut c_void, p_dest_rect: *mut c_void, h_dest_window_override: *mut c_void, p_dirty_region: *mut c_void, dw_flags: u32) -> HRESULT,
+        GetFrontBufferData(p_dest_surface: *mut c_void) -> HRESULT,
+        GetBackBuffer(i_back_buffer: u32, r#type: i32, pp_back_buffer: *mut *mut c_void) -> HRESULT,
+        GetRasterStatus(p_raster_status: *mut c_void) -> HRESULT,
+        GetDisplayMode(p_mode: *mut c_void) -> HRESULT,
+        GetDevice(pp_device: *mut *mut c_void) -> HRESULT,
+        GetPresentParameters(p_presentation_parameters: *mut c_void) -> HRESULT,
+    }
+}
@@ -0,0 +1,643 @@
+//! How discovery creates its devices: with which driver, at which feature levels
+//! and on which adapter, and what to fall back to when that fails.
+//!
+//! The defaults reproduce what each backend always did. VMs, CI runners and
+//! hybrid-graphics laptops tend to need something else, such as WARP or the null
+//! driver, or the discrete adapter over the integrated one. [`DiscoveryOptions`]
+//! lists the preferences, and [`DiscoveryOptions::plan`] turns them into the
+//! device creations a backend tries, in order, until one succeeds.
+
+use crate::RenderEngine;
+
+/// The driver a device is created with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DriverType {
+    /// The adapter's own driver, `HAL` in Direct3D 9.
+    Hardware,
+    /// The software rasterizer, not available to Direct3D 9.
+    Warp,
+    /// The reference rasterizer, which needs the SDK layers installed. Not
+    /// available to Direct3D 12.
+    Reference,
+    /// A device that renders nothing, `NULLREF` in Direct3D 9. Not available to
+    /// Direct3D 12.
+    Null,
+}
+
+/// A `D3D_FEATURE_LEVEL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum FeatureLevel {
+    Level9_1 = 0x9100,
+    Level9_2 = 0x9200,
+    Level9_3 = 0x9300,
+    Level10_0 = 0xa000,
+    Level10_1 = 0xa100,
+    Level11_0 = 0xb000,
+    Level11_1 = 0xb100,
+    Level12_0 = 0xc000,
+    Level12_1 = 0xc100,
+    Level12_2 = 0xc200,
+}
+
+impl FeatureLevel {
+    /// The `D3D_FEATURE_LEVEL` value.
+    pub fn raw(self) -> i32 {
+        self as i32
+    }
+}
+
+#[cfg(windows)]
+impl From<FeatureLevel> for windows::Win32::Graphics::Direct3D::D3D_FEATURE_LEVEL {
+    fn from(level: FeatureLevel) -> Self {
+        Self(level.raw())
+    }
+}
+
+/// Locally unique identifier of an adapter, stable until the next reboot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Luid {
+    pub low: u32,
+    pub high: i32,
+}
+
+/// An adapter as its engine enumerates it: DXGI for DirectX10 and up,
+/// `IDirect3D9` for DirectX9.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdapterInfo {
+    /// Position in the enumeration.
+    pub index: u32,
+    pub luid: Luid,
+    /// PCI vendor id, e.g. `0x10de` for NVIDIA, `0x1002` for AMD and `0x8086`
+    /// for Intel.
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub description: String,
+    /// A software adapter, such as the Microsoft Basic Render Driver.
+    pub software: bool,
+}
+
+/// Which adapter hardware devices are created on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AdapterSelector {
+    /// The system's default adapter.
+    #[default]
+    Default,
+    /// The adapter at this position in the enumeration.
+    Index(u32),
+    /// The adapter with this LUID.
+    Luid(Luid),
+    /// Every adapter of this PCI vendor, in enumeration order.
+    Vendor(u32),
+}
+
+impl AdapterSelector {
+    fn matches(&self, adapter: &AdapterInfo) -> bool {
+        match self {
+            Self::Default => false,
+            Self::Index(index) => adapter.index == *index,
+            Self::Luid(luid) => adapter.luid == *luid,
+            Self::Vendor(vendor_id) => adapter.vendor_id == *vendor_id,
+        }
+    }
+}
+
+/// One device creation a backend tries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Attempt {
+    pub driver: DriverType,
+    /// Index of the adapter to create the device on, `None` for the default
+    /// one or drivers that take no adapter.
+    pub adapter: Option<u32>,
+    /// Levels to request, in order of preference. Empty for DirectX9, which
+    /// has none, and a single minimum level for DirectX12.
+    pub feature_levels: Vec<FeatureLevel>,
+}
+
+/// Driver, feature level and adapter preferences for discovery, each tried in
+/// the order added. A preference left unset keeps the backend's default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscoveryOptions {
+    drivers: Vec<DriverType>,
+    feature_levels: Vec<FeatureLevel>,
+    adapters: Vec<AdapterSelector>,
+}
+
+impl DiscoveryOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tries `driver` after the drivers added before it.
+    pub fn driver(mut self, driver: DriverType) -> Self {
+        self.drivers.push(driver);
+        self
+    }
+
+    /// Requests these levels, in order of preference. Levels the engine has no
+    /// use for are left out of its attempts.
+    pub fn feature_levels(mut self, levels: impl IntoIterator<Item = FeatureLevel>) -> Self {
+        self.feature_levels = levels.into_iter().collect();
+        self
+    }
+
+    /// Tries hardware devices on the adapters `adapter` selects after those
+    /// selected before.
+    pub fn adapter(mut self, adapter: AdapterSelector) -> Self {
+        self.adapters.push(adapter);
+        self
+    }
+
+    /// The device creations to try for `engine`, in order, given the adapters
+    /// it enumerates. Drivers the engine does not support are skipped, as are
+    /// adapters no selector matches; an empty plan means nothing can be tried.
+    pub fn plan(&self, engine: RenderEngine, adapters: &[AdapterInfo]) -> Vec<Attempt> {
+        let drivers = if self.drivers.is_empty() {
+            vec![default_driver(engine)]
+        } else {
+            self.drivers.clone()
+        };
+        let levels = if self.feature_levels.is_empty() {
+            default_feature_levels(engine).to_vec()
+        } else {
+            self.feature_levels
+                .iter()
+                .copied()
+                .filter(|level| supports_level(engine, *level))
+                .collect()
+        };
+        if levels.is_empty() && engine != RenderEngine::DirectX9 {
+            return Vec::new();
+        }
+
+        let selected = self.select_adapters(adapters);
+        let level_groups: Vec<Vec<FeatureLevel>> = match engine {
+            // D3D12CreateDevice takes a single minimum level.
+            RenderEngine::DirectX12 => levels.iter().map(|level| vec![*level]).collect(),
+            _ => vec![levels],
+        };
+
+        let mut attempts = Vec::new();
+        for driver in drivers {
+            if !supports_driver(engine, driver) {
+                continue;
+            }
+            let on: &[Option<u32>] = if takes_adapter(engine, driver) {
+                &selected
+            } else {
+                &[None]
+            };
+            for &adapter in on {
+                for feature_levels in &level_groups {
+                    let attempt = Attempt {
+                        driver,
+                        adapter,
+                        feature_levels: feature_levels.clone(),
+                    };
+                    if !attempts.contains(&attempt) {
+                        attempts.push(attempt);
+                    }
+                }
+            }
+        }
+        attempts
+    }
+
+    /// Adapters the selectors pick, in order and without repeats.
+    fn select_adapters(&self, adapters: &[AdapterInfo]) -> Vec<Option<u32>> {
+        let selectors = if self.adapters.is_empty() {
+            &[AdapterSelector::Default][..]
+        } else {
+            &self.adapters
+        };
+
+        let mut selected = Vec::new();
+        for selector in selectors {
+            let picks: Vec<Option<u32>> = match selector {
+                AdapterSelector::Default => vec![None],
+                _ => adapters
+                    .iter()
+                    .filter(|adapter| selector.matches(adapter))
+                    .map(|adapter| Some(adapter.index))
+                    .collect(),
+            };
+            for pick in picks {
+                if !selected.contains(&pick) {
+                    selected.push(pick);
+                }
+            }
+        }
+        selected
+    }
+}
+
+/// What each backend created its devices with before options existed.
+fn default_driver(engine: RenderEngine) -> DriverType {
+    match engine {
+        RenderEngine::DirectX9 => DriverType::Null,
+        _ => DriverType::Hardware,
+    }
+}
+
+fn default_feature_levels(engine: RenderEngine) -> &'static [FeatureLevel] {
+    match engine {
+        RenderEngine::DirectX9 => &[],
+        RenderEngine::DirectX10 => &[FeatureLevel::Level10_1, FeatureLevel::Level10_0],
+        RenderEngine::DirectX11 => &[FeatureLevel::Level10_0, FeatureLevel::Level11_1],
+        RenderEngine::DirectX12 => &[FeatureLevel::Level11_0],
+    }
+}
+
+fn supports_driver(engine: RenderEngine, driver: DriverType) -> bool {
+    match engine {
+        RenderEngine::DirectX9 => driver != DriverType::Warp,
+        RenderEngine::DirectX10 | RenderEngine::DirectX11 => true,
+        RenderEngine::DirectX12 => matches!(driver, DriverType::Hardware | DriverType::Warp),
+    }
+}
+
+/// DirectX10 only applies levels to its `ID3D10Device1`.
+fn supports_level(engine: RenderEngine, level: FeatureLevel) -> bool {
+    match engine {
+        RenderEngine::DirectX9 => false,
+        RenderEngine::DirectX10 => level <= FeatureLevel::Level10_1,
+        RenderEngine::DirectX11 => level <= FeatureLevel::Level12_1,
+        RenderEngine::DirectX12 => level >= FeatureLevel::Level11_0,
+    }
+}
+
+/// Direct3D 9 creates every device type on an adapter, DXGI engines only
+/// hardware ones.
+fn takes_adapter(engine: RenderEngine, driver: DriverType) -> bool {
+    engine == RenderEngine::DirectX9 || driver == DriverType::Hardware
+}
+
+/// Calls `discover` with each attempt in turn until one succeeds, failing with
+/// the last attempt's error.
+#[cfg(any(
+    test,
+    all(
+        windows,
+        any(
+            feature = "directx9",
+            feature = "directx10",
+            feature = "directx11",
+            feature = "directx12"
+        )
+    )
+))]
+pub(crate) fn retry<T>(
+    engine: RenderEngine,
+    attempts: Vec<Attempt>,
+    mut discover: impl FnMut(&Attempt) -> crate::ShroudResult<T>,
+) -> crate::ShroudResult<T> {
+    let mut error = crate::ShroudError::NoDiscoveryAttempt(engine);
+    for attempt in &attempts {
+        match discover(attempt) {
+            Ok(found) => return Ok(found),
+            Err(e) => error = e,
+        }
+    }
+    Err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShroudError;
+    use FeatureLevel::*;
+
+    const NVIDIA: u32 = 0x10de;
+    const INTEL: u32 = 0x8086;
+
+    fn adapter(index: u32, vendor_id: u32) -> AdapterInfo {
+        AdapterInfo {
+            index,
+            luid: Luid {
+                low: 0x1000 + index,
+                high: 0,
+            },
+            vendor_id,
+            device_id: 0,
+            description: format!("Adapter {index}"),
+            software: false,
+        }
+    }
+
+    /// An integrated Intel adapter, two discrete NVIDIA ones and WARP's.
+    fn adapters() -> Vec<AdapterInfo> {
+        vec![
+            adapter(0, INTEL),
+            adapter(1, NVIDIA),
+            adapter(2, NVIDIA),
+            AdapterInfo {
+                software: true,
+                ..adapter(3, 0x1414)
+            },
+        ]
+    }
+
+    fn attempt(driver: DriverType, adapter: Option<u32>, levels: &[FeatureLevel]) -> Attempt {
+        Attempt {
+            driver,
+            adapter,
+            feature_levels: levels.to_vec(),
+        }
+    }
+
+    fn picked(options: &DiscoveryOptions, engine: RenderEngine) -> Vec<Option<u32>> {
+        options
+            .plan(engine, &adapters())
+            .into_iter()
+            .map(|attempt| attempt.adapter)
+            .collect()
+    }
+
+    #[test]
+    fn defaults_reproduce_the_backends() {
+        let options = DiscoveryOptions::new();
+        let plan = |engine| options.plan(engine, &adapters());
+
+        assert_eq!(
+            plan(RenderEngine::DirectX9),
+            [attempt(DriverType::Null, None, &[])]
+        );
+        assert_eq!(
+            plan(RenderEngine::DirectX10),
+            [attempt(DriverType::Hardware, None, &[Level10_1, Level10_0])]
+        );
+        assert_eq!(
+            plan(RenderEngine::DirectX11),
+            [attempt(DriverType::Hardware, None, &[Level10_0, Level11_1])]
+        );
+        assert_eq!(
+            plan(RenderEngine::DirectX12),
+            [attempt(DriverType::Hardware, None, &[Level11_0])]
+        );
+    }
+
+    #[test]
+    fn defaults_need_no_adapters() {
+        for engine in [
+            RenderEngine::DirectX9,
+            RenderEngine::DirectX10,
+            RenderEngine::DirectX11,
+            RenderEngine::DirectX12,
+        ] {
+            let options = DiscoveryOptions::new();
+            assert_eq!(options.plan(engine, &[]), options.plan(engine, &adapters()));
+        }
+    }
+
+    #[test]
+    fn skips_unsupported_drivers() {
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Warp)
+            .driver(DriverType::Reference)
+            .driver(DriverType::Null)
+            .driver(DriverType::Hardware);
+        let drivers = |engine| {
+            options
+                .plan(engine, &adapters())
+                .into_iter()
+                .map(|attempt| attempt.driver)
+                .collect::<Vec<_>>()
+        };
+
+        use DriverType::*;
+        assert_eq!(drivers(RenderEngine::DirectX9), [Reference, Null, Hardware]);
+        assert_eq!(
+            drivers(RenderEngine::DirectX11),
+            [Warp, Reference, Null, Hardware]
+        );
+        assert_eq!(drivers(RenderEngine::DirectX12), [Warp, Hardware]);
+
+        let only_null = DiscoveryOptions::new().driver(Null);
+        assert!(only_null
+            .plan(RenderEngine::DirectX12, &adapters())
+            .is_empty());
+    }
+
+    #[test]
+    fn selects_adapters_by_index_luid_and_vendor() {
+        let by = |selector| DiscoveryOptions::new().adapter(selector);
+
+        assert_eq!(
+            picked(&by(AdapterSelector::Index(2)), RenderEngine::DirectX11),
+            [Some(2)]
+        );
+        assert_eq!(
+            picked(
+                &by(AdapterSelector::Luid(Luid {
+                    low: 0x1001,
+                    high: 0
+                })),
+                RenderEngine::DirectX11
+            ),
+            [Some(1)]
+        );
+        assert_eq!(
+            picked(
+                &by(AdapterSelector::Vendor(NVIDIA)),
+                RenderEngine::DirectX11
+            ),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(
+            picked(&by(AdapterSelector::Default), RenderEngine::DirectX11),
+            [None]
+        );
+    }
+
+    #[test]
+    fn selectors_matching_nothing_leave_no_attempts() {
+        let options = DiscoveryOptions::new()
+            .adapter(AdapterSelector::Index(7))
+            .adapter(AdapterSelector::Vendor(0x1002));
+        assert!(options
+            .plan(RenderEngine::DirectX11, &adapters())
+            .is_empty());
+        assert!(options.plan(RenderEngine::DirectX9, &adapters()).is_empty());
+    }
+
+    #[test]
+    fn selectors_apply_in_order_without_repeats() {
+        let options = DiscoveryOptions::new()
+            .adapter(AdapterSelector::Index(2))
+            .adapter(AdapterSelector::Vendor(NVIDIA))
+            .adapter(AdapterSelector::Default)
+            .adapter(AdapterSelector::Index(0))
+            .adapter(AdapterSelector::Default);
+        assert_eq!(
+            picked(&options, RenderEngine::DirectX11),
+            [Some(2), Some(1), None, Some(0)]
+        );
+    }
+
+    #[test]
+    fn only_hardware_dxgi_devices_take_adapters() {
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Hardware)
+            .driver(DriverType::Warp)
+            .adapter(AdapterSelector::Vendor(NVIDIA));
+        assert_eq!(
+            options.plan(RenderEngine::DirectX12, &adapters()),
+            [
+                attempt(DriverType::Hardware, Some(1), &[Level11_0]),
+                attempt(DriverType::Hardware, Some(2), &[Level11_0]),
+                attempt(DriverType::Warp, None, &[Level11_0]),
+            ]
+        );
+
+        // Direct3D 9 creates its reference and null devices on an adapter too.
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Null)
+            .adapter(AdapterSelector::Index(1));
+        assert_eq!(
+            options.plan(RenderEngine::DirectX9, &adapters()),
+            [attempt(DriverType::Null, Some(1), &[])]
+        );
+    }
+
+    #[test]
+    fn leaves_out_levels_the_engine_has_no_use_for() {
+        let options = DiscoveryOptions::new().feature_levels([
+            Level12_1, Level11_1, Level11_0, Level10_1, Level10_0, Level9_3,
+        ]);
+        let levels = |engine| {
+            options
+                .plan(engine, &adapters())
+                .into_iter()
+                .map(|attempt| attempt.feature_levels)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(levels(RenderEngine::DirectX9), [vec![]]);
+        assert_eq!(
+            levels(RenderEngine::DirectX10),
+            [vec![Level10_1, Level10_0, Level9_3]]
+        );
+        assert_eq!(
+            levels(RenderEngine::DirectX11),
+            [vec![
+                Level12_1, Level11_1, Level11_0, Level10_1, Level10_0, Level9_3
+            ]]
+        );
+
+        let too_old = DiscoveryOptions::new().feature_levels([Level10_1, Level10_0]);
+        assert!(too_old
+            .plan(RenderEngine::DirectX12, &adapters())
+            .is_empty());
+        let too_new = DiscoveryOptions::new().feature_levels([Level11_0]);
+        assert!(too_new
+            .plan(RenderEngine::DirectX10, &adapters())
+            .is_empty());
+    }
+
+    #[test]
+    fn directx12_tries_one_level_at_a_time() {
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Hardware)
+            .driver(DriverType::Warp)
+            .feature_levels([Level12_1, Level12_0, Level11_0])
+            .adapter(AdapterSelector::Index(1));
+        assert_eq!(
+            options.plan(RenderEngine::DirectX12, &adapters()),
+            [
+                attempt(DriverType::Hardware, Some(1), &[Level12_1]),
+                attempt(DriverType::Hardware, Some(1), &[Level12_0]),
+                attempt(DriverType::Hardware, Some(1), &[Level11_0]),
+                attempt(DriverType::Warp, None, &[Level12_1]),
+                attempt(DriverType::Warp, None, &[Level12_0]),
+                attempt(DriverType::Warp, None, &[Level11_0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn repeated_preferences_are_tried_once() {
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Warp)
+            .driver(DriverType::Hardware)
+            .driver(DriverType::Warp)
+            .driver(DriverType::Hardware)
+            .feature_levels([Level12_0, Level11_0, Level12_0])
+            .adapter(AdapterSelector::Index(1))
+            .adapter(AdapterSelector::Luid(Luid {
+                low: 0x1001,
+                high: 0,
+            }));
+        assert_eq!(
+            options.plan(RenderEngine::DirectX12, &adapters()),
+            [
+                attempt(DriverType::Warp, None, &[Level12_0]),
+                attempt(DriverType::Warp, None, &[Level11_0]),
+                attempt(DriverType::Hardware, Some(1), &[Level12_0]),
+                attempt(DriverType::Hardware, Some(1), &[Level11_0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn retries_stop_at_the_first_success() {
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Hardware)
+            .driver(DriverType::Warp)
+            .driver(DriverType::Reference);
+        let mut tried = Vec::new();
+        let found = retry(
+            RenderEngine::DirectX11,
+            options.plan(RenderEngine::DirectX11, &adapters()),
+            |attempt| {
+                tried.push(attempt.driver);
+                match attempt.driver {
+                    DriverType::Hardware => Err(ShroudError::Window),
+                    driver => Ok(driver),
+                }
+            },
+        );
+        assert_eq!(found.unwrap(), DriverType::Warp);
+        assert_eq!(tried, [DriverType::Hardware, DriverType::Warp]);
+    }
+
+    #[test]
+    fn empty_plans_are_reported() {
+        let options = DiscoveryOptions::new().driver(DriverType::Null);
+        let plan = options.plan(RenderEngine::DirectX12, &adapters());
+        let result = retry(RenderEngine::DirectX12, plan, |_| Ok(()));
+        assert!(matches!(
+            result,
+            Err(ShroudError::NoDiscoveryAttempt(RenderEngine::DirectX12))
+        ));
+    }
+
+    #[cfg(feature = "directx12")]
+    #[test]
+    fn retries_report_the_last_swap_chain_failure() {
+        use crate::HRESULT;
+
+        const E_INVALIDARG: HRESULT = HRESULT(0x8007_0057_u32 as i32);
+        const DXGI_ERROR_UNSUPPORTED: HRESULT = HRESULT(0x887a_0004_u32 as i32);
+
+        let options = DiscoveryOptions::new()
+            .driver(DriverType::Hardware)
+            .driver(DriverType::Warp)
+            .feature_levels([Level12_0, Level11_0]);
+        let plan = options.plan(RenderEngine::DirectX12, &adapters());
+        let mut tried = Vec::new();
+        let result = retry(RenderEngine::DirectX12, plan.clone(), |attempt| {
+            tried.push(attempt.clone());
+            Err::<(), _>(ShroudError::DirectX12CreateSwapchain(
+                match attempt.driver {
+                    DriverType::Warp => DXGI_ERROR_UNSUPPORTED,
+                    _ => E_INVALIDARG,
+                },
+            ))
+        });
+        assert_eq!(tried, plan);
+        assert!(matches!(
+            result,
+            Err(ShroudError::DirectX12CreateSwapchain(code)) if code == DXGI_ERROR_UNSUPPORTED
+        ));
+    }
+}
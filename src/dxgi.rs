@@ -0,0 +1,267 @@
+#[cfg(windows)]
+use windows::core::Interface;
+#[cfg(windows)]
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIAdapter1, IDXGIAdapter2, IDXGIAdapter3, IDXGIAdapter4, IDXGIFactory1,
+    IDXGIFactory2, IDXGIFactory3, IDXGIFactory4, IDXGIFactory5, IDXGIFactory6, IDXGIFactory7,
+    IDXGIOutput1, IDXGIOutput2, IDXGIOutput3, IDXGIOutput4, IDXGIOutput5, IDXGIOutput6,
+    IDXGISwapChain1, IDXGISwapChain2, IDXGISwapChain3, IDXGISwapChain4, DXGI_ADAPTER_FLAG_SOFTWARE,
+};
+
+#[cfg(windows)]
+use crate::{
+    discovery::{AdapterInfo, Luid},
+    vtable,
+};
+use crate::{method_table::versioned_vmt, MethodTable, ShroudError, ShroudResult, Snapshot};
+
+mod methods;
+pub use methods::*;
+
+versioned_vmt! {
+    /// Method table of a swap chain at the highest `IDXGISwapChain` version it supports.
+    ///
+    /// Every version extends the previous one, so the table of the highest version
+    /// covers the methods of all lower versions as well.
+    pub enum DxgiSwapchainVmt {
+        Swapchain4(4, DxgiSwapchain4Methods, IDXGISwapChain4),
+        Swapchain3(3, DxgiSwapchain3Methods, IDXGISwapChain3),
+        Swapchain2(2, DxgiSwapchain2Methods, IDXGISwapChain2),
+        Swapchain1(1, DxgiSwapchain1Methods, IDXGISwapChain1),
+    }
+}
+
+versioned_vmt! {
+    /// Method table of a factory at the highest `IDXGIFactory` version it supports.
+    pub enum DxgiFactoryVmt {
+        Factory7(7, DxgiFactory7Methods, IDXGIFactory7),
+        Factory6(6, DxgiFactory6Methods, IDXGIFactory6),
+        Factory5(5, DxgiFactory5Methods, IDXGIFactory5),
+        Factory4(4, DxgiFactory4Methods, IDXGIFactory4),
+        Factory3(3, DxgiFactory3Methods, IDXGIFactory3),
+        Factory2(2, DxgiFactory2Methods, IDXGIFactory2),
+        Factory1(1, DxgiFactory1Methods, IDXGIFactory1),
+    }
+}
+
+versioned_vmt! {
+    /// Method table of an adapter at the highest `IDXGIAdapter` version it supports.
+    pub enum DxgiAdapterVmt {
+        Adapter4(4, DxgiAdapter4Methods, IDXGIAdapter4),
+        Adapter3(3, DxgiAdapter3Methods, IDXGIAdapter3),
+        Adapter2(2, DxgiAdapter2Methods, IDXGIAdapter2),
+        Adapter1(1, DxgiAdapter1Methods, IDXGIAdapter1),
+    }
+}
+
+versioned_vmt! {
+    /// Method table of an output at the highest `IDXGIOutput` version it supports.
+    pub enum DxgiOutputVmt {
+        Output6(6, DxgiOutput6Methods, IDXGIOutput6),
+        Output5(5, DxgiOutput5Methods, IDXGIOutput5),
+        Output4(4, DxgiOutput4Methods, IDXGIOutput4),
+        Output3(3, DxgiOutput3Methods, IDXGIOutput3),
+        Output2(2, DxgiOutput2Methods, IDXGIOutput2),
+        Output1(1, DxgiOutput1Methods, IDXGIOutput1),
+    }
+}
+
+/// Factory, adapter and output tables, discovered without creating a D3D device.
+pub struct DxgiMethods {
+    factory_vmt: MethodTable<DxgiFactoryMethods>,
+    latest_factory_vmt: Option<DxgiFactoryVmt>,
+    adapter_vmt: MethodTable<DxgiAdapterMethods>,
+    latest_adapter_vmt: Option<DxgiAdapterVmt>,
+    output_vmt: Option<MethodTable<DxgiOutputMethods>>,
+    latest_output_vmt: Option<DxgiOutputVmt>,
+}
+
+impl DxgiMethods {
+    pub fn factory_vmt(&self) -> &MethodTable<DxgiFactoryMethods> {
+        &self.factory_vmt
+    }
+
+    /// The factory's table at the highest `IDXGIFactory` version it supports,
+    /// e.g. `IDXGIFactory2` for `CreateSwapChainForHwnd`.
+    pub fn latest_factory_vmt(&self) -> Option<&DxgiFactoryVmt> {
+        self.latest_factory_vmt.as_ref()
+    }
+
+    pub fn adapter_vmt(&self) -> &MethodTable<DxgiAdapterMethods> {
+        &self.adapter_vmt
+    }
+
+    pub fn latest_adapter_vmt(&self) -> Option<&DxgiAdapterVmt> {
+        self.latest_adapter_vmt.as_ref()
+    }
+
+    /// Table of the adapter's first output, `None` when no display is attached.
+    pub fn output_vmt(&self) -> Option<&MethodTable<DxgiOutputMethods>> {
+        self.output_vmt.as_ref()
+    }
+
+    pub fn latest_output_vmt(&self) -> Option<&DxgiOutputVmt> {
+        self.latest_output_vmt.as_ref()
+    }
+}
+
+impl DxgiMethods {
+    /// Records every table into `snapshot`, typically the snapshot of the render
+    /// engine the tables were discovered alongside.
+    pub fn push_to(&self, snapshot: &mut Snapshot) {
+        snapshot.push(&self.factory_vmt);
+        if let Some(vmt) = &self.latest_factory_vmt {
+            vmt.push_to(snapshot);
+        }
+        snapshot.push(&self.adapter_vmt);
+        if let Some(vmt) = &self.latest_adapter_vmt {
+            vmt.push_to(snapshot);
+        }
+        if let Some(vmt) = &self.output_vmt {
+            snapshot.push(vmt);
+        }
+        if let Some(vmt) = &self.latest_output_vmt {
+            vmt.push_to(snapshot);
+        }
+    }
+}
+
+impl TryFrom<&Snapshot> for DxgiMethods {
+    type Error = ShroudError;
+
+    fn try_from(snapshot: &Snapshot) -> ShroudResult<Self> {
+        Ok(Self {
+            factory_vmt: snapshot.required_table()?,
+            latest_factory_vmt: DxgiFactoryVmt::from_snapshot(snapshot)?,
+            adapter_vmt: snapshot.required_table()?,
+            latest_adapter_vmt: DxgiAdapterVmt::from_snapshot(snapshot)?,
+            output_vmt: snapshot.table()?,
+            latest_output_vmt: DxgiOutputVmt::from_snapshot(snapshot)?,
+        })
+    }
+}
+
+impl std::fmt::Debug for DxgiMethods {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "DXGI Method Table")?;
+
+        writeln!(f, "Factory Virtual Method Table")?;
+        write!(f, "{:?}", self.factory_vmt)?;
+        writeln!(f)?;
+
+        if let Some(vmt) = &self.latest_factory_vmt {
+            writeln!(f, "Factory{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Adapter Virtual Method Table")?;
+        write!(f, "{:?}", self.adapter_vmt)?;
+        writeln!(f)?;
+
+        if let Some(vmt) = &self.latest_adapter_vmt {
+            writeln!(f, "Adapter{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
+        }
+
+        if let Some(vmt) = &self.output_vmt {
+            writeln!(f, "Output Virtual Method Table")?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
+        }
+
+        if let Some(vmt) = &self.latest_output_vmt {
+            writeln!(f, "Output{} Virtual Method Table", vmt.version())?;
+            write!(f, "{:?}", vmt)?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Discovers the factory, adapter and output tables from a `CreateDXGIFactory1`
+/// factory and its first adapter. No window or D3D device is needed.
+#[cfg(windows)]
+pub fn methods() -> ShroudResult<DxgiMethods> {
+    let factory: IDXGIFactory1 =
+        unsafe { CreateDXGIFactory1().map_err(|e| ShroudError::DxgiCreateFactory(e.code()))? };
+    let factory_vmt = unsafe { vtable::read::<DxgiFactoryMethods>(factory.as_raw())? };
+    let latest_factory_vmt = DxgiFactoryVmt::query(&factory)?;
+
+    let adapter = unsafe {
+        factory
+            .EnumAdapters1(0)
+            .map_err(|e| ShroudError::DxgiEnumAdapters(e.code()))?
+    };
+    let adapter_vmt = unsafe { vtable::read::<DxgiAdapterMethods>(adapter.as_raw())? };
+    let latest_adapter_vmt = DxgiAdapterVmt::query(&adapter)?;
+
+    // Render-only adapters and headless sessions have no outputs.
+    let (output_vmt, latest_output_vmt) = match unsafe { adapter.EnumOutputs(0) } {
+        Ok(output) => (
+            Some(unsafe { vtable::read::<DxgiOutputMethods>(output.as_raw())? }),
+            DxgiOutputVmt::query(&output)?,
+        ),
+        Err(_) => (None, None),
+    };
+
+    Ok(DxgiMethods {
+        factory_vmt,
+        latest_factory_vmt,
+        adapter_vmt,
+        latest_adapter_vmt,
+        output_vmt,
+        latest_output_vmt,
+    })
+}
+
+/// Adapters DXGI enumerates, which [`crate::discovery::AdapterSelector`]s pick
+/// from for DirectX10 and up.
+#[cfg(windows)]
+pub fn adapters() -> ShroudResult<Vec<AdapterInfo>> {
+    let factory: IDXGIFactory1 =
+        unsafe { CreateDXGIFactory1().map_err(|e| ShroudError::DxgiCreateFactory(e.code()))? };
+
+    let mut adapters = Vec::new();
+    for index in 0.. {
+        let Ok(adapter) = (unsafe { factory.EnumAdapters1(index) }) else {
+            break;
+        };
+        let desc =
+            unsafe { adapter.GetDesc1() }.map_err(|e| ShroudError::DxgiEnumAdapters(e.code()))?;
+        let description_len = desc
+            .Description
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(desc.Description.len());
+        adapters.push(AdapterInfo {
+            index,
+            luid: Luid {
+                low: desc.AdapterLuid.LowPart,
+                high: desc.AdapterLuid.HighPart,
+            },
+            vendor_id: desc.VendorId,
+            device_id: desc.DeviceId,
+            description: String::from_utf16_lossy(&desc.Description[..description_len]),
+            software: desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32 != 0,
+        });
+    }
+    Ok(adapters)
+}
+
+/// The adapter `attempt` creates its device on, `None` for the default one.
+#[cfg(all(windows, any(feature = "directx10", feature = "directx11")))]
+pub(crate) fn attempt_adapter(
+    attempt: &crate::discovery::Attempt,
+) -> ShroudResult<Option<windows::Win32::Graphics::Dxgi::IDXGIAdapter>> {
+    let Some(index) = attempt.adapter else {
+        return Ok(None);
+    };
+    let factory: IDXGIFactory1 =
+        unsafe { CreateDXGIFactory1().map_err(|e| ShroudError::DxgiCreateFactory(e.code()))? };
+    let adapter = unsafe { factory.EnumAdapters(index) }
+        .map_err(|e| ShroudError::DxgiEnumAdapters(e.code()))?;
+    Ok(Some(adapter))
+}
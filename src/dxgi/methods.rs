@@ -0,0 +1,2434 @@
+// Generated by `cargo xtask codegen` from xtask/winmd/Windows.Win32.winmd, do not edit.
+
+use strum_macros::{EnumCount, EnumIter};
+
+#[cfg(windows)]
+use crate::vtable::assert_slot;
+use crate::InterfaceMethods;
+
+/// `IDXGISwapChain1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiSwapchain1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+    GetDesc1,
+    GetFullscreenDesc,
+    GetHwnd,
+    GetCoreWindow,
+    Present1,
+    IsTemporaryMonoSupported,
+    GetRestrictToOutput,
+    SetBackgroundColor,
+    GetBackgroundColor,
+    SetRotation,
+    GetRotation,
+}
+
+impl InterfaceMethods for DxgiSwapchain1Methods {
+    const INTERFACE: &'static str = "IDXGISwapChain1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain1_Vtbl as Vtbl;
+    use DxgiSwapchain1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.Present => M::Present);
+    assert_slot!(Vtbl, base__.GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, base__.SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, base__.GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, base__.ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, base__.GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.GetLastPresentCount => M::GetLastPresentCount);
+    assert_slot!(Vtbl, GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, GetFullscreenDesc => M::GetFullscreenDesc);
+    assert_slot!(Vtbl, GetHwnd => M::GetHwnd);
+    assert_slot!(Vtbl, GetCoreWindow => M::GetCoreWindow);
+    assert_slot!(Vtbl, Present1 => M::Present1);
+    assert_slot!(Vtbl, IsTemporaryMonoSupported => M::IsTemporaryMonoSupported);
+    assert_slot!(Vtbl, GetRestrictToOutput => M::GetRestrictToOutput);
+    assert_slot!(Vtbl, SetBackgroundColor => M::SetBackgroundColor);
+    assert_slot!(Vtbl, GetBackgroundColor => M::GetBackgroundColor);
+    assert_slot!(Vtbl, SetRotation => M::SetRotation);
+    assert_slot!(Vtbl, GetRotation => M::GetRotation);
+};
+
+/// `IDXGISwapChain2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiSwapchain2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+    GetDesc1,
+    GetFullscreenDesc,
+    GetHwnd,
+    GetCoreWindow,
+    Present1,
+    IsTemporaryMonoSupported,
+    GetRestrictToOutput,
+    SetBackgroundColor,
+    GetBackgroundColor,
+    SetRotation,
+    GetRotation,
+    SetSourceSize,
+    GetSourceSize,
+    SetMaximumFrameLatency,
+    GetMaximumFrameLatency,
+    GetFrameLatencyWaitableObject,
+    SetMatrixTransform,
+    GetMatrixTransform,
+}
+
+impl InterfaceMethods for DxgiSwapchain2Methods {
+    const INTERFACE: &'static str = "IDXGISwapChain2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain2_Vtbl as Vtbl;
+    use DxgiSwapchain2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.Present => M::Present);
+    assert_slot!(Vtbl, base__.base__.GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, base__.base__.SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, base__.base__.GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, base__.base__.ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, base__.base__.GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.GetLastPresentCount => M::GetLastPresentCount);
+    assert_slot!(Vtbl, base__.GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, base__.GetFullscreenDesc => M::GetFullscreenDesc);
+    assert_slot!(Vtbl, base__.GetHwnd => M::GetHwnd);
+    assert_slot!(Vtbl, base__.GetCoreWindow => M::GetCoreWindow);
+    assert_slot!(Vtbl, base__.Present1 => M::Present1);
+    assert_slot!(Vtbl, base__.IsTemporaryMonoSupported => M::IsTemporaryMonoSupported);
+    assert_slot!(Vtbl, base__.GetRestrictToOutput => M::GetRestrictToOutput);
+    assert_slot!(Vtbl, base__.SetBackgroundColor => M::SetBackgroundColor);
+    assert_slot!(Vtbl, base__.GetBackgroundColor => M::GetBackgroundColor);
+    assert_slot!(Vtbl, base__.SetRotation => M::SetRotation);
+    assert_slot!(Vtbl, base__.GetRotation => M::GetRotation);
+    assert_slot!(Vtbl, SetSourceSize => M::SetSourceSize);
+    assert_slot!(Vtbl, GetSourceSize => M::GetSourceSize);
+    assert_slot!(Vtbl, SetMaximumFrameLatency => M::SetMaximumFrameLatency);
+    assert_slot!(Vtbl, GetMaximumFrameLatency => M::GetMaximumFrameLatency);
+    assert_slot!(Vtbl, GetFrameLatencyWaitableObject => M::GetFrameLatencyWaitableObject);
+    assert_slot!(Vtbl, SetMatrixTransform => M::SetMatrixTransform);
+    assert_slot!(Vtbl, GetMatrixTransform => M::GetMatrixTransform);
+};
+
+/// `IDXGISwapChain3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiSwapchain3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+    GetDesc1,
+    GetFullscreenDesc,
+    GetHwnd,
+    GetCoreWindow,
+    Present1,
+    IsTemporaryMonoSupported,
+    GetRestrictToOutput,
+    SetBackgroundColor,
+    GetBackgroundColor,
+    SetRotation,
+    GetRotation,
+    SetSourceSize,
+    GetSourceSize,
+    SetMaximumFrameLatency,
+    GetMaximumFrameLatency,
+    GetFrameLatencyWaitableObject,
+    SetMatrixTransform,
+    GetMatrixTransform,
+    GetCurrentBackBufferIndex,
+    CheckColorSpaceSupport,
+    SetColorSpace1,
+    ResizeBuffers1,
+}
+
+impl InterfaceMethods for DxgiSwapchain3Methods {
+    const INTERFACE: &'static str = "IDXGISwapChain3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain3_Vtbl as Vtbl;
+    use DxgiSwapchain3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.Present => M::Present);
+    assert_slot!(Vtbl, base__.base__.base__.GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, base__.base__.base__.GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, base__.base__.base__.GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, base__.base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.base__.GetLastPresentCount => M::GetLastPresentCount);
+    assert_slot!(Vtbl, base__.base__.GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, base__.base__.GetFullscreenDesc => M::GetFullscreenDesc);
+    assert_slot!(Vtbl, base__.base__.GetHwnd => M::GetHwnd);
+    assert_slot!(Vtbl, base__.base__.GetCoreWindow => M::GetCoreWindow);
+    assert_slot!(Vtbl, base__.base__.Present1 => M::Present1);
+    assert_slot!(Vtbl, base__.base__.IsTemporaryMonoSupported => M::IsTemporaryMonoSupported);
+    assert_slot!(Vtbl, base__.base__.GetRestrictToOutput => M::GetRestrictToOutput);
+    assert_slot!(Vtbl, base__.base__.SetBackgroundColor => M::SetBackgroundColor);
+    assert_slot!(Vtbl, base__.base__.GetBackgroundColor => M::GetBackgroundColor);
+    assert_slot!(Vtbl, base__.base__.SetRotation => M::SetRotation);
+    assert_slot!(Vtbl, base__.base__.GetRotation => M::GetRotation);
+    assert_slot!(Vtbl, base__.SetSourceSize => M::SetSourceSize);
+    assert_slot!(Vtbl, base__.GetSourceSize => M::GetSourceSize);
+    assert_slot!(Vtbl, base__.SetMaximumFrameLatency => M::SetMaximumFrameLatency);
+    assert_slot!(Vtbl, base__.GetMaximumFrameLatency => M::GetMaximumFrameLatency);
+    assert_slot!(Vtbl, base__.GetFrameLatencyWaitableObject => M::GetFrameLatencyWaitableObject);
+    assert_slot!(Vtbl, base__.SetMatrixTransform => M::SetMatrixTransform);
+    assert_slot!(Vtbl, base__.GetMatrixTransform => M::GetMatrixTransform);
+    assert_slot!(Vtbl, GetCurrentBackBufferIndex => M::GetCurrentBackBufferIndex);
+    assert_slot!(Vtbl, CheckColorSpaceSupport => M::CheckColorSpaceSupport);
+    assert_slot!(Vtbl, SetColorSpace1 => M::SetColorSpace1);
+    assert_slot!(Vtbl, ResizeBuffers1 => M::ResizeBuffers1);
+};
+
+/// `IDXGISwapChain4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiSwapchain4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDevice,
+    Present,
+    GetBuffer,
+    SetFullscreenState,
+    GetFullscreenState,
+    GetDesc,
+    ResizeBuffers,
+    ResizeTarget,
+    GetContainingOutput,
+    GetFrameStatistics,
+    GetLastPresentCount,
+    GetDesc1,
+    GetFullscreenDesc,
+    GetHwnd,
+    GetCoreWindow,
+    Present1,
+    IsTemporaryMonoSupported,
+    GetRestrictToOutput,
+    SetBackgroundColor,
+    GetBackgroundColor,
+    SetRotation,
+    GetRotation,
+    SetSourceSize,
+    GetSourceSize,
+    SetMaximumFrameLatency,
+    GetMaximumFrameLatency,
+    GetFrameLatencyWaitableObject,
+    SetMatrixTransform,
+    GetMatrixTransform,
+    GetCurrentBackBufferIndex,
+    CheckColorSpaceSupport,
+    SetColorSpace1,
+    ResizeBuffers1,
+    SetHDRMetaData,
+}
+
+impl InterfaceMethods for DxgiSwapchain4Methods {
+    const INTERFACE: &'static str = "IDXGISwapChain4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGISwapChain4_Vtbl as Vtbl;
+    use DxgiSwapchain4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDevice => M::GetDevice);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Present => M::Present);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetBuffer => M::GetBuffer);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetFullscreenState => M::SetFullscreenState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetFullscreenState => M::GetFullscreenState);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResizeBuffers => M::ResizeBuffers);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ResizeTarget => M::ResizeTarget);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetContainingOutput => M::GetContainingOutput);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetLastPresentCount => M::GetLastPresentCount);
+    assert_slot!(Vtbl, base__.base__.base__.GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, base__.base__.base__.GetFullscreenDesc => M::GetFullscreenDesc);
+    assert_slot!(Vtbl, base__.base__.base__.GetHwnd => M::GetHwnd);
+    assert_slot!(Vtbl, base__.base__.base__.GetCoreWindow => M::GetCoreWindow);
+    assert_slot!(Vtbl, base__.base__.base__.Present1 => M::Present1);
+    assert_slot!(Vtbl, base__.base__.base__.IsTemporaryMonoSupported => M::IsTemporaryMonoSupported);
+    assert_slot!(Vtbl, base__.base__.base__.GetRestrictToOutput => M::GetRestrictToOutput);
+    assert_slot!(Vtbl, base__.base__.base__.SetBackgroundColor => M::SetBackgroundColor);
+    assert_slot!(Vtbl, base__.base__.base__.GetBackgroundColor => M::GetBackgroundColor);
+    assert_slot!(Vtbl, base__.base__.base__.SetRotation => M::SetRotation);
+    assert_slot!(Vtbl, base__.base__.base__.GetRotation => M::GetRotation);
+    assert_slot!(Vtbl, base__.base__.SetSourceSize => M::SetSourceSize);
+    assert_slot!(Vtbl, base__.base__.GetSourceSize => M::GetSourceSize);
+    assert_slot!(Vtbl, base__.base__.SetMaximumFrameLatency => M::SetMaximumFrameLatency);
+    assert_slot!(Vtbl, base__.base__.GetMaximumFrameLatency => M::GetMaximumFrameLatency);
+    assert_slot!(Vtbl, base__.base__.GetFrameLatencyWaitableObject => M::GetFrameLatencyWaitableObject);
+    assert_slot!(Vtbl, base__.base__.SetMatrixTransform => M::SetMatrixTransform);
+    assert_slot!(Vtbl, base__.base__.GetMatrixTransform => M::GetMatrixTransform);
+    assert_slot!(Vtbl, base__.GetCurrentBackBufferIndex => M::GetCurrentBackBufferIndex);
+    assert_slot!(Vtbl, base__.CheckColorSpaceSupport => M::CheckColorSpaceSupport);
+    assert_slot!(Vtbl, base__.SetColorSpace1 => M::SetColorSpace1);
+    assert_slot!(Vtbl, base__.ResizeBuffers1 => M::ResizeBuffers1);
+    assert_slot!(Vtbl, SetHDRMetaData => M::SetHDRMetaData);
+};
+
+/// `IDXGIFactory` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactoryMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+}
+
+impl InterfaceMethods for DxgiFactoryMethods {
+    const INTERFACE: &'static str = "IDXGIFactory";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory_Vtbl as Vtbl;
+    use DxgiFactoryMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+};
+
+/// `IDXGIFactory1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+}
+
+impl InterfaceMethods for DxgiFactory1Methods {
+    const INTERFACE: &'static str = "IDXGIFactory1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory1_Vtbl as Vtbl;
+    use DxgiFactory1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, IsCurrent => M::IsCurrent);
+};
+
+/// `IDXGIFactory2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+    IsWindowedStereoEnabled,
+    CreateSwapChainForHwnd,
+    CreateSwapChainForCoreWindow,
+    GetSharedResourceAdapterLuid,
+    RegisterStereoStatusWindow,
+    RegisterStereoStatusEvent,
+    UnregisterStereoStatus,
+    RegisterOcclusionStatusWindow,
+    RegisterOcclusionStatusEvent,
+    UnregisterOcclusionStatus,
+    CreateSwapChainForComposition,
+}
+
+impl InterfaceMethods for DxgiFactory2Methods {
+    const INTERFACE: &'static str = "IDXGIFactory2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory2_Vtbl as Vtbl;
+    use DxgiFactory2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, base__.EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, base__.IsCurrent => M::IsCurrent);
+    assert_slot!(Vtbl, IsWindowedStereoEnabled => M::IsWindowedStereoEnabled);
+    assert_slot!(Vtbl, CreateSwapChainForHwnd => M::CreateSwapChainForHwnd);
+    assert_slot!(Vtbl, CreateSwapChainForCoreWindow => M::CreateSwapChainForCoreWindow);
+    assert_slot!(Vtbl, GetSharedResourceAdapterLuid => M::GetSharedResourceAdapterLuid);
+    assert_slot!(Vtbl, RegisterStereoStatusWindow => M::RegisterStereoStatusWindow);
+    assert_slot!(Vtbl, RegisterStereoStatusEvent => M::RegisterStereoStatusEvent);
+    assert_slot!(Vtbl, UnregisterStereoStatus => M::UnregisterStereoStatus);
+    assert_slot!(Vtbl, RegisterOcclusionStatusWindow => M::RegisterOcclusionStatusWindow);
+    assert_slot!(Vtbl, RegisterOcclusionStatusEvent => M::RegisterOcclusionStatusEvent);
+    assert_slot!(Vtbl, UnregisterOcclusionStatus => M::UnregisterOcclusionStatus);
+    assert_slot!(Vtbl, CreateSwapChainForComposition => M::CreateSwapChainForComposition);
+};
+
+/// `IDXGIFactory3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+    IsWindowedStereoEnabled,
+    CreateSwapChainForHwnd,
+    CreateSwapChainForCoreWindow,
+    GetSharedResourceAdapterLuid,
+    RegisterStereoStatusWindow,
+    RegisterStereoStatusEvent,
+    UnregisterStereoStatus,
+    RegisterOcclusionStatusWindow,
+    RegisterOcclusionStatusEvent,
+    UnregisterOcclusionStatus,
+    CreateSwapChainForComposition,
+    GetCreationFlags,
+}
+
+impl InterfaceMethods for DxgiFactory3Methods {
+    const INTERFACE: &'static str = "IDXGIFactory3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory3_Vtbl as Vtbl;
+    use DxgiFactory3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.base__.base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, base__.base__.EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, base__.base__.IsCurrent => M::IsCurrent);
+    assert_slot!(Vtbl, base__.IsWindowedStereoEnabled => M::IsWindowedStereoEnabled);
+    assert_slot!(Vtbl, base__.CreateSwapChainForHwnd => M::CreateSwapChainForHwnd);
+    assert_slot!(Vtbl, base__.CreateSwapChainForCoreWindow => M::CreateSwapChainForCoreWindow);
+    assert_slot!(Vtbl, base__.GetSharedResourceAdapterLuid => M::GetSharedResourceAdapterLuid);
+    assert_slot!(Vtbl, base__.RegisterStereoStatusWindow => M::RegisterStereoStatusWindow);
+    assert_slot!(Vtbl, base__.RegisterStereoStatusEvent => M::RegisterStereoStatusEvent);
+    assert_slot!(Vtbl, base__.UnregisterStereoStatus => M::UnregisterStereoStatus);
+    assert_slot!(Vtbl, base__.RegisterOcclusionStatusWindow => M::RegisterOcclusionStatusWindow);
+    assert_slot!(Vtbl, base__.RegisterOcclusionStatusEvent => M::RegisterOcclusionStatusEvent);
+    assert_slot!(Vtbl, base__.UnregisterOcclusionStatus => M::UnregisterOcclusionStatus);
+    assert_slot!(Vtbl, base__.CreateSwapChainForComposition => M::CreateSwapChainForComposition);
+    assert_slot!(Vtbl, GetCreationFlags => M::GetCreationFlags);
+};
+
+/// `IDXGIFactory4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+    IsWindowedStereoEnabled,
+    CreateSwapChainForHwnd,
+    CreateSwapChainForCoreWindow,
+    GetSharedResourceAdapterLuid,
+    RegisterStereoStatusWindow,
+    RegisterStereoStatusEvent,
+    UnregisterStereoStatus,
+    RegisterOcclusionStatusWindow,
+    RegisterOcclusionStatusEvent,
+    UnregisterOcclusionStatus,
+    CreateSwapChainForComposition,
+    GetCreationFlags,
+    EnumAdapterByLuid,
+    EnumWarpAdapter,
+}
+
+impl InterfaceMethods for DxgiFactory4Methods {
+    const INTERFACE: &'static str = "IDXGIFactory4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory4_Vtbl as Vtbl;
+    use DxgiFactory4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.base__.base__.base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, base__.base__.base__.EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, base__.base__.base__.IsCurrent => M::IsCurrent);
+    assert_slot!(Vtbl, base__.base__.IsWindowedStereoEnabled => M::IsWindowedStereoEnabled);
+    assert_slot!(Vtbl, base__.base__.CreateSwapChainForHwnd => M::CreateSwapChainForHwnd);
+    assert_slot!(Vtbl, base__.base__.CreateSwapChainForCoreWindow => M::CreateSwapChainForCoreWindow);
+    assert_slot!(Vtbl, base__.base__.GetSharedResourceAdapterLuid => M::GetSharedResourceAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.RegisterStereoStatusWindow => M::RegisterStereoStatusWindow);
+    assert_slot!(Vtbl, base__.base__.RegisterStereoStatusEvent => M::RegisterStereoStatusEvent);
+    assert_slot!(Vtbl, base__.base__.UnregisterStereoStatus => M::UnregisterStereoStatus);
+    assert_slot!(Vtbl, base__.base__.RegisterOcclusionStatusWindow => M::RegisterOcclusionStatusWindow);
+    assert_slot!(Vtbl, base__.base__.RegisterOcclusionStatusEvent => M::RegisterOcclusionStatusEvent);
+    assert_slot!(Vtbl, base__.base__.UnregisterOcclusionStatus => M::UnregisterOcclusionStatus);
+    assert_slot!(Vtbl, base__.base__.CreateSwapChainForComposition => M::CreateSwapChainForComposition);
+    assert_slot!(Vtbl, base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, EnumAdapterByLuid => M::EnumAdapterByLuid);
+    assert_slot!(Vtbl, EnumWarpAdapter => M::EnumWarpAdapter);
+};
+
+/// `IDXGIFactory5` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory5Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+    IsWindowedStereoEnabled,
+    CreateSwapChainForHwnd,
+    CreateSwapChainForCoreWindow,
+    GetSharedResourceAdapterLuid,
+    RegisterStereoStatusWindow,
+    RegisterStereoStatusEvent,
+    UnregisterStereoStatus,
+    RegisterOcclusionStatusWindow,
+    RegisterOcclusionStatusEvent,
+    UnregisterOcclusionStatus,
+    CreateSwapChainForComposition,
+    GetCreationFlags,
+    EnumAdapterByLuid,
+    EnumWarpAdapter,
+    CheckFeatureSupport,
+}
+
+impl InterfaceMethods for DxgiFactory5Methods {
+    const INTERFACE: &'static str = "IDXGIFactory5";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory5_Vtbl as Vtbl;
+    use DxgiFactory5Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IsCurrent => M::IsCurrent);
+    assert_slot!(Vtbl, base__.base__.base__.IsWindowedStereoEnabled => M::IsWindowedStereoEnabled);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSwapChainForHwnd => M::CreateSwapChainForHwnd);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSwapChainForCoreWindow => M::CreateSwapChainForCoreWindow);
+    assert_slot!(Vtbl, base__.base__.base__.GetSharedResourceAdapterLuid => M::GetSharedResourceAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.RegisterStereoStatusWindow => M::RegisterStereoStatusWindow);
+    assert_slot!(Vtbl, base__.base__.base__.RegisterStereoStatusEvent => M::RegisterStereoStatusEvent);
+    assert_slot!(Vtbl, base__.base__.base__.UnregisterStereoStatus => M::UnregisterStereoStatus);
+    assert_slot!(Vtbl, base__.base__.base__.RegisterOcclusionStatusWindow => M::RegisterOcclusionStatusWindow);
+    assert_slot!(Vtbl, base__.base__.base__.RegisterOcclusionStatusEvent => M::RegisterOcclusionStatusEvent);
+    assert_slot!(Vtbl, base__.base__.base__.UnregisterOcclusionStatus => M::UnregisterOcclusionStatus);
+    assert_slot!(Vtbl, base__.base__.base__.CreateSwapChainForComposition => M::CreateSwapChainForComposition);
+    assert_slot!(Vtbl, base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.EnumAdapterByLuid => M::EnumAdapterByLuid);
+    assert_slot!(Vtbl, base__.EnumWarpAdapter => M::EnumWarpAdapter);
+    assert_slot!(Vtbl, CheckFeatureSupport => M::CheckFeatureSupport);
+};
+
+/// `IDXGIFactory6` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory6Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+    IsWindowedStereoEnabled,
+    CreateSwapChainForHwnd,
+    CreateSwapChainForCoreWindow,
+    GetSharedResourceAdapterLuid,
+    RegisterStereoStatusWindow,
+    RegisterStereoStatusEvent,
+    UnregisterStereoStatus,
+    RegisterOcclusionStatusWindow,
+    RegisterOcclusionStatusEvent,
+    UnregisterOcclusionStatus,
+    CreateSwapChainForComposition,
+    GetCreationFlags,
+    EnumAdapterByLuid,
+    EnumWarpAdapter,
+    CheckFeatureSupport,
+    EnumAdapterByGpuPreference,
+}
+
+impl InterfaceMethods for DxgiFactory6Methods {
+    const INTERFACE: &'static str = "IDXGIFactory6";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory6_Vtbl as Vtbl;
+    use DxgiFactory6Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.IsCurrent => M::IsCurrent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.IsWindowedStereoEnabled => M::IsWindowedStereoEnabled);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSwapChainForHwnd => M::CreateSwapChainForHwnd);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSwapChainForCoreWindow => M::CreateSwapChainForCoreWindow);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetSharedResourceAdapterLuid => M::GetSharedResourceAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RegisterStereoStatusWindow => M::RegisterStereoStatusWindow);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RegisterStereoStatusEvent => M::RegisterStereoStatusEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.UnregisterStereoStatus => M::UnregisterStereoStatus);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RegisterOcclusionStatusWindow => M::RegisterOcclusionStatusWindow);
+    assert_slot!(Vtbl, base__.base__.base__.base__.RegisterOcclusionStatusEvent => M::RegisterOcclusionStatusEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.UnregisterOcclusionStatus => M::UnregisterOcclusionStatus);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CreateSwapChainForComposition => M::CreateSwapChainForComposition);
+    assert_slot!(Vtbl, base__.base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.base__.EnumAdapterByLuid => M::EnumAdapterByLuid);
+    assert_slot!(Vtbl, base__.base__.EnumWarpAdapter => M::EnumWarpAdapter);
+    assert_slot!(Vtbl, base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, EnumAdapterByGpuPreference => M::EnumAdapterByGpuPreference);
+};
+
+/// `IDXGIFactory7` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiFactory7Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumAdapters,
+    MakeWindowAssociation,
+    GetWindowAssociation,
+    CreateSwapChain,
+    CreateSoftwareAdapter,
+    EnumAdapters1,
+    IsCurrent,
+    IsWindowedStereoEnabled,
+    CreateSwapChainForHwnd,
+    CreateSwapChainForCoreWindow,
+    GetSharedResourceAdapterLuid,
+    RegisterStereoStatusWindow,
+    RegisterStereoStatusEvent,
+    UnregisterStereoStatus,
+    RegisterOcclusionStatusWindow,
+    RegisterOcclusionStatusEvent,
+    UnregisterOcclusionStatus,
+    CreateSwapChainForComposition,
+    GetCreationFlags,
+    EnumAdapterByLuid,
+    EnumWarpAdapter,
+    CheckFeatureSupport,
+    EnumAdapterByGpuPreference,
+    RegisterAdaptersChangedEvent,
+    UnregisterAdaptersChangedEvent,
+}
+
+impl InterfaceMethods for DxgiFactory7Methods {
+    const INTERFACE: &'static str = "IDXGIFactory7";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIFactory7_Vtbl as Vtbl;
+    use DxgiFactory7Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.EnumAdapters => M::EnumAdapters);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.MakeWindowAssociation => M::MakeWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetWindowAssociation => M::GetWindowAssociation);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateSwapChain => M::CreateSwapChain);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.CreateSoftwareAdapter => M::CreateSoftwareAdapter);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.EnumAdapters1 => M::EnumAdapters1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.IsCurrent => M::IsCurrent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.IsWindowedStereoEnabled => M::IsWindowedStereoEnabled);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSwapChainForHwnd => M::CreateSwapChainForHwnd);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSwapChainForCoreWindow => M::CreateSwapChainForCoreWindow);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetSharedResourceAdapterLuid => M::GetSharedResourceAdapterLuid);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RegisterStereoStatusWindow => M::RegisterStereoStatusWindow);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RegisterStereoStatusEvent => M::RegisterStereoStatusEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.UnregisterStereoStatus => M::UnregisterStereoStatus);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RegisterOcclusionStatusWindow => M::RegisterOcclusionStatusWindow);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.RegisterOcclusionStatusEvent => M::RegisterOcclusionStatusEvent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.UnregisterOcclusionStatus => M::UnregisterOcclusionStatus);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.CreateSwapChainForComposition => M::CreateSwapChainForComposition);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetCreationFlags => M::GetCreationFlags);
+    assert_slot!(Vtbl, base__.base__.base__.EnumAdapterByLuid => M::EnumAdapterByLuid);
+    assert_slot!(Vtbl, base__.base__.base__.EnumWarpAdapter => M::EnumWarpAdapter);
+    assert_slot!(Vtbl, base__.base__.CheckFeatureSupport => M::CheckFeatureSupport);
+    assert_slot!(Vtbl, base__.EnumAdapterByGpuPreference => M::EnumAdapterByGpuPreference);
+    assert_slot!(Vtbl, RegisterAdaptersChangedEvent => M::RegisterAdaptersChangedEvent);
+    assert_slot!(Vtbl, UnregisterAdaptersChangedEvent => M::UnregisterAdaptersChangedEvent);
+};
+
+/// `IDXGIAdapter` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiAdapterMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumOutputs,
+    GetDesc,
+    CheckInterfaceSupport,
+}
+
+impl InterfaceMethods for DxgiAdapterMethods {
+    const INTERFACE: &'static str = "IDXGIAdapter";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIAdapter_Vtbl as Vtbl;
+    use DxgiAdapterMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, EnumOutputs => M::EnumOutputs);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, CheckInterfaceSupport => M::CheckInterfaceSupport);
+};
+
+/// `IDXGIAdapter1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiAdapter1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumOutputs,
+    GetDesc,
+    CheckInterfaceSupport,
+    GetDesc1,
+}
+
+impl InterfaceMethods for DxgiAdapter1Methods {
+    const INTERFACE: &'static str = "IDXGIAdapter1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIAdapter1_Vtbl as Vtbl;
+    use DxgiAdapter1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.EnumOutputs => M::EnumOutputs);
+    assert_slot!(Vtbl, base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.CheckInterfaceSupport => M::CheckInterfaceSupport);
+    assert_slot!(Vtbl, GetDesc1 => M::GetDesc1);
+};
+
+/// `IDXGIAdapter2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiAdapter2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumOutputs,
+    GetDesc,
+    CheckInterfaceSupport,
+    GetDesc1,
+    GetDesc2,
+}
+
+impl InterfaceMethods for DxgiAdapter2Methods {
+    const INTERFACE: &'static str = "IDXGIAdapter2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIAdapter2_Vtbl as Vtbl;
+    use DxgiAdapter2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.EnumOutputs => M::EnumOutputs);
+    assert_slot!(Vtbl, base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.CheckInterfaceSupport => M::CheckInterfaceSupport);
+    assert_slot!(Vtbl, base__.GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, GetDesc2 => M::GetDesc2);
+};
+
+/// `IDXGIAdapter3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiAdapter3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumOutputs,
+    GetDesc,
+    CheckInterfaceSupport,
+    GetDesc1,
+    GetDesc2,
+    RegisterHardwareContentProtectionTeardownStatusEvent,
+    UnregisterHardwareContentProtectionTeardownStatus,
+    QueryVideoMemoryInfo,
+    SetVideoMemoryReservation,
+    RegisterVideoMemoryBudgetChangeNotificationEvent,
+    UnregisterVideoMemoryBudgetChangeNotification,
+}
+
+impl InterfaceMethods for DxgiAdapter3Methods {
+    const INTERFACE: &'static str = "IDXGIAdapter3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIAdapter3_Vtbl as Vtbl;
+    use DxgiAdapter3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.EnumOutputs => M::EnumOutputs);
+    assert_slot!(Vtbl, base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.CheckInterfaceSupport => M::CheckInterfaceSupport);
+    assert_slot!(Vtbl, base__.base__.GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, base__.GetDesc2 => M::GetDesc2);
+    assert_slot!(Vtbl, RegisterHardwareContentProtectionTeardownStatusEvent => M::RegisterHardwareContentProtectionTeardownStatusEvent);
+    assert_slot!(Vtbl, UnregisterHardwareContentProtectionTeardownStatus => M::UnregisterHardwareContentProtectionTeardownStatus);
+    assert_slot!(Vtbl, QueryVideoMemoryInfo => M::QueryVideoMemoryInfo);
+    assert_slot!(Vtbl, SetVideoMemoryReservation => M::SetVideoMemoryReservation);
+    assert_slot!(Vtbl, RegisterVideoMemoryBudgetChangeNotificationEvent => M::RegisterVideoMemoryBudgetChangeNotificationEvent);
+    assert_slot!(Vtbl, UnregisterVideoMemoryBudgetChangeNotification => M::UnregisterVideoMemoryBudgetChangeNotification);
+};
+
+/// `IDXGIAdapter4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiAdapter4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    EnumOutputs,
+    GetDesc,
+    CheckInterfaceSupport,
+    GetDesc1,
+    GetDesc2,
+    RegisterHardwareContentProtectionTeardownStatusEvent,
+    UnregisterHardwareContentProtectionTeardownStatus,
+    QueryVideoMemoryInfo,
+    SetVideoMemoryReservation,
+    RegisterVideoMemoryBudgetChangeNotificationEvent,
+    UnregisterVideoMemoryBudgetChangeNotification,
+    GetDesc3,
+}
+
+impl InterfaceMethods for DxgiAdapter4Methods {
+    const INTERFACE: &'static str = "IDXGIAdapter4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIAdapter4_Vtbl as Vtbl;
+    use DxgiAdapter4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.EnumOutputs => M::EnumOutputs);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.base__.CheckInterfaceSupport => M::CheckInterfaceSupport);
+    assert_slot!(Vtbl, base__.base__.base__.GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, base__.base__.GetDesc2 => M::GetDesc2);
+    assert_slot!(Vtbl, base__.RegisterHardwareContentProtectionTeardownStatusEvent => M::RegisterHardwareContentProtectionTeardownStatusEvent);
+    assert_slot!(Vtbl, base__.UnregisterHardwareContentProtectionTeardownStatus => M::UnregisterHardwareContentProtectionTeardownStatus);
+    assert_slot!(Vtbl, base__.QueryVideoMemoryInfo => M::QueryVideoMemoryInfo);
+    assert_slot!(Vtbl, base__.SetVideoMemoryReservation => M::SetVideoMemoryReservation);
+    assert_slot!(Vtbl, base__.RegisterVideoMemoryBudgetChangeNotificationEvent => M::RegisterVideoMemoryBudgetChangeNotificationEvent);
+    assert_slot!(Vtbl, base__.UnregisterVideoMemoryBudgetChangeNotification => M::UnregisterVideoMemoryBudgetChangeNotification);
+    assert_slot!(Vtbl, GetDesc3 => M::GetDesc3);
+};
+
+/// `IDXGIOutput` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutputMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+}
+
+impl InterfaceMethods for DxgiOutputMethods {
+    const INTERFACE: &'static str = "IDXGIOutput";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput_Vtbl as Vtbl;
+    use DxgiOutputMethods as M;
+
+    assert_slot!(Vtbl, base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, GetFrameStatistics => M::GetFrameStatistics);
+};
+
+/// `IDXGIOutput1` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutput1Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+    GetDisplayModeList1,
+    FindClosestMatchingMode1,
+    GetDisplaySurfaceData1,
+    DuplicateOutput,
+}
+
+impl InterfaceMethods for DxgiOutput1Methods {
+    const INTERFACE: &'static str = "IDXGIOutput1";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput1_Vtbl as Vtbl;
+    use DxgiOutput1Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, base__.FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, base__.WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, base__.TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, base__.ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, base__.GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, base__.SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, base__.GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, base__.SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, base__.GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, GetDisplayModeList1 => M::GetDisplayModeList1);
+    assert_slot!(Vtbl, FindClosestMatchingMode1 => M::FindClosestMatchingMode1);
+    assert_slot!(Vtbl, GetDisplaySurfaceData1 => M::GetDisplaySurfaceData1);
+    assert_slot!(Vtbl, DuplicateOutput => M::DuplicateOutput);
+};
+
+/// `IDXGIOutput2` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutput2Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+    GetDisplayModeList1,
+    FindClosestMatchingMode1,
+    GetDisplaySurfaceData1,
+    DuplicateOutput,
+    SupportsOverlays,
+}
+
+impl InterfaceMethods for DxgiOutput2Methods {
+    const INTERFACE: &'static str = "IDXGIOutput2";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput2_Vtbl as Vtbl;
+    use DxgiOutput2Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, base__.base__.FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, base__.base__.WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, base__.base__.TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, base__.base__.ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, base__.base__.GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, base__.base__.SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, base__.base__.GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, base__.base__.SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, base__.base__.GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.GetDisplayModeList1 => M::GetDisplayModeList1);
+    assert_slot!(Vtbl, base__.FindClosestMatchingMode1 => M::FindClosestMatchingMode1);
+    assert_slot!(Vtbl, base__.GetDisplaySurfaceData1 => M::GetDisplaySurfaceData1);
+    assert_slot!(Vtbl, base__.DuplicateOutput => M::DuplicateOutput);
+    assert_slot!(Vtbl, SupportsOverlays => M::SupportsOverlays);
+};
+
+/// `IDXGIOutput3` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutput3Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+    GetDisplayModeList1,
+    FindClosestMatchingMode1,
+    GetDisplaySurfaceData1,
+    DuplicateOutput,
+    SupportsOverlays,
+    CheckOverlaySupport,
+}
+
+impl InterfaceMethods for DxgiOutput3Methods {
+    const INTERFACE: &'static str = "IDXGIOutput3";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput3_Vtbl as Vtbl;
+    use DxgiOutput3Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, base__.base__.base__.FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, base__.base__.base__.WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, base__.base__.base__.TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, base__.base__.base__.SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, base__.base__.base__.GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, base__.base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.GetDisplayModeList1 => M::GetDisplayModeList1);
+    assert_slot!(Vtbl, base__.base__.FindClosestMatchingMode1 => M::FindClosestMatchingMode1);
+    assert_slot!(Vtbl, base__.base__.GetDisplaySurfaceData1 => M::GetDisplaySurfaceData1);
+    assert_slot!(Vtbl, base__.base__.DuplicateOutput => M::DuplicateOutput);
+    assert_slot!(Vtbl, base__.SupportsOverlays => M::SupportsOverlays);
+    assert_slot!(Vtbl, CheckOverlaySupport => M::CheckOverlaySupport);
+};
+
+/// `IDXGIOutput4` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutput4Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+    GetDisplayModeList1,
+    FindClosestMatchingMode1,
+    GetDisplaySurfaceData1,
+    DuplicateOutput,
+    SupportsOverlays,
+    CheckOverlaySupport,
+    CheckOverlayColorSpaceSupport,
+}
+
+impl InterfaceMethods for DxgiOutput4Methods {
+    const INTERFACE: &'static str = "IDXGIOutput4";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput4_Vtbl as Vtbl;
+    use DxgiOutput4Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, base__.base__.base__.base__.WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, base__.base__.base__.base__.TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.base__.ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.base__.GetDisplayModeList1 => M::GetDisplayModeList1);
+    assert_slot!(Vtbl, base__.base__.base__.FindClosestMatchingMode1 => M::FindClosestMatchingMode1);
+    assert_slot!(Vtbl, base__.base__.base__.GetDisplaySurfaceData1 => M::GetDisplaySurfaceData1);
+    assert_slot!(Vtbl, base__.base__.base__.DuplicateOutput => M::DuplicateOutput);
+    assert_slot!(Vtbl, base__.base__.SupportsOverlays => M::SupportsOverlays);
+    assert_slot!(Vtbl, base__.CheckOverlaySupport => M::CheckOverlaySupport);
+    assert_slot!(Vtbl, CheckOverlayColorSpaceSupport => M::CheckOverlayColorSpaceSupport);
+};
+
+/// `IDXGIOutput5` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutput5Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+    GetDisplayModeList1,
+    FindClosestMatchingMode1,
+    GetDisplaySurfaceData1,
+    DuplicateOutput,
+    SupportsOverlays,
+    CheckOverlaySupport,
+    CheckOverlayColorSpaceSupport,
+    DuplicateOutput1,
+}
+
+impl InterfaceMethods for DxgiOutput5Methods {
+    const INTERFACE: &'static str = "IDXGIOutput5";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput5_Vtbl as Vtbl;
+    use DxgiOutput5Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDisplayModeList1 => M::GetDisplayModeList1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.FindClosestMatchingMode1 => M::FindClosestMatchingMode1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.GetDisplaySurfaceData1 => M::GetDisplaySurfaceData1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.DuplicateOutput => M::DuplicateOutput);
+    assert_slot!(Vtbl, base__.base__.base__.SupportsOverlays => M::SupportsOverlays);
+    assert_slot!(Vtbl, base__.base__.CheckOverlaySupport => M::CheckOverlaySupport);
+    assert_slot!(Vtbl, base__.CheckOverlayColorSpaceSupport => M::CheckOverlayColorSpaceSupport);
+    assert_slot!(Vtbl, DuplicateOutput1 => M::DuplicateOutput1);
+};
+
+/// `IDXGIOutput6` vtable slots, inherited methods first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum DxgiOutput6Methods {
+    QueryInterface,
+    AddRef,
+    Release,
+    SetPrivateData,
+    SetPrivateDataInterface,
+    GetPrivateData,
+    GetParent,
+    GetDesc,
+    GetDisplayModeList,
+    FindClosestMatchingMode,
+    WaitForVBlank,
+    TakeOwnership,
+    ReleaseOwnership,
+    GetGammaControlCapabilities,
+    SetGammaControl,
+    GetGammaControl,
+    SetDisplaySurface,
+    GetDisplaySurfaceData,
+    GetFrameStatistics,
+    GetDisplayModeList1,
+    FindClosestMatchingMode1,
+    GetDisplaySurfaceData1,
+    DuplicateOutput,
+    SupportsOverlays,
+    CheckOverlaySupport,
+    CheckOverlayColorSpaceSupport,
+    DuplicateOutput1,
+    GetDesc1,
+    CheckHardwareCompositionSupport,
+}
+
+impl InterfaceMethods for DxgiOutput6Methods {
+    const INTERFACE: &'static str = "IDXGIOutput6";
+}
+
+#[cfg(windows)]
+const _: () = {
+    use windows::Win32::Graphics::Dxgi::IDXGIOutput6_Vtbl as Vtbl;
+    use DxgiOutput6Methods as M;
+
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.QueryInterface => M::QueryInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.AddRef => M::AddRef);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.base__.Release => M::Release);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateData => M::SetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.SetPrivateDataInterface => M::SetPrivateDataInterface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetPrivateData => M::GetPrivateData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.base__.GetParent => M::GetParent);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetDesc => M::GetDesc);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetDisplayModeList => M::GetDisplayModeList);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.FindClosestMatchingMode => M::FindClosestMatchingMode);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.WaitForVBlank => M::WaitForVBlank);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.TakeOwnership => M::TakeOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.ReleaseOwnership => M::ReleaseOwnership);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetGammaControlCapabilities => M::GetGammaControlCapabilities);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetGammaControl => M::SetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetGammaControl => M::GetGammaControl);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.SetDisplaySurface => M::SetDisplaySurface);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetDisplaySurfaceData => M::GetDisplaySurfaceData);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.base__.GetFrameStatistics => M::GetFrameStatistics);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDisplayModeList1 => M::GetDisplayModeList1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.FindClosestMatchingMode1 => M::FindClosestMatchingMode1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.GetDisplaySurfaceData1 => M::GetDisplaySurfaceData1);
+    assert_slot!(Vtbl, base__.base__.base__.base__.base__.DuplicateOutput => M::DuplicateOutput);
+    assert_slot!(Vtbl, base__.base__.base__.base__.SupportsOverlays => M::SupportsOverlays);
+    assert_slot!(Vtbl, base__.base__.base__.CheckOverlaySupport => M::CheckOverlaySupport);
+    assert_slot!(Vtbl, base__.base__.CheckOverlayColorSpaceSupport => M::CheckOverlayColorSpaceSupport);
+    assert_slot!(Vtbl, base__.DuplicateOutput1 => M::DuplicateOutput1);
+    assert_slot!(Vtbl, GetDesc1 => M::GetDesc1);
+    assert_slot!(Vtbl, CheckHardwareCompositionSupport => M::CheckHardwareCompositionSupport);
+};
+
+/// Typed signatures of [`DxgiSwapchain1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain1 {
+    use std::ffi::c_void;
+
+    use super::DxgiSwapchain1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiSwapchain1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetFullscreenDesc(p_desc: *mut c_void) -> HRESULT,
+        GetHwnd(p_hwnd: *mut *mut c_void) -> HRESULT,
+        GetCoreWindow(refiid: *mut c_void, pp_unk: *mut *mut c_void) -> HRESULT,
+        Present1(sync_interval: u32, present_flags: u32, p_present_parameters: *mut c_void) -> HRESULT,
+        IsTemporaryMonoSupported() -> i32,
+        GetRestrictToOutput(pp_restrict_to_output: *mut *mut c_void) -> HRESULT,
+        SetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        GetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        SetRotation(rotation: i32) -> HRESULT,
+        GetRotation(p_rotation: *mut i32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiSwapchain2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain2 {
+    use std::ffi::c_void;
+
+    use super::DxgiSwapchain2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiSwapchain2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetFullscreenDesc(p_desc: *mut c_void) -> HRESULT,
+        GetHwnd(p_hwnd: *mut *mut c_void) -> HRESULT,
+        GetCoreWindow(refiid: *mut c_void, pp_unk: *mut *mut c_void) -> HRESULT,
+        Present1(sync_interval: u32, present_flags: u32, p_present_parameters: *mut c_void) -> HRESULT,
+        IsTemporaryMonoSupported() -> i32,
+        GetRestrictToOutput(pp_restrict_to_output: *mut *mut c_void) -> HRESULT,
+        SetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        GetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        SetRotation(rotation: i32) -> HRESULT,
+        GetRotation(p_rotation: *mut i32) -> HRESULT,
+        SetSourceSize(width: u32, height: u32) -> HRESULT,
+        GetSourceSize(p_width: *mut u32, p_height: *mut u32) -> HRESULT,
+        SetMaximumFrameLatency(max_latency: u32) -> HRESULT,
+        GetMaximumFrameLatency(p_max_latency: *mut u32) -> HRESULT,
+        GetFrameLatencyWaitableObject() -> *mut c_void,
+        SetMatrixTransform(p_matrix: *mut c_void) -> HRESULT,
+        GetMatrixTransform(p_matrix: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiSwapchain3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain3 {
+    use std::ffi::c_void;
+
+    use super::DxgiSwapchain3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiSwapchain3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetFullscreenDesc(p_desc: *mut c_void) -> HRESULT,
+        GetHwnd(p_hwnd: *mut *mut c_void) -> HRESULT,
+        GetCoreWindow(refiid: *mut c_void, pp_unk: *mut *mut c_void) -> HRESULT,
+        Present1(sync_interval: u32, present_flags: u32, p_present_parameters: *mut c_void) -> HRESULT,
+        IsTemporaryMonoSupported() -> i32,
+        GetRestrictToOutput(pp_restrict_to_output: *mut *mut c_void) -> HRESULT,
+        SetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        GetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        SetRotation(rotation: i32) -> HRESULT,
+        GetRotation(p_rotation: *mut i32) -> HRESULT,
+        SetSourceSize(width: u32, height: u32) -> HRESULT,
+        GetSourceSize(p_width: *mut u32, p_height: *mut u32) -> HRESULT,
+        SetMaximumFrameLatency(max_latency: u32) -> HRESULT,
+        GetMaximumFrameLatency(p_max_latency: *mut u32) -> HRESULT,
+        GetFrameLatencyWaitableObject() -> *mut c_void,
+        SetMatrixTransform(p_matrix: *mut c_void) -> HRESULT,
+        GetMatrixTransform(p_matrix: *mut c_void) -> HRESULT,
+        GetCurrentBackBufferIndex() -> u32,
+        CheckColorSpaceSupport(color_space: i32, p_color_space_support: *mut u32) -> HRESULT,
+        SetColorSpace1(color_space: i32) -> HRESULT,
+        ResizeBuffers1(buffer_count: u32, width: u32, height: u32, format: i32, swap_chain_flags: u32, p_creation_node_mask: *mut u32, pp_present_queue: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiSwapchain4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod swapchain4 {
+    use std::ffi::c_void;
+
+    use super::DxgiSwapchain4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiSwapchain4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDevice(riid: *mut c_void, pp_device: *mut *mut c_void) -> HRESULT,
+        Present(sync_interval: u32, flags: u32) -> HRESULT,
+        GetBuffer(buffer: u32, riid: *mut c_void, pp_surface: *mut *mut c_void) -> HRESULT,
+        SetFullscreenState(fullscreen: i32, p_target: *mut c_void) -> HRESULT,
+        GetFullscreenState(p_fullscreen: *mut i32, pp_target: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        ResizeBuffers(buffer_count: u32, width: u32, height: u32, new_format: i32, swap_chain_flags: u32) -> HRESULT,
+        ResizeTarget(p_new_target_parameters: *mut c_void) -> HRESULT,
+        GetContainingOutput(pp_output: *mut *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetLastPresentCount(p_last_present_count: *mut u32) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetFullscreenDesc(p_desc: *mut c_void) -> HRESULT,
+        GetHwnd(p_hwnd: *mut *mut c_void) -> HRESULT,
+        GetCoreWindow(refiid: *mut c_void, pp_unk: *mut *mut c_void) -> HRESULT,
+        Present1(sync_interval: u32, present_flags: u32, p_present_parameters: *mut c_void) -> HRESULT,
+        IsTemporaryMonoSupported() -> i32,
+        GetRestrictToOutput(pp_restrict_to_output: *mut *mut c_void) -> HRESULT,
+        SetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        GetBackgroundColor(p_color: *mut c_void) -> HRESULT,
+        SetRotation(rotation: i32) -> HRESULT,
+        GetRotation(p_rotation: *mut i32) -> HRESULT,
+        SetSourceSize(width: u32, height: u32) -> HRESULT,
+        GetSourceSize(p_width: *mut u32, p_height: *mut u32) -> HRESULT,
+        SetMaximumFrameLatency(max_latency: u32) -> HRESULT,
+        GetMaximumFrameLatency(p_max_latency: *mut u32) -> HRESULT,
+        GetFrameLatencyWaitableObject() -> *mut c_void,
+        SetMatrixTransform(p_matrix: *mut c_void) -> HRESULT,
+        GetMatrixTransform(p_matrix: *mut c_void) -> HRESULT,
+        GetCurrentBackBufferIndex() -> u32,
+        CheckColorSpaceSupport(color_space: i32, p_color_space_support: *mut u32) -> HRESULT,
+        SetColorSpace1(color_space: i32) -> HRESULT,
+        ResizeBuffers1(buffer_count: u32, width: u32, height: u32, format: i32, swap_chain_flags: u32, p_creation_node_mask: *mut u32, pp_present_queue: *mut *mut c_void) -> HRESULT,
+        SetHDRMetaData(r#type: i32, size: u32, p_meta_data: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiFactoryMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory {
+    use std::ffi::c_void;
+
+    use super::DxgiFactoryMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactoryMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory1 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory2 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+        IsWindowedStereoEnabled() -> i32,
+        CreateSwapChainForHwnd(p_device: *mut c_void, h_wnd: *mut c_void, p_desc: *mut c_void, p_fullscreen_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSwapChainForCoreWindow(p_device: *mut c_void, p_window: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSharedResourceAdapterLuid(h_resource: *mut c_void, p_luid: *mut c_void) -> HRESULT,
+        RegisterStereoStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterStereoStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterStereoStatus(dw_cookie: u32),
+        RegisterOcclusionStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterOcclusionStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterOcclusionStatus(dw_cookie: u32),
+        CreateSwapChainForComposition(p_device: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory3 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+        IsWindowedStereoEnabled() -> i32,
+        CreateSwapChainForHwnd(p_device: *mut c_void, h_wnd: *mut c_void, p_desc: *mut c_void, p_fullscreen_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSwapChainForCoreWindow(p_device: *mut c_void, p_window: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSharedResourceAdapterLuid(h_resource: *mut c_void, p_luid: *mut c_void) -> HRESULT,
+        RegisterStereoStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterStereoStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterStereoStatus(dw_cookie: u32),
+        RegisterOcclusionStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterOcclusionStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterOcclusionStatus(dw_cookie: u32),
+        CreateSwapChainForComposition(p_device: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetCreationFlags() -> u32,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory4 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+        IsWindowedStereoEnabled() -> i32,
+        CreateSwapChainForHwnd(p_device: *mut c_void, h_wnd: *mut c_void, p_desc: *mut c_void, p_fullscreen_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSwapChainForCoreWindow(p_device: *mut c_void, p_window: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSharedResourceAdapterLuid(h_resource: *mut c_void, p_luid: *mut c_void) -> HRESULT,
+        RegisterStereoStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterStereoStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterStereoStatus(dw_cookie: u32),
+        RegisterOcclusionStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterOcclusionStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterOcclusionStatus(dw_cookie: u32),
+        CreateSwapChainForComposition(p_device: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetCreationFlags() -> u32,
+        EnumAdapterByLuid(adapter_luid: u64, riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        EnumWarpAdapter(riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory5Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory5 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory5Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory5Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+        IsWindowedStereoEnabled() -> i32,
+        CreateSwapChainForHwnd(p_device: *mut c_void, h_wnd: *mut c_void, p_desc: *mut c_void, p_fullscreen_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSwapChainForCoreWindow(p_device: *mut c_void, p_window: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSharedResourceAdapterLuid(h_resource: *mut c_void, p_luid: *mut c_void) -> HRESULT,
+        RegisterStereoStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterStereoStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterStereoStatus(dw_cookie: u32),
+        RegisterOcclusionStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterOcclusionStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterOcclusionStatus(dw_cookie: u32),
+        CreateSwapChainForComposition(p_device: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetCreationFlags() -> u32,
+        EnumAdapterByLuid(adapter_luid: u64, riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        EnumWarpAdapter(riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory6Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory6 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory6Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory6Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+        IsWindowedStereoEnabled() -> i32,
+        CreateSwapChainForHwnd(p_device: *mut c_void, h_wnd: *mut c_void, p_desc: *mut c_void, p_fullscreen_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSwapChainForCoreWindow(p_device: *mut c_void, p_window: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSharedResourceAdapterLuid(h_resource: *mut c_void, p_luid: *mut c_void) -> HRESULT,
+        RegisterStereoStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterStereoStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterStereoStatus(dw_cookie: u32),
+        RegisterOcclusionStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterOcclusionStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterOcclusionStatus(dw_cookie: u32),
+        CreateSwapChainForComposition(p_device: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetCreationFlags() -> u32,
+        EnumAdapterByLuid(adapter_luid: u64, riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        EnumWarpAdapter(riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        EnumAdapterByGpuPreference(adapter: u32, gpu_preference: i32, riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiFactory7Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod factory7 {
+    use std::ffi::c_void;
+
+    use super::DxgiFactory7Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiFactory7Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumAdapters(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        MakeWindowAssociation(window_handle: *mut c_void, flags: u32) -> HRESULT,
+        GetWindowAssociation(p_window_handle: *mut *mut c_void) -> HRESULT,
+        CreateSwapChain(p_device: *mut c_void, p_desc: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSoftwareAdapter(module: *mut c_void, pp_adapter: *mut *mut c_void) -> HRESULT,
+        EnumAdapters1(adapter: u32, pp_adapter: *mut *mut c_void) -> HRESULT,
+        IsCurrent() -> i32,
+        IsWindowedStereoEnabled() -> i32,
+        CreateSwapChainForHwnd(p_device: *mut c_void, h_wnd: *mut c_void, p_desc: *mut c_void, p_fullscreen_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        CreateSwapChainForCoreWindow(p_device: *mut c_void, p_window: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetSharedResourceAdapterLuid(h_resource: *mut c_void, p_luid: *mut c_void) -> HRESULT,
+        RegisterStereoStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterStereoStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterStereoStatus(dw_cookie: u32),
+        RegisterOcclusionStatusWindow(window_handle: *mut c_void, w_msg: u32, pdw_cookie: *mut u32) -> HRESULT,
+        RegisterOcclusionStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterOcclusionStatus(dw_cookie: u32),
+        CreateSwapChainForComposition(p_device: *mut c_void, p_desc: *mut c_void, p_restrict_to_output: *mut c_void, pp_swap_chain: *mut *mut c_void) -> HRESULT,
+        GetCreationFlags() -> u32,
+        EnumAdapterByLuid(adapter_luid: u64, riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        EnumWarpAdapter(riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        CheckFeatureSupport(feature: i32, p_feature_support_data: *mut c_void, feature_support_data_size: u32) -> HRESULT,
+        EnumAdapterByGpuPreference(adapter: u32, gpu_preference: i32, riid: *mut c_void, ppv_adapter: *mut *mut c_void) -> HRESULT,
+        RegisterAdaptersChangedEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterAdaptersChangedEvent(dw_cookie: u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiAdapterMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod adapter {
+    use std::ffi::c_void;
+
+    use super::DxgiAdapterMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiAdapterMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumOutputs(output: u32, pp_output: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        CheckInterfaceSupport(interface_name: *mut c_void, p_umd_version: *mut i64) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiAdapter1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod adapter1 {
+    use std::ffi::c_void;
+
+    use super::DxgiAdapter1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiAdapter1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumOutputs(output: u32, pp_output: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        CheckInterfaceSupport(interface_name: *mut c_void, p_umd_version: *mut i64) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiAdapter2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod adapter2 {
+    use std::ffi::c_void;
+
+    use super::DxgiAdapter2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiAdapter2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumOutputs(output: u32, pp_output: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        CheckInterfaceSupport(interface_name: *mut c_void, p_umd_version: *mut i64) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetDesc2(p_desc: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiAdapter3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod adapter3 {
+    use std::ffi::c_void;
+
+    use super::DxgiAdapter3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiAdapter3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumOutputs(output: u32, pp_output: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        CheckInterfaceSupport(interface_name: *mut c_void, p_umd_version: *mut i64) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetDesc2(p_desc: *mut c_void) -> HRESULT,
+        RegisterHardwareContentProtectionTeardownStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterHardwareContentProtectionTeardownStatus(dw_cookie: u32),
+        QueryVideoMemoryInfo(node_index: u32, memory_segment_group: i32, p_video_memory_info: *mut c_void) -> HRESULT,
+        SetVideoMemoryReservation(node_index: u32, memory_segment_group: i32, reservation: u64) -> HRESULT,
+        RegisterVideoMemoryBudgetChangeNotificationEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterVideoMemoryBudgetChangeNotification(dw_cookie: u32),
+    }
+}
+
+/// Typed signatures of [`DxgiAdapter4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod adapter4 {
+    use std::ffi::c_void;
+
+    use super::DxgiAdapter4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiAdapter4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        EnumOutputs(output: u32, pp_output: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        CheckInterfaceSupport(interface_name: *mut c_void, p_umd_version: *mut i64) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        GetDesc2(p_desc: *mut c_void) -> HRESULT,
+        RegisterHardwareContentProtectionTeardownStatusEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterHardwareContentProtectionTeardownStatus(dw_cookie: u32),
+        QueryVideoMemoryInfo(node_index: u32, memory_segment_group: i32, p_video_memory_info: *mut c_void) -> HRESULT,
+        SetVideoMemoryReservation(node_index: u32, memory_segment_group: i32, reservation: u64) -> HRESULT,
+        RegisterVideoMemoryBudgetChangeNotificationEvent(h_event: *mut c_void, pdw_cookie: *mut u32) -> HRESULT,
+        UnregisterVideoMemoryBudgetChangeNotification(dw_cookie: u32),
+        GetDesc3(p_desc: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiOutputMethods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output {
+    use std::ffi::c_void;
+
+    use super::DxgiOutputMethods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutputMethods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiOutput1Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output1 {
+    use std::ffi::c_void;
+
+    use super::DxgiOutput1Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutput1Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetDisplayModeList1(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode1(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData1(p_destination: *mut c_void) -> HRESULT,
+        DuplicateOutput(p_device: *mut c_void, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiOutput2Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output2 {
+    use std::ffi::c_void;
+
+    use super::DxgiOutput2Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutput2Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetDisplayModeList1(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode1(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData1(p_destination: *mut c_void) -> HRESULT,
+        DuplicateOutput(p_device: *mut c_void, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+        SupportsOverlays() -> i32,
+    }
+}
+
+/// Typed signatures of [`DxgiOutput3Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output3 {
+    use std::ffi::c_void;
+
+    use super::DxgiOutput3Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutput3Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetDisplayModeList1(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode1(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData1(p_destination: *mut c_void) -> HRESULT,
+        DuplicateOutput(p_device: *mut c_void, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+        SupportsOverlays() -> i32,
+        CheckOverlaySupport(enum_format: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiOutput4Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output4 {
+    use std::ffi::c_void;
+
+    use super::DxgiOutput4Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutput4Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetDisplayModeList1(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode1(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData1(p_destination: *mut c_void) -> HRESULT,
+        DuplicateOutput(p_device: *mut c_void, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+        SupportsOverlays() -> i32,
+        CheckOverlaySupport(enum_format: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+        CheckOverlayColorSpaceSupport(format: i32, color_space: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiOutput5Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output5 {
+    use std::ffi::c_void;
+
+    use super::DxgiOutput5Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutput5Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetDisplayModeList1(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode1(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData1(p_destination: *mut c_void) -> HRESULT,
+        DuplicateOutput(p_device: *mut c_void, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+        SupportsOverlays() -> i32,
+        CheckOverlaySupport(enum_format: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+        CheckOverlayColorSpaceSupport(format: i32, color_space: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+        DuplicateOutput1(p_device: *mut c_void, flags: u32, supported_formats_count: u32, p_supported_formats: *mut i32, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+    }
+}
+
+/// Typed signatures of [`DxgiOutput6Methods`], for [`MethodTable::typed`](crate::MethodTable::typed).
+pub mod output6 {
+    use std::ffi::c_void;
+
+    use super::DxgiOutput6Methods;
+    use crate::{method_table::signatures, HRESULT};
+
+    signatures! {
+        DxgiOutput6Methods;
+        QueryInterface(riid: *mut c_void, ppv_object: *mut *mut c_void) -> HRESULT,
+        AddRef() -> u32,
+        Release() -> u32,
+        SetPrivateData(name: *mut c_void, data_size: u32, p_data: *mut c_void) -> HRESULT,
+        SetPrivateDataInterface(name: *mut c_void, p_unknown: *mut c_void) -> HRESULT,
+        GetPrivateData(name: *mut c_void, p_data_size: *mut u32, p_data: *mut c_void) -> HRESULT,
+        GetParent(riid: *mut c_void, pp_parent: *mut *mut c_void) -> HRESULT,
+        GetDesc(p_desc: *mut c_void) -> HRESULT,
+        GetDisplayModeList(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        WaitForVBlank() -> HRESULT,
+        TakeOwnership(p_device: *mut c_void, exclusive: i32) -> HRESULT,
+        ReleaseOwnership(),
+        GetGammaControlCapabilities(p_gamma_caps: *mut c_void) -> HRESULT,
+        SetGammaControl(p_array: *mut c_void) -> HRESULT,
+        GetGammaControl(p_array: *mut c_void) -> HRESULT,
+        SetDisplaySurface(p_scanout_surface: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData(p_destination: *mut c_void) -> HRESULT,
+        GetFrameStatistics(p_stats: *mut c_void) -> HRESULT,
+        GetDisplayModeList1(enum_format: i32, flags: u32, p_num_modes: *mut u32, p_desc: *mut c_void) -> HRESULT,
+        FindClosestMatchingMode1(p_mode_to_match: *mut c_void, p_closest_match: *mut c_void, p_concerned_device: *mut c_void) -> HRESULT,
+        GetDisplaySurfaceData1(p_destination: *mut c_void) -> HRESULT,
+        DuplicateOutput(p_device: *mut c_void, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+        SupportsOverlays() -> i32,
+        CheckOverlaySupport(enum_format: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+        CheckOverlayColorSpaceSupport(format: i32, color_space: i32, p_concerned_device: *mut c_void, p_flags: *mut u32) -> HRESULT,
+        DuplicateOutput1(p_device: *mut c_void, flags: u32, supported_formats_count: u32, p_supported_formats: *mut i32, pp_output_duplication: *mut *mut c_void) -> HRESULT,
+        GetDesc1(p_desc: *mut c_void) -> HRESULT,
+        CheckHardwareCompositionSupport(p_flags: *mut u32) -> HRESULT,
+    }
+}
@@ -0,0 +1,410 @@
+//! Per-frame callbacks on the render functions nearly every consumer hooks.
+//!
+//! Each engine module, e.g. [`dx11::on_present`], discovers its tables with the
+//! engine's `methods()`, hooks one slot and runs the registered closures from
+//! the detour before calling through to the original function. The hook is
+//! installed with the first closure and removed once the last one is dropped.
+//!
+//! The engine independent part is [`Dispatcher`], which can be driven by any
+//! vtable, including a fake one on hosts without DirectX.
+
+use std::{
+    ffi::c_void,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::{HookGuard, Method, MethodTable, ShroudResult};
+
+#[cfg(all(windows, feature = "directx9"))]
+pub mod dx9;
+
+#[cfg(all(windows, feature = "directx11"))]
+pub mod dx11;
+
+#[cfg(all(windows, feature = "directx12"))]
+pub mod dx12;
+
+/// Size and format of the buffers a swap chain presents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BackBuffer {
+    pub width: u32,
+    pub height: u32,
+    /// A `DXGI_FORMAT`, or a `D3DFORMAT` on DirectX9.
+    pub format: u32,
+    pub count: u32,
+}
+
+/// Describes the back buffers of a DXGI swap chain, `None` if it can not.
+#[cfg(all(windows, any(feature = "directx11", feature = "directx12")))]
+fn describe(swapchain: &windows::Win32::Graphics::Dxgi::IDXGISwapChain) -> Option<BackBuffer> {
+    let desc = unsafe { swapchain.GetDesc() }.ok()?;
+    Some(BackBuffer {
+        width: desc.BufferDesc.Width,
+        height: desc.BufferDesc.Height,
+        format: desc.BufferDesc.Format.0 as u32,
+        count: desc.BufferCount,
+    })
+}
+
+type Callback<C> = Arc<Mutex<Box<C>>>;
+
+struct State<M: Method, C: ?Sized> {
+    callbacks: Vec<(u64, Callback<C>)>,
+    next_id: u64,
+    guard: Option<HookGuard<M::Fn>>,
+}
+
+/// The callbacks registered on the method `M`, and the vtable hook running them.
+///
+/// Meant to live in a `static` next to its `detour`, an `extern "system"` fn of
+/// type `M::Fn` that calls [`Dispatcher::dispatch`] to run the callbacks and then
+/// [`Dispatcher::original`] to carry on with the call.
+///
+/// The hook is installed by the first [`Dispatcher::subscribe`] and removed when
+/// the last [`Subscription`] goes away. Should the slot have been hooked again on
+/// top of the detour by then, the detour stays in place calling straight through,
+/// and is reused by the next subscriber.
+pub struct Dispatcher<M: Method, C: ?Sized> {
+    detour: M::Fn,
+    original: AtomicPtr<c_void>,
+    state: Mutex<State<M, C>>,
+}
+
+impl<M: Method, C: ?Sized + Send> Dispatcher<M, C> {
+    pub const fn new(detour: M::Fn) -> Self {
+        Self {
+            detour,
+            original: AtomicPtr::new(std::ptr::null_mut()),
+            state: Mutex::new(State {
+                callbacks: Vec::new(),
+                next_id: 0,
+                guard: None,
+            }),
+        }
+    }
+
+    /// Registers `callback`, first hooking the table `discover` returns if no
+    /// callback is registered yet. The callback is removed with the returned
+    /// subscription.
+    ///
+    /// # Safety
+    ///
+    /// The table `discover` returns must be read from a live vtable whose `M`
+    /// slot holds a function of type `M::Fn`.
+    pub unsafe fn subscribe(
+        &'static self,
+        callback: Box<C>,
+        discover: impl FnOnce() -> ShroudResult<MethodTable<M::Methods>>,
+    ) -> ShroudResult<Subscription>
+    where
+        M: 'static,
+        M::Methods: EnumCount + IntoEnumIterator + PartialEq,
+        C: 'static,
+    {
+        let mut state = self.lock();
+        if state.guard.is_none() {
+            let table = discover()?;
+            // The detour may run as soon as the slot is swapped, so it needs an
+            // original before then. The slot may have changed since discovery,
+            // which the guard reports once it is swapped.
+            self.original
+                .store(table.get(M::METHOD).cast_mut(), Ordering::Release);
+            let guard = table.hook(M::METHOD, self.detour)?;
+            let original: *const c_void = std::mem::transmute_copy(&guard.original());
+            self.original.store(original.cast_mut(), Ordering::Release);
+            state.guard = Some(guard);
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.callbacks.push((id, Arc::new(Mutex::new(callback))));
+        Ok(Subscription {
+            dispatcher: self,
+            id,
+        })
+    }
+
+    /// Runs `call` on every registered callback, in registration order.
+    ///
+    /// Callbacks are called without holding the registry, so they may subscribe
+    /// and unsubscribe freely. A callback still running on another thread, or
+    /// further up this one, is skipped.
+    pub fn dispatch(&self, mut call: impl FnMut(&mut C)) {
+        let callbacks: Vec<Callback<C>> = self
+            .lock()
+            .callbacks
+            .iter()
+            .map(|(_, callback)| callback.clone())
+            .collect();
+
+        for callback in callbacks {
+            if let Ok(mut callback) = callback.try_lock() {
+                call(&mut callback);
+            }
+        }
+    }
+
+    /// The function the slot held before it was hooked.
+    ///
+    /// # Panics
+    ///
+    /// If the hook was never installed, which the detour can not observe.
+    pub fn original(&self) -> M::Fn {
+        let original = self.original.load(Ordering::Acquire);
+        assert!(!original.is_null(), "dispatcher was never installed");
+        unsafe { std::mem::transmute_copy(&original) }
+    }
+
+    /// Whether the detour is currently in the vtable.
+    pub fn is_installed(&self) -> bool {
+        self.lock().guard.is_some()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<M, C>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+trait Unsubscribe: Sync {
+    fn unsubscribe(&self, id: u64) -> ShroudResult<()>;
+}
+
+impl<M: Method, C: ?Sized + Send> Unsubscribe for Dispatcher<M, C> {
+    fn unsubscribe(&self, id: u64) -> ShroudResult<()> {
+        let mut state = self.lock();
+        state.callbacks.retain(|&(other, _)| other != id);
+        if !state.callbacks.is_empty() {
+            return Ok(());
+        }
+
+        let restored = match &state.guard {
+            Some(guard) => unsafe { guard.restore()? },
+            None => false,
+        };
+        if restored {
+            std::mem::forget(state.guard.take());
+        }
+        Ok(())
+    }
+}
+
+/// A registered callback, removed when dropped.
+///
+/// A callback may still be running on the render thread while it is removed, and
+/// finishes that one call.
+#[must_use = "the callback is removed as soon as its subscription is dropped"]
+pub struct Subscription {
+    dispatcher: &'static dyn Unsubscribe,
+    id: u64,
+}
+
+impl Subscription {
+    /// Removes the callback now, reporting failure to unhook instead of ignoring
+    /// it as drop does.
+    pub fn remove(self) -> ShroudResult<()> {
+        let result = self.dispatcher.unsubscribe(self.id);
+        std::mem::forget(self);
+        result
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let _ = self.dispatcher.unsubscribe(self.id);
+    }
+}
+
+impl std::fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, AtomicUsize};
+
+    use strum::EnumCount;
+
+    use super::*;
+    use crate::{
+        testing::{fake::Present, slot_fn, FakeMethods, FakeObject, SlotFn},
+        ShroudError,
+    };
+
+    type Callback = dyn FnMut(u32) + Send;
+
+    /// A dispatcher on the fake `Present`, whose detour adds 1000 to the result.
+    macro_rules! dispatcher {
+        ($name:ident) => {
+            static $name: Dispatcher<Present, Callback> = Dispatcher::new({
+                unsafe extern "system" fn detour(this: *mut c_void, arg: u32) -> u32 {
+                    $name.dispatch(|callback| callback(arg));
+                    ($name.original())(this, arg) + 1000
+                }
+                detour as SlotFn
+            });
+        };
+    }
+
+    /// Counts discoveries of `object`'s table.
+    fn discover<'a>(
+        object: &'a FakeObject,
+        discovered: &'a AtomicUsize,
+    ) -> impl FnOnce() -> ShroudResult<MethodTable<FakeMethods>> + 'a {
+        move || {
+            discovered.fetch_add(1, Ordering::SeqCst);
+            unsafe { crate::vtable::read(object.as_ptr()) }
+        }
+    }
+
+    /// A callback adding its argument to `total`.
+    fn adding(total: &'static AtomicU32) -> Box<Callback> {
+        Box::new(move |arg| {
+            total.fetch_add(arg, Ordering::SeqCst);
+        })
+    }
+
+    #[test]
+    fn installs_with_the_first_subscription_and_removes_with_the_last() {
+        dispatcher!(DISPATCHER);
+        static FIRST: AtomicU32 = AtomicU32::new(0);
+        static SECOND: AtomicU32 = AtomicU32::new(0);
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let discovered = AtomicUsize::new(0);
+        assert!(!DISPATCHER.is_installed());
+
+        let first = unsafe { DISPATCHER.subscribe(adding(&FIRST), discover(&object, &discovered)) }
+            .unwrap();
+        assert!(DISPATCHER.is_installed());
+        assert_eq!(object.call(3, 1), 1301);
+        assert_eq!(FIRST.load(Ordering::SeqCst), 1);
+
+        let second =
+            unsafe { DISPATCHER.subscribe(adding(&SECOND), discover(&object, &discovered)) }
+                .unwrap();
+        assert_eq!(discovered.load(Ordering::SeqCst), 1);
+        assert_eq!(object.call(3, 2), 1302);
+        assert_eq!(FIRST.load(Ordering::SeqCst), 3);
+        assert_eq!(SECOND.load(Ordering::SeqCst), 2);
+
+        drop(first);
+        assert!(DISPATCHER.is_installed());
+        assert_eq!(object.call(3, 4), 1304);
+        assert_eq!(FIRST.load(Ordering::SeqCst), 3);
+        assert_eq!(SECOND.load(Ordering::SeqCst), 6);
+
+        second.remove().unwrap();
+        assert!(!DISPATCHER.is_installed());
+        assert_eq!(object.call(3, 8), 308);
+        assert_eq!(SECOND.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn stays_under_hooks_stacked_on_top() {
+        dispatcher!(DISPATCHER);
+        static CALLS: AtomicU32 = AtomicU32::new(0);
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let discovered = AtomicUsize::new(0);
+        let table = unsafe { crate::vtable::read::<FakeMethods>(object.as_ptr()) }.unwrap();
+
+        let subscription =
+            unsafe { DISPATCHER.subscribe(adding(&CALLS), discover(&object, &discovered)) }
+                .unwrap();
+        let stacked = unsafe { table.hook(FakeMethods::Present, slot_fn(4)) }.unwrap();
+        assert_eq!(object.call(3, 1), 401);
+
+        // Unhooking would undo the stacked hook, so the detour stays in place.
+        drop(subscription);
+        assert!(DISPATCHER.is_installed());
+
+        stacked.unhook().unwrap();
+        assert_eq!(object.call(3, 1), 1301);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        // The next subscriber reuses the detour left in place.
+        let subscription =
+            unsafe { DISPATCHER.subscribe(adding(&CALLS), discover(&object, &discovered)) }
+                .unwrap();
+        assert_eq!(discovered.load(Ordering::SeqCst), 1);
+        assert_eq!(object.call(3, 2), 1302);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+        drop(subscription);
+        assert!(!DISPATCHER.is_installed());
+        assert_eq!(object.call(3, 1), 301);
+    }
+
+    #[test]
+    fn failed_discovery_installs_nothing() {
+        dispatcher!(DISPATCHER);
+        static CALLS: AtomicU32 = AtomicU32::new(0);
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let discovered = AtomicUsize::new(0);
+
+        let result =
+            unsafe { DISPATCHER.subscribe(adding(&CALLS), || Err(ShroudError::DetachedTable)) };
+        assert!(matches!(result, Err(ShroudError::DetachedTable)));
+        assert!(!DISPATCHER.is_installed());
+
+        let subscription =
+            unsafe { DISPATCHER.subscribe(adding(&CALLS), discover(&object, &discovered)) }
+                .unwrap();
+        assert_eq!(object.call(3, 1), 1301);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        drop(subscription);
+    }
+
+    #[test]
+    fn callbacks_may_subscribe_while_dispatched() {
+        dispatcher!(DISPATCHER);
+        static CALLS: AtomicU32 = AtomicU32::new(0);
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let discovered = AtomicUsize::new(0);
+        // Tables hold raw pointers and are not `Send`, so the callback reads
+        // its own from the vtable's address.
+        let vtable = object.vtable as usize;
+        let rediscover = move || unsafe {
+            MethodTable::<FakeMethods>::from_vtable(vtable as *const *const c_void)
+        };
+
+        let nested = Arc::new(Mutex::new(Vec::new()));
+        let subscriptions = nested.clone();
+        let subscription = unsafe {
+            DISPATCHER.subscribe(
+                Box::new(move |_| {
+                    let subscription = DISPATCHER.subscribe(adding(&CALLS), rediscover).unwrap();
+                    subscriptions.lock().unwrap().push(subscription);
+                }),
+                discover(&object, &discovered),
+            )
+        }
+        .unwrap();
+
+        assert_eq!(object.call(3, 1), 1301);
+        assert_eq!(nested.lock().unwrap().len(), 1);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        drop(subscription);
+        assert!(DISPATCHER.is_installed());
+        nested.lock().unwrap().clear();
+        assert!(!DISPATCHER.is_installed());
+        assert_eq!(object.call(3, 1), 301);
+    }
+
+    #[test]
+    #[should_panic(expected = "never installed")]
+    fn original_needs_an_installed_hook() {
+        dispatcher!(DISPATCHER);
+        let _: SlotFn = DISPATCHER.original();
+    }
+}
@@ -0,0 +1,187 @@
+//! `IDXGISwapChain::Present` and `ResizeBuffers` of Direct3D 11 swap chains.
+
+use std::ffi::c_void;
+
+use windows::{
+    core::{Interface, HRESULT},
+    Win32::Graphics::{
+        Direct3D11::{ID3D11Device, ID3D11DeviceContext},
+        Dxgi::IDXGISwapChain,
+    },
+};
+
+use super::{describe, BackBuffer, Dispatcher, Subscription};
+use crate::{
+    directx11::{self, swapchain, DirectX11SwapchainMethods},
+    MethodTable, ShroudResult,
+};
+
+/// A frame about to be presented.
+pub struct Frame<'a> {
+    swapchain: &'a IDXGISwapChain,
+    device: &'a ID3D11Device,
+    sync_interval: u32,
+    flags: u32,
+}
+
+impl Frame<'_> {
+    pub fn swapchain(&self) -> &IDXGISwapChain {
+        self.swapchain
+    }
+
+    pub fn device(&self) -> &ID3D11Device {
+        self.device
+    }
+
+    /// The device's immediate context, to render the overlay with.
+    pub fn context(&self) -> Option<ID3D11DeviceContext> {
+        unsafe { self.device.GetImmediateContext() }.ok()
+    }
+
+    /// Size and format of the buffer being presented.
+    pub fn back_buffer(&self) -> Option<BackBuffer> {
+        describe(self.swapchain)
+    }
+
+    /// `SyncInterval` argument of the present.
+    pub fn sync_interval(&self) -> u32 {
+        self.sync_interval
+    }
+
+    /// `DXGI_PRESENT` flags of the present.
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+/// Buffers about to be resized. Anything still referencing them, such as render
+/// target views, has to be released now for the resize to succeed.
+pub struct Resize<'a> {
+    swapchain: &'a IDXGISwapChain,
+    device: &'a ID3D11Device,
+    buffer_count: u32,
+    width: u32,
+    height: u32,
+    format: u32,
+    flags: u32,
+}
+
+impl Resize<'_> {
+    pub fn swapchain(&self) -> &IDXGISwapChain {
+        self.swapchain
+    }
+
+    pub fn device(&self) -> &ID3D11Device {
+        self.device
+    }
+
+    /// The buffers as they are before the resize.
+    pub fn back_buffer(&self) -> Option<BackBuffer> {
+        describe(self.swapchain)
+    }
+
+    /// New number of buffers, 0 to keep the current one.
+    pub fn buffer_count(&self) -> u32 {
+        self.buffer_count
+    }
+
+    /// New width, 0 to match the window.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// New height, 0 to match the window.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// New `DXGI_FORMAT`, 0 to keep the current one.
+    pub fn format(&self) -> u32 {
+        self.format
+    }
+
+    /// New `DXGI_SWAP_CHAIN_FLAG`s.
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+static PRESENT: Dispatcher<swapchain::Present, dyn FnMut(&Frame) + Send> =
+    Dispatcher::new(present as _);
+
+static RESIZE_BUFFERS: Dispatcher<swapchain::ResizeBuffers, dyn FnMut(&Resize) + Send> =
+    Dispatcher::new(resize_buffers as _);
+
+/// Runs `callback` on every frame a Direct3D 11 swap chain presents, until the
+/// returned subscription is dropped.
+///
+/// The first subscription discovers the swap chain table with
+/// [`directx11::methods`] and hooks `Present` in it.
+pub fn on_present(callback: impl FnMut(&Frame) + Send + 'static) -> ShroudResult<Subscription> {
+    unsafe { PRESENT.subscribe(Box::new(callback), swapchain_vmt) }
+}
+
+/// Runs `callback` before a Direct3D 11 swap chain resizes its buffers, until the
+/// returned subscription is dropped.
+pub fn on_resize_buffers(
+    callback: impl FnMut(&Resize) + Send + 'static,
+) -> ShroudResult<Subscription> {
+    unsafe { RESIZE_BUFFERS.subscribe(Box::new(callback), swapchain_vmt) }
+}
+
+fn swapchain_vmt() -> ShroudResult<MethodTable<DirectX11SwapchainMethods>> {
+    Ok(directx11::methods()?.swapchain_vmt().clone())
+}
+
+/// The device of a Direct3D 11 swap chain. Swap chains of the other APIs share
+/// the vtable, and fail this.
+fn device(swapchain: &IDXGISwapChain) -> Option<ID3D11Device> {
+    unsafe { swapchain.GetDevice() }.ok()
+}
+
+unsafe extern "system" fn present(this: *mut c_void, sync_interval: u32, flags: u32) -> HRESULT {
+    if let Some(swapchain) = IDXGISwapChain::from_raw_borrowed(&this) {
+        if let Some(device) = device(swapchain) {
+            let frame = Frame {
+                swapchain,
+                device: &device,
+                sync_interval,
+                flags,
+            };
+            PRESENT.dispatch(|callback| callback(&frame));
+        }
+    }
+    PRESENT.original()(this, sync_interval, flags)
+}
+
+unsafe extern "system" fn resize_buffers(
+    this: *mut c_void,
+    buffer_count: u32,
+    width: u32,
+    height: u32,
+    new_format: i32,
+    swap_chain_flags: u32,
+) -> HRESULT {
+    if let Some(swapchain) = IDXGISwapChain::from_raw_borrowed(&this) {
+        if let Some(device) = device(swapchain) {
+            let resize = Resize {
+                swapchain,
+                device: &device,
+                buffer_count,
+                width,
+                height,
+                format: new_format as u32,
+                flags: swap_chain_flags,
+            };
+            RESIZE_BUFFERS.dispatch(|callback| callback(&resize));
+        }
+    }
+    RESIZE_BUFFERS.original()(
+        this,
+        buffer_count,
+        width,
+        height,
+        new_format,
+        swap_chain_flags,
+    )
+}
@@ -0,0 +1,66 @@
+//! `IDirect3DDevice9::EndScene`.
+
+use std::ffi::c_void;
+
+use windows::{
+    core::{Interface, HRESULT},
+    Win32::Graphics::Direct3D9::{IDirect3DDevice9, IDirect3DSwapChain9, D3DPRESENT_PARAMETERS},
+};
+
+use super::{BackBuffer, Dispatcher, Subscription};
+use crate::{
+    directx9::{self, device, DirectX9DeviceMethods},
+    MethodTable, ShroudResult,
+};
+
+/// A scene about to end, the last point to draw into it before it is presented.
+pub struct Scene<'a> {
+    device: &'a IDirect3DDevice9,
+}
+
+impl Scene<'_> {
+    pub fn device(&self) -> &IDirect3DDevice9 {
+        self.device
+    }
+
+    /// The device's implicit swap chain.
+    pub fn swapchain(&self) -> Option<IDirect3DSwapChain9> {
+        unsafe { self.device.GetSwapChain(0) }.ok()
+    }
+
+    /// Size and format of the implicit swap chain's back buffers.
+    pub fn back_buffer(&self) -> Option<BackBuffer> {
+        let mut parameters = D3DPRESENT_PARAMETERS::default();
+        unsafe { self.swapchain()?.GetPresentParameters(&mut parameters) }.ok()?;
+        Some(BackBuffer {
+            width: parameters.BackBufferWidth,
+            height: parameters.BackBufferHeight,
+            format: parameters.BackBufferFormat.0,
+            count: parameters.BackBufferCount,
+        })
+    }
+}
+
+static END_SCENE: Dispatcher<device::EndScene, dyn FnMut(&Scene) + Send> =
+    Dispatcher::new(end_scene as _);
+
+/// Runs `callback` whenever a Direct3D 9 device ends a scene, until the returned
+/// subscription is dropped. Games may end several scenes per frame.
+///
+/// The first subscription discovers the device table with
+/// [`directx9::methods`] and hooks `EndScene` in it.
+pub fn on_end_scene(callback: impl FnMut(&Scene) + Send + 'static) -> ShroudResult<Subscription> {
+    unsafe { END_SCENE.subscribe(Box::new(callback), device_vmt) }
+}
+
+fn device_vmt() -> ShroudResult<MethodTable<DirectX9DeviceMethods>> {
+    Ok(directx9::methods()?.device_vmt().clone())
+}
+
+unsafe extern "system" fn end_scene(this: *mut c_void) -> HRESULT {
+    if let Some(device) = IDirect3DDevice9::from_raw_borrowed(&this) {
+        let scene = Scene { device };
+        END_SCENE.dispatch(|callback| callback(&scene));
+    }
+    END_SCENE.original()(this)
+}
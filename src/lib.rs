@@ -1,19 +1,57 @@
 use std::ffi::{IntoStringError, NulError};
 
-use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use thiserror::Error;
-use windows::{
-    core::{HRESULT, PCSTR},
-    Win32::{
-        Foundation::{BOOL, HMODULE, HWND, LPARAM},
-        System::LibraryLoader::GetModuleHandleA,
-        UI::WindowsAndMessaging::{EnumWindows, GetWindowThreadProcessId},
-    },
-};
-
-#[cfg(not(target_os = "windows"))]
-compile_error!("only windows is supported");
+
+#[cfg(windows)]
+pub use windows::core::HRESULT;
+
+/// Portable stand-in for `windows::core::HRESULT`, so that [`ShroudError`] is
+/// the same type on every host.
+#[cfg(not(windows))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct HRESULT(pub i32);
+
+#[cfg(test)]
+mod testing;
+
+pub mod method_table;
+pub use method_table::{HookGuard, InterfaceMethods, Method, MethodTable};
+
+pub mod vtable;
+
+pub mod shadow;
+
+mod protect;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod detour;
+
+pub mod hooks;
+
+pub mod module;
+
+pub mod detect;
+pub use detect::{detect_render_engines, Detection};
+
+pub mod watch;
+pub use watch::{watch, Watch};
+
+pub mod discovery;
+pub use discovery::DiscoveryOptions;
+
+pub mod window;
+pub use window::{TemporaryWindow, WindowProvider, WindowSelector};
+
+pub mod snapshot;
+pub use snapshot::Snapshot;
+
+pub mod diff;
+pub use diff::{diff, SnapshotDiff};
+
+#[cfg(any(feature = "directx10", feature = "directx11", feature = "directx12"))]
+pub mod dxgi;
 
 #[cfg(feature = "directx9")]
 pub mod directx9;
@@ -27,44 +65,19 @@ pub mod directx11;
 #[cfg(feature = "directx12")]
 pub mod directx12;
 
-#[cfg(any(feature = "directx11", feature = "directx12"))]
+#[cfg(all(
+    windows,
+    any(feature = "directx10", feature = "directx11", feature = "directx12")
+))]
 pub mod swapchain_util;
 
-pub(crate) fn get_process_window() -> Option<HWND> {
-    extern "system" fn enum_windows_callback(hwnd: HWND, l_param: LPARAM) -> BOOL {
-        let mut wnd_proc_id: u32 = 0;
-        unsafe {
-            GetWindowThreadProcessId(hwnd, Some(&mut wnd_proc_id));
-            if std::process::id() != wnd_proc_id {
-                return true.into();
-            }
-
-            *(l_param.0 as *mut HWND) = hwnd;
-        }
-        false.into()
-    }
-
-    let mut output: HWND = HWND(0);
-    unsafe {
-        EnumWindows(
-            Some(enum_windows_callback),
-            std::mem::transmute::<_, LPARAM>(&mut output as *mut HWND),
-        )
-        .ok()?
-    };
-
-    match output.0 == 0 {
-        true => None,
-        false => Some(output),
-    }
-}
-
 static DIRECTX_9_DLL_NAME: &str = concat!("d3d9.dll", "\0");
 static DIRECTX_10_DLL_NAME: &str = concat!("d3d10.dll", "\0");
 static DIRECTX_11_DLL_NAME: &str = concat!("d3d11.dll", "\0");
 static DIRECTX_12_DLL_NAME: &str = concat!("d3d12.dll", "\0");
 
-#[derive(Debug, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RenderEngine {
     DirectX9,
     DirectX10,
@@ -72,21 +85,15 @@ pub enum RenderEngine {
     DirectX12,
 }
 
+/// The render engine the process most likely renders with, see
+/// [`detect_render_engines`].
 pub fn detect_render_engine() -> Option<RenderEngine> {
-    RenderEngine::iter()
-        .find(|render_engine| RenderEngine::get_render_engine_handle(render_engine).is_ok())
+    detect_render_engines()
+        .first()
+        .map(|detection| detection.engine)
 }
 
 impl RenderEngine {
-    pub(crate) fn get_render_engine_handle(render_engine: &RenderEngine) -> ShroudResult<HMODULE> {
-        let handle_name = RenderEngine::dll_name(render_engine);
-        let handle = unsafe {
-            GetModuleHandleA(PCSTR::from_raw(handle_name.as_ptr()))
-                .map_err(|_| ShroudError::OpenHandleError(handle_name.to_owned()))?
-        };
-        Ok(handle)
-    }
-
     pub fn dll_name(entry: &RenderEngine) -> &str {
         match entry {
             RenderEngine::DirectX9 => DIRECTX_9_DLL_NAME,
@@ -111,15 +118,65 @@ pub enum ShroudError {
     #[error("Error finding main process window!")]
     Window,
 
+    #[error("Error creating temporary window: {0}")]
+    TemporaryWindow(std::io::Error),
+
     #[error("General expectation failed `{0}`")]
     Expectation(&'static str),
 
+    #[error("Null {0} pointer")]
+    NullPointer(&'static str),
+
+    #[error("Misaligned {0} pointer `{1:#x}`")]
+    MisalignedPointer(&'static str, usize),
+
+    #[error("Invalid snapshot `{0}`")]
+    Snapshot(String),
+
+    #[error("Method table expected {expected} entries, found {actual}")]
+    MethodTableLength { expected: usize, actual: usize },
+
+    #[error("Method table is not backed by a live vtable")]
+    DetachedTable,
+
+    #[error("Error changing memory protection at `{0:#x}`: {1}")]
+    Protect(usize, std::io::Error),
+
+    #[error("Can not detour `{0:#x}`: {1}")]
+    Detour(usize, &'static str),
+
+    #[error("Error watching module loads: {0}")]
+    Watch(std::io::Error),
+
+    #[error("No way to create a {0:?} device is left by the discovery options")]
+    NoDiscoveryAttempt(RenderEngine),
+
+    #[cfg(any(feature = "directx10", feature = "directx11", feature = "directx12"))]
+    #[error("Error creating dxgi factory `{0:#?}`")]
+    DxgiCreateFactory(HRESULT),
+    #[cfg(any(feature = "directx10", feature = "directx11", feature = "directx12"))]
+    #[error("Error enumerating dxgi adapters `{0:#?}`")]
+    DxgiEnumAdapters(HRESULT),
+
     #[cfg(feature = "directx9")]
     #[error("Error creating directx9 instance `{0:#?}`")]
     DirectX9Create(HRESULT),
     #[cfg(feature = "directx9")]
     #[error("Error creating directx9 device `{0:#?}`")]
     DirectX9CreateDevice(HRESULT),
+    #[cfg(feature = "directx9")]
+    #[error("Error creating directx9ex device `{0:#?}`")]
+    DirectX9CreateDeviceEx(HRESULT),
+    #[cfg(feature = "directx9")]
+    #[error("Error getting directx9 swapchain `{0:#?}`")]
+    DirectX9GetSwapchain(HRESULT),
+
+    #[cfg(feature = "directx10")]
+    #[error("Error creating directx10 device `{0:#?}`")]
+    DirectX10CreateDeviceAndSwapchain(HRESULT),
+    #[cfg(feature = "directx10")]
+    #[error("Error creating directx10.1 device `{0:#?}`")]
+    DirectX10CreateDevice1(HRESULT),
 
     #[cfg(feature = "directx11")]
     #[error("Error creating directx11 device `{0:#?}`")]
@@ -144,6 +201,15 @@ pub enum ShroudError {
     #[error("Error creating directx12 command list `{0:#?}`")]
     DirectX12CreateCommandList(HRESULT),
     #[cfg(feature = "directx12")]
+    #[error("Error creating directx12 fence `{0:#?}`")]
+    DirectX12CreateFence(HRESULT),
+    #[cfg(feature = "directx12")]
+    #[error("Error creating directx12 resource `{0:#?}`")]
+    DirectX12CreateResource(HRESULT),
+    #[cfg(feature = "directx12")]
+    #[error("Error creating directx12 descriptor heap `{0:#?}`")]
+    DirectX12CreateDescriptorHeap(HRESULT),
+    #[cfg(feature = "directx12")]
     #[error("Error creating directx12 swapchain `{0:#?}`")]
     DirectX12CreateSwapchain(HRESULT),
 }
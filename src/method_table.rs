@@ -0,0 +1,566 @@
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use strum::{EnumCount, IntoEnumIterator};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::detour::Detour;
+use crate::{
+    module::{Location, Resolver},
+    protect, ShroudError, ShroudResult,
+};
+
+/// Names the COM interface whose vtable a method enum describes.
+pub trait InterfaceMethods {
+    const INTERFACE: &'static str;
+}
+
+/// One slot of the method enum `Methods`, with the function pointer type of the
+/// method behind it.
+///
+/// Every method enum comes with a module of marker types implementing this, one
+/// per variant, e.g. `directx11::swapchain::Present` for
+/// `DirectX11SwapchainMethods::Present`. Their signatures use portable types:
+/// enums pass as integers, interfaces and structs by pointer as `c_void`.
+pub trait Method {
+    type Methods;
+
+    const METHOD: Self::Methods;
+
+    /// `unsafe extern "system" fn` taking the object first, as the vtable holds it.
+    type Fn: Copy + Send + Sync;
+}
+
+/// A virtual method table typed by the enum `E` naming its slots.
+///
+/// The table always holds exactly `E::COUNT` entries, in the order `E::iter()`
+/// yields its variants. Each entry is attributed to its owning module when the
+/// table is built.
+///
+/// Tables read from a live object with [`crate::vtable::read`] also remember
+/// where that vtable lives, so its slots can be patched with [`MethodTable::hook`].
+pub struct MethodTable<E> {
+    entries: Vec<*const c_void>,
+    locations: Vec<Option<Location>>,
+    vtable: *mut *const c_void,
+    methods: PhantomData<E>,
+}
+
+impl<E> MethodTable<E>
+where
+    E: EnumCount + IntoEnumIterator + PartialEq,
+{
+    pub fn new(entries: Vec<*const c_void>) -> ShroudResult<Self> {
+        if entries.len() != E::COUNT {
+            return Err(ShroudError::MethodTableLength {
+                expected: E::COUNT,
+                actual: entries.len(),
+            });
+        }
+
+        let mut resolver = Resolver::default();
+        let locations = entries
+            .iter()
+            .map(|&address| resolver.locate(address))
+            .collect();
+
+        Ok(Self {
+            entries,
+            locations,
+            vtable: std::ptr::null_mut(),
+            methods: PhantomData,
+        })
+    }
+
+    /// Like [`MethodTable::new`], reading the entries from the live `vtable`.
+    ///
+    /// # Safety
+    ///
+    /// `vtable` must be non-null, aligned and have at least `E::COUNT` readable entries.
+    pub(crate) unsafe fn from_vtable(vtable: *const *const c_void) -> ShroudResult<Self> {
+        let entries = std::slice::from_raw_parts(vtable, E::COUNT).to_vec();
+        Ok(Self {
+            vtable: vtable.cast_mut(),
+            ..Self::new(entries)?
+        })
+    }
+
+    /// Rebuilds a table from recorded locations alone, for offline analysis of
+    /// tables discovered elsewhere. Every entry's address is null.
+    pub(crate) fn from_locations(locations: Vec<Option<Location>>) -> ShroudResult<Self> {
+        if locations.len() != E::COUNT {
+            return Err(ShroudError::MethodTableLength {
+                expected: E::COUNT,
+                actual: locations.len(),
+            });
+        }
+
+        Ok(Self {
+            entries: vec![std::ptr::null(); locations.len()],
+            locations,
+            vtable: std::ptr::null_mut(),
+            methods: PhantomData,
+        })
+    }
+
+    /// Slot index of `method` within the table.
+    pub fn slot(method: E) -> usize {
+        E::iter()
+            .position(|m| m == method)
+            .expect("enum variant missing from its own iterator")
+    }
+
+    pub fn get(&self, method: E) -> *const c_void {
+        self.entries[Self::slot(method)]
+    }
+
+    /// The function `M` resolves to as its typed function pointer, `None` for
+    /// tables without addresses.
+    pub fn typed<M: Method<Methods = E>>(&self) -> Option<M::Fn> {
+        let address = self.get(M::METHOD);
+        (!address.is_null()).then(|| unsafe { std::mem::transmute_copy(&address) })
+    }
+
+    /// Owning module and RVA of `method`, if its module could be resolved.
+    pub fn location(&self, method: E) -> Option<&Location> {
+        self.locations[Self::slot(method)].as_ref()
+    }
+
+    pub fn locations(&self) -> impl Iterator<Item = (E, Option<&Location>)> + '_ {
+        E::iter().zip(self.locations.iter().map(Option::as_ref))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (E, *const c_void)> + '_ {
+        E::iter().zip(self.entries.iter().copied())
+    }
+
+    pub fn as_slice(&self) -> &[*const c_void] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Address of the live vtable this table was read from, null for tables
+    /// built from entries or snapshots.
+    pub fn vtable(&self) -> *const *const c_void {
+        self.vtable
+    }
+
+    /// Points `method` at `detour` in the shared vtable, for every object using it.
+    ///
+    /// The slot is swapped atomically while its page is made writable, and the
+    /// returned guard puts the original function back when dropped. The table's
+    /// own entries keep the addresses seen at discovery.
+    ///
+    /// # Safety
+    ///
+    /// The vtable must still be mapped, and `F` must be a function pointer type
+    /// matching the ABI and signature of `method`.
+    pub unsafe fn hook<F: Copy>(&self, method: E, detour: F) -> ShroudResult<HookGuard<F>> {
+        assert_eq!(
+            std::mem::size_of::<F>(),
+            std::mem::size_of::<*const c_void>(),
+            "detour must be a function pointer"
+        );
+        if self.vtable.is_null() {
+            return Err(ShroudError::DetachedTable);
+        }
+
+        let slot = self.vtable.add(Self::slot(method));
+        let detour: *const c_void = std::mem::transmute_copy(&detour);
+        let original = swap(slot, detour)?;
+
+        Ok(HookGuard {
+            slot,
+            original,
+            detour,
+            function: PhantomData,
+        })
+    }
+
+    /// Detours the function `method` resolves to, see [`Detour`].
+    ///
+    /// Unlike [`MethodTable::hook`] this catches every call to the function, not
+    /// only those made through the vtable.
+    ///
+    /// # Safety
+    ///
+    /// See [`Detour::new`].
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub unsafe fn detour<F: Copy>(&self, method: E, detour: F) -> ShroudResult<Detour<F>> {
+        let target = self.get(method);
+        if target.is_null() {
+            return Err(ShroudError::DetachedTable);
+        }
+        Detour::new(target, detour)
+    }
+}
+
+/// Atomically replaces the function pointer in `slot`, returning the previous one.
+unsafe fn swap(slot: *mut *const c_void, function: *const c_void) -> ShroudResult<*const c_void> {
+    protect::patch(slot.cast(), std::mem::size_of::<*const c_void>(), || {
+        let slot = &*(slot as *const AtomicPtr<c_void>);
+        slot.swap(function.cast_mut(), Ordering::AcqRel)
+            .cast_const()
+    })
+}
+
+/// A patched vtable slot, restored to its original function on drop.
+pub struct HookGuard<F> {
+    slot: *mut *const c_void,
+    original: *const c_void,
+    detour: *const c_void,
+    function: PhantomData<F>,
+}
+
+impl<F: Copy> HookGuard<F> {
+    /// The function the slot held before hooking, for the detour to call through.
+    pub fn original(&self) -> F {
+        unsafe { std::mem::transmute_copy(&self.original) }
+    }
+
+    /// Restores the slot now, reporting a slot that cannot be made writable
+    /// instead of ignoring it as drop does. Like every patch, the slot's page is
+    /// left writable if its protection cannot be restored afterwards.
+    pub fn unhook(self) -> ShroudResult<()> {
+        let result = unsafe { self.restore() };
+        std::mem::forget(self);
+        result.map(|_| ())
+    }
+}
+
+impl<F> HookGuard<F> {
+    /// Puts the original function back, unless the slot was re-hooked on top of
+    /// this detour, in which case it is left to whoever hooked it last. Returns
+    /// whether the slot was restored.
+    pub(crate) unsafe fn restore(&self) -> ShroudResult<bool> {
+        let (slot, original, detour) = (self.slot, self.original, self.detour);
+        protect::patch(slot.cast(), std::mem::size_of::<*const c_void>(), || {
+            let slot = &*(slot as *const AtomicPtr<c_void>);
+            slot.compare_exchange(
+                detour.cast_mut(),
+                original.cast_mut(),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
+        })
+    }
+}
+
+// The guard only refers to a slot of a shared vtable, which it patches under the
+// same lock from whichever thread drops it.
+unsafe impl<F: Send> Send for HookGuard<F> {}
+unsafe impl<F: Sync> Sync for HookGuard<F> {}
+
+impl<F> Drop for HookGuard<F> {
+    fn drop(&mut self) {
+        let _ = unsafe { self.restore() };
+    }
+}
+
+impl<F> std::fmt::Debug for HookGuard<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HookGuard")
+            .field("slot", &self.slot)
+            .field("original", &self.original)
+            .field("detour", &self.detour)
+            .finish()
+    }
+}
+
+impl<E> Clone for MethodTable<E> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            locations: self.locations.clone(),
+            vtable: self.vtable,
+            methods: PhantomData,
+        }
+    }
+}
+
+impl<E> std::fmt::Debug for MethodTable<E>
+where
+    E: EnumCount + IntoEnumIterator + PartialEq + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, ((method, address), location)) in self.iter().zip(&self.locations).enumerate() {
+            write!(f, "\t[{}] {:?} {:#?}", i, method, address)?;
+            match location {
+                Some(location) => writeln!(f, " {}", location)?,
+                None => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Declares the [`Method`] marker types of a method enum's slots, given each
+/// slot's parameters after the object pointer and its return type.
+#[cfg(any(
+    test,
+    feature = "directx9",
+    feature = "directx10",
+    feature = "directx11",
+    feature = "directx12"
+))]
+macro_rules! signatures {
+    (
+        $methods:ident;
+        $($method:ident($($param:ident: $ty:ty),*) $(-> $ret:ty)?,)+
+    ) => {
+        $(
+            #[doc = concat!("Signature of [`", stringify!($methods), "::", stringify!($method), "`].")]
+            #[derive(Debug, Clone, Copy)]
+            pub struct $method;
+
+            impl $crate::Method for $method {
+                type Methods = $methods;
+
+                const METHOD: $methods = $methods::$method;
+
+                type Fn = unsafe extern "system" fn(this: *mut c_void, $($param: $ty),*) $(-> $ret)?;
+            }
+        )+
+    };
+}
+#[cfg(any(
+    test,
+    feature = "directx9",
+    feature = "directx10",
+    feature = "directx11",
+    feature = "directx12"
+))]
+pub(crate) use signatures;
+
+/// Declares an enum holding the method table of an object at the highest
+/// version of an interface family it supports, e.g. `IDXGISwapChain1..4`.
+///
+/// Versions are listed highest first; each one names its version number, method
+/// enum and `windows` interface. The enum gets `version()`, snapshot support and
+/// on Windows a `query()` that QueryInterfaces down the list.
+#[cfg(any(feature = "directx10", feature = "directx11", feature = "directx12"))]
+macro_rules! versioned_vmt {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($variant:ident($version:literal, $methods:ty, $interface:ty)),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        pub enum $name {
+            $($variant($crate::MethodTable<$methods>),)+
+        }
+
+        impl $name {
+            /// Interface version reached.
+            pub fn version(&self) -> u32 {
+                match self {
+                    $($name::$variant(_) => $version,)+
+                }
+            }
+
+            pub(crate) fn push_to(&self, snapshot: &mut $crate::Snapshot) {
+                match self {
+                    $($name::$variant(table) => snapshot.push(table),)+
+                }
+            }
+
+            /// The highest version recorded in `snapshot`, if any.
+            pub(crate) fn from_snapshot(
+                snapshot: &$crate::Snapshot,
+            ) -> $crate::ShroudResult<Option<Self>> {
+                $(
+                    if let Some(table) = snapshot.table::<$methods>()? {
+                        return Ok(Some($name::$variant(table)));
+                    }
+                )+
+                Ok(None)
+            }
+
+            /// Queries `obj` for each version, highest first, and reads the table of
+            /// the first one it supports. `None` if it supports none.
+            #[cfg(windows)]
+            pub(crate) fn query<I: windows::core::Interface>(
+                obj: &I,
+            ) -> $crate::ShroudResult<Option<Self>> {
+                $(
+                    if let Ok(obj) = obj.cast::<$interface>() {
+                        let raw = windows::core::Interface::as_raw(&obj);
+                        let table = unsafe { $crate::vtable::read::<$methods>(raw)? };
+                        return Ok(Some($name::$variant(table)));
+                    }
+                )+
+                Ok(None)
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($name::$variant(table) => write!(f, "{:?}", table),)+
+                }
+            }
+        }
+    };
+}
+#[cfg(any(feature = "directx10", feature = "directx11", feature = "directx12"))]
+pub(crate) use versioned_vmt;
+
+#[cfg(test)]
+mod tests {
+    use strum::{EnumCount, IntoEnumIterator};
+
+    use super::*;
+    use crate::testing::{detour, slot_fn, FakeMethods, FakeObject, SlotFn};
+
+    fn entries(len: usize) -> Vec<*const c_void> {
+        (0..len).map(slot_fn).collect()
+    }
+
+    #[test]
+    fn new_checks_the_length() {
+        for len in [0, FakeMethods::COUNT - 1, FakeMethods::COUNT + 1] {
+            let error = MethodTable::<FakeMethods>::new(entries(len)).unwrap_err();
+            assert!(matches!(
+                error,
+                ShroudError::MethodTableLength { expected, actual }
+                    if expected == FakeMethods::COUNT && actual == len
+            ));
+        }
+
+        let table = MethodTable::<FakeMethods>::new(entries(FakeMethods::COUNT)).unwrap();
+        assert_eq!(table.len(), FakeMethods::COUNT);
+        assert!(table.vtable().is_null());
+    }
+
+    #[test]
+    fn slots_follow_declaration_order() {
+        for (index, method) in FakeMethods::iter().enumerate() {
+            assert_eq!(MethodTable::slot(method), index);
+        }
+    }
+
+    #[test]
+    fn get_and_iter_read_the_vtable() {
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let table = unsafe { MethodTable::<FakeMethods>::from_vtable(object.vtable) }.unwrap();
+        assert_eq!(table.vtable(), object.vtable);
+
+        assert_eq!(table.get(FakeMethods::QueryInterface), slot_fn(0));
+        assert_eq!(table.get(FakeMethods::Present), slot_fn(3));
+
+        let iterated: Vec<_> = table.iter().collect();
+        let expected: Vec<_> = FakeMethods::iter()
+            .enumerate()
+            .map(|(index, method)| (method, slot_fn(index)))
+            .collect();
+        assert_eq!(iterated, expected);
+        assert_eq!(table.as_slice(), &entries(FakeMethods::COUNT)[..]);
+    }
+
+    #[test]
+    fn typed_calls_the_slot() {
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let table = unsafe { MethodTable::<FakeMethods>::from_vtable(object.vtable) }.unwrap();
+
+        let present = table.typed::<crate::testing::fake::Present>().unwrap();
+        assert_eq!(unsafe { present(object.as_ptr().cast_mut(), 7) }, 307);
+
+        let detached = MethodTable::<FakeMethods>::from_locations(vec![None; FakeMethods::COUNT]);
+        assert!(detached
+            .unwrap()
+            .typed::<crate::testing::fake::Present>()
+            .is_none());
+    }
+
+    #[test]
+    fn hooks_restore_the_slot() {
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let table = unsafe { MethodTable::<FakeMethods>::from_vtable(object.vtable) }.unwrap();
+
+        let guard = unsafe { table.hook(FakeMethods::Present, detour as SlotFn) }.unwrap();
+        assert_eq!(object.call(3, 1), 9001);
+        assert_eq!(
+            unsafe { (guard.original())(object.as_ptr().cast_mut(), 1) },
+            301
+        );
+        assert_eq!(table.get(FakeMethods::Present), slot_fn(3));
+
+        drop(guard);
+        assert_eq!(object.call(3, 1), 301);
+    }
+
+    #[test]
+    fn hooks_leave_later_hooks_in_place() {
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let table = unsafe { MethodTable::<FakeMethods>::from_vtable(object.vtable) }.unwrap();
+
+        let first = unsafe { table.hook(FakeMethods::Present, detour as SlotFn) }.unwrap();
+        let second = unsafe { table.hook(FakeMethods::Present, slot_fn(4)) }.unwrap();
+        first.unhook().unwrap();
+        assert_eq!(object.call(3, 1), 401);
+
+        second.unhook().unwrap();
+        assert_eq!(object.call(3, 1), 9001);
+    }
+
+    #[test]
+    fn detached_tables_cannot_be_hooked() {
+        let table = MethodTable::<FakeMethods>::new(entries(FakeMethods::COUNT)).unwrap();
+        let result = unsafe { table.hook(FakeMethods::Present, detour as SlotFn) };
+        assert!(matches!(result, Err(ShroudError::DetachedTable)));
+    }
+
+    /// A vtable on a page of its own, read-only like those in a module's
+    /// `.rdata`.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn hooks_read_only_vtables() {
+        use crate::module::maps::permissions;
+
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let vtable = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                page,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+        assert_ne!(vtable, libc::MAP_FAILED);
+        let vtable = vtable.cast::<*const c_void>();
+        for (index, entry) in entries(FakeMethods::COUNT).into_iter().enumerate() {
+            unsafe { vtable.add(index).write(entry) };
+        }
+        assert_eq!(
+            unsafe { libc::mprotect(vtable.cast(), page, libc::PROT_READ) },
+            0
+        );
+        let object = FakeObject { vtable };
+
+        let table = unsafe { MethodTable::<FakeMethods>::from_vtable(vtable) }.unwrap();
+        let guard = unsafe { table.hook(FakeMethods::Present, detour as SlotFn) }.unwrap();
+        assert_eq!(object.call(3, 1), 9001);
+        assert_eq!(permissions(vtable as usize).as_deref(), Some("r--p"));
+
+        guard.unhook().unwrap();
+        assert_eq!(object.call(3, 1), 301);
+        assert_eq!(permissions(vtable as usize).as_deref(), Some("r--p"));
+
+        unsafe { libc::munmap(vtable.cast(), page) };
+    }
+}
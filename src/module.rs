@@ -0,0 +1,453 @@
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// A loaded module (dll, shared object or executable) owning some code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Module {
+    pub name: String,
+    pub path: PathBuf,
+    pub base: usize,
+    /// File version on Windows (`10.0.22621.2506`), soname version elsewhere.
+    pub version: Option<String>,
+}
+
+/// Where an address lives: its owning module and the offset from that module's base.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub module: Arc<Module>,
+    pub rva: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}+{:#x}", self.module.name, self.rva)
+    }
+}
+
+/// Lists the modules loaded in a process.
+///
+/// Detection runs over this rather than the live process, so it can be fed any
+/// module list.
+pub trait ModuleEnumerator {
+    /// Every loaded module. Versions may be left out, they are looked up for the
+    /// modules that turn out to matter.
+    fn modules(&self) -> Vec<Module>;
+}
+
+/// The modules loaded in the current process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessModules;
+
+impl ModuleEnumerator for ProcessModules {
+    fn modules(&self) -> Vec<Module> {
+        process_modules()
+    }
+}
+
+impl ModuleEnumerator for [Module] {
+    fn modules(&self) -> Vec<Module> {
+        self.to_vec()
+    }
+}
+
+#[cfg(windows)]
+fn process_modules() -> Vec<Module> {
+    use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+    use windows::Win32::{
+        Foundation::CloseHandle,
+        System::Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Module32FirstW, Module32NextW, MODULEENTRY32W,
+            TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32,
+        },
+    };
+
+    let Ok(snapshot) =
+        (unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, 0) })
+    else {
+        return Vec::new();
+    };
+
+    let mut modules = Vec::new();
+    let mut entry = MODULEENTRY32W {
+        dwSize: std::mem::size_of::<MODULEENTRY32W>() as u32,
+        ..Default::default()
+    };
+    let mut next = unsafe { Module32FirstW(snapshot, &mut entry) };
+    while next.is_ok() {
+        let length = entry
+            .szExePath
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(entry.szExePath.len());
+        let path = PathBuf::from(OsString::from_wide(&entry.szExePath[..length]));
+        modules.push(Module::unversioned(entry.modBaseAddr as usize, path));
+        next = unsafe { Module32NextW(snapshot, &mut entry) };
+    }
+
+    let _ = unsafe { CloseHandle(snapshot) };
+    modules
+}
+
+#[cfg(target_os = "linux")]
+fn process_modules() -> Vec<Module> {
+    unsafe extern "C" fn callback(
+        info: *mut libc::dl_phdr_info,
+        _size: libc::size_t,
+        modules: *mut c_void,
+    ) -> libc::c_int {
+        let modules = &mut *(modules as *mut Vec<Module>);
+        let info = &*info;
+        // The main executable is listed without a name.
+        if !info.dlpi_name.is_null() {
+            let name = std::ffi::CStr::from_ptr(info.dlpi_name);
+            if !name.is_empty() {
+                let path = PathBuf::from(name.to_string_lossy().into_owned());
+                modules.push(Module::new(info.dlpi_addr as usize, path));
+            }
+        }
+        0
+    }
+
+    let mut modules: Vec<Module> = Vec::new();
+    unsafe { libc::dl_iterate_phdr(Some(callback), &mut modules as *mut _ as *mut c_void) };
+    modules
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn process_modules() -> Vec<Module> {
+    Vec::new()
+}
+
+/// Resolves the module owning `address`, if any.
+pub fn locate(address: *const c_void) -> Option<Location> {
+    Resolver::default().locate(address)
+}
+
+/// Resolves many addresses, sharing one [`Module`] per base address.
+#[derive(Default)]
+pub(crate) struct Resolver {
+    modules: HashMap<usize, Arc<Module>>,
+    #[cfg(target_os = "linux")]
+    maps: Option<Vec<maps::Mapping>>,
+}
+
+impl Resolver {
+    pub(crate) fn locate(&mut self, address: *const c_void) -> Option<Location> {
+        if address.is_null() {
+            return None;
+        }
+
+        let (base, path) = self.owner(address)?;
+        let rva = (address as usize).checked_sub(base)?;
+        let module = self
+            .modules
+            .entry(base)
+            .or_insert_with(|| Arc::new(Module::new(base, path)))
+            .clone();
+
+        Some(Location { module, rva })
+    }
+
+    #[cfg(windows)]
+    fn owner(&mut self, address: *const c_void) -> Option<(usize, PathBuf)> {
+        use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+        use windows::{
+            core::PCWSTR,
+            Win32::{
+                Foundation::HMODULE,
+                System::LibraryLoader::{
+                    GetModuleFileNameW, GetModuleHandleExW, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
+                    GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+                },
+            },
+        };
+
+        let mut handle = HMODULE::default();
+        unsafe {
+            GetModuleHandleExW(
+                GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS
+                    | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+                PCWSTR::from_raw(address.cast()),
+                &mut handle,
+            )
+            .ok()?
+        };
+
+        let base = handle.0 as usize;
+        if let Some(module) = self.modules.get(&base) {
+            return Some((base, module.path.clone()));
+        }
+
+        let mut buffer = [0u16; 1024];
+        let length = unsafe { GetModuleFileNameW(handle, &mut buffer) } as usize;
+        let path = PathBuf::from(OsString::from_wide(&buffer[..length]));
+        Some((base, path))
+    }
+
+    #[cfg(unix)]
+    fn owner(&mut self, address: *const c_void) -> Option<(usize, PathBuf)> {
+        let mut info = std::mem::MaybeUninit::<libc::Dl_info>::zeroed();
+        if unsafe { libc::dladdr(address, info.as_mut_ptr()) } != 0 {
+            let info = unsafe { info.assume_init() };
+            if !info.dli_fbase.is_null() && !info.dli_fname.is_null() {
+                let name = unsafe { std::ffi::CStr::from_ptr(info.dli_fname) };
+                if !name.is_empty() {
+                    let path = PathBuf::from(name.to_string_lossy().into_owned());
+                    return Some((info.dli_fbase as usize, path));
+                }
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            let maps = self.maps.get_or_insert_with(maps::read);
+            maps::owner(maps, address as usize)
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        None
+    }
+
+    #[cfg(not(any(windows, unix)))]
+    fn owner(&mut self, _address: *const c_void) -> Option<(usize, PathBuf)> {
+        None
+    }
+}
+
+impl Module {
+    fn new(base: usize, path: PathBuf) -> Self {
+        let version = file_version(&path);
+        Self {
+            version,
+            ..Self::unversioned(base, path)
+        }
+    }
+
+    fn unversioned(base: usize, path: PathBuf) -> Self {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string_lossy().into_owned());
+        Self {
+            name,
+            path,
+            base,
+            version: None,
+        }
+    }
+}
+
+#[cfg(windows)]
+pub(crate) fn file_version(path: &Path) -> Option<String> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::{
+        core::{w, PCWSTR},
+        Win32::Storage::FileSystem::{
+            GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW, VS_FIXEDFILEINFO,
+        },
+    };
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let name = PCWSTR::from_raw(wide.as_ptr());
+
+    let size = unsafe { GetFileVersionInfoSizeW(name, None) };
+    if size == 0 {
+        return None;
+    }
+
+    let mut data = vec![0u8; size as usize];
+    unsafe { GetFileVersionInfoW(name, 0, size, data.as_mut_ptr().cast()).ok()? };
+
+    let mut info: *mut c_void = std::ptr::null_mut();
+    let mut length = 0u32;
+    let found = unsafe { VerQueryValueW(data.as_ptr().cast(), w!("\\"), &mut info, &mut length) };
+    if !found.as_bool()
+        || info.is_null()
+        || (length as usize) < std::mem::size_of::<VS_FIXEDFILEINFO>()
+    {
+        return None;
+    }
+
+    let info = unsafe { &*(info as *const VS_FIXEDFILEINFO) };
+    Some(format!(
+        "{}.{}.{}.{}",
+        info.dwFileVersionMS >> 16,
+        info.dwFileVersionMS & 0xffff,
+        info.dwFileVersionLS >> 16,
+        info.dwFileVersionLS & 0xffff
+    ))
+}
+
+// Shared objects carry their version in the file name, e.g. `libvulkan.so.1.3.275`.
+#[cfg(not(windows))]
+pub(crate) fn file_version(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?;
+    let (_, version) = name.split_once(".so.")?;
+    Some(version.to_owned())
+}
+
+/// `/proc/self/maps` fallback for addresses `dladdr` can not attribute,
+/// such as the main executable on some loaders.
+#[cfg(target_os = "linux")]
+pub(crate) mod maps {
+    use std::path::PathBuf;
+
+    pub(crate) struct Mapping {
+        start: usize,
+        end: usize,
+        permissions: String,
+        offset: usize,
+        path: PathBuf,
+    }
+
+    /// File-backed mappings of the process.
+    pub(crate) fn read() -> Vec<Mapping> {
+        read_all()
+            .into_iter()
+            .filter(|mapping| mapping.path.starts_with("/"))
+            .collect()
+    }
+
+    fn read_all() -> Vec<Mapping> {
+        std::fs::read_to_string("/proc/self/maps")
+            .map(|maps| parse_all(&maps))
+            .unwrap_or_default()
+    }
+
+    pub(super) fn parse_all(maps: &str) -> Vec<Mapping> {
+        maps.lines().filter_map(parse).collect()
+    }
+
+    /// Permissions (`r-xp`) of the mapping holding `address`, anonymous ones included.
+    pub(crate) fn permissions(address: usize) -> Option<String> {
+        read_all()
+            .into_iter()
+            .find(|mapping| (mapping.start..mapping.end).contains(&address))
+            .map(|mapping| mapping.permissions)
+    }
+
+    // 55d0c5a00000-55d0c5a2c000 r-xp 00002000 08:01 1234 /usr/bin/game
+    fn parse(line: &str) -> Option<Mapping> {
+        let mut fields = line.split_whitespace();
+        let (start, end) = fields.next()?.split_once('-')?;
+        let permissions = fields.next()?;
+        let offset = fields.next()?;
+        let _device = fields.next()?;
+        let _inode = fields.next()?;
+        let path = fields.collect::<Vec<_>>().join(" ");
+
+        Some(Mapping {
+            start: usize::from_str_radix(start, 16).ok()?,
+            end: usize::from_str_radix(end, 16).ok()?,
+            permissions: permissions.to_owned(),
+            offset: usize::from_str_radix(offset, 16).ok()?,
+            path: PathBuf::from(path),
+        })
+    }
+
+    pub(crate) fn owner(maps: &[Mapping], address: usize) -> Option<(usize, PathBuf)> {
+        let mapping = maps
+            .iter()
+            .find(|mapping| (mapping.start..mapping.end).contains(&address))?;
+
+        // The module base is where its first page (file offset zero) was mapped.
+        let base = maps
+            .iter()
+            .filter(|m| m.path == mapping.path && m.offset == 0)
+            .map(|m| m.start)
+            .min()
+            .or_else(|| mapping.start.checked_sub(mapping.offset))?;
+
+        Some((base, mapping.path.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[inline(never)]
+    extern "C" fn in_test_binary() -> u32 {
+        42
+    }
+
+    #[test]
+    fn locates_a_function_of_the_test_binary() {
+        let address = in_test_binary as *const c_void;
+        let location = locate(address).expect("test binary function resolved");
+
+        let exe = std::env::current_exe().unwrap();
+        assert_eq!(
+            location.module.name,
+            exe.file_name().unwrap().to_string_lossy()
+        );
+        assert_ne!(location.rva, 0);
+        assert_eq!(location.module.base + location.rva, address as usize);
+    }
+
+    #[test]
+    fn shares_modules_between_addresses() {
+        let mut resolver = Resolver::default();
+        let first = resolver.locate(in_test_binary as *const c_void).unwrap();
+        let second = resolver
+            .locate(locates_a_function_of_the_test_binary as *const c_void)
+            .unwrap();
+        assert!(Arc::ptr_eq(&first.module, &second.module));
+    }
+
+    #[test]
+    fn null_has_no_location() {
+        assert_eq!(locate(std::ptr::null()), None);
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn versions_come_from_shared_object_names() {
+        assert_eq!(
+            file_version(Path::new("/usr/lib/libvulkan.so.1.3.275")).as_deref(),
+            Some("1.3.275")
+        );
+        assert_eq!(file_version(Path::new("/usr/lib/libvulkan.so")), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    mod maps {
+        use std::path::PathBuf;
+
+        use super::super::maps::{owner, parse_all};
+
+        const MAPS: &str = "\
+1000-2000 r--p 00000000 08:01 1 /usr/bin/game
+2000-5000 r-xp 00001000 08:01 1 /usr/bin/game
+7000-8000 rw-p 00000000 00:00 0
+9000-a000 r-xp 00004000 08:01 2 /usr/lib/libodd.so
+";
+
+        #[test]
+        fn base_is_the_first_mapped_page() {
+            let maps = parse_all(MAPS);
+            assert_eq!(
+                owner(&maps, 0x2345),
+                Some((0x1000, PathBuf::from("/usr/bin/game")))
+            );
+            assert_eq!(owner(&maps, 0x6000), None);
+        }
+
+        #[test]
+        fn base_falls_back_to_the_file_offset() {
+            let maps = parse_all(MAPS);
+            assert_eq!(
+                owner(&maps, 0x9100),
+                Some((0x5000, PathBuf::from("/usr/lib/libodd.so")))
+            );
+
+            // An offset past the mapping's start has no base to offer.
+            let maps = parse_all("1000-2000 r-xp 00004000 08:01 2 /usr/lib/libodd.so");
+            assert_eq!(owner(&maps, 0x1800), None);
+        }
+    }
+}
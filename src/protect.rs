@@ -0,0 +1,257 @@
+//! Temporarily writable memory, for patching vtables and code in place.
+
+use std::{ffi::c_void, sync::Mutex};
+
+use crate::{ShroudError, ShroudResult};
+
+/// Serializes patches, so two patches on one page do not restore each other's
+/// temporary protection.
+static PATCHING: Mutex<()> = Mutex::new(());
+
+/// Runs `patch` while `[address, address + len)` is writable, then restores the
+/// previous protection of each page involved, which may differ from page to
+/// page.
+///
+/// Once `patch` ran its result is returned even if the protection cannot be
+/// restored, leaving the pages writable. Failing instead would lose track of a
+/// patch that is already applied, with nobody left to undo it.
+///
+/// # Safety
+///
+/// The range must be mapped, and `patch` must leave it in a state other threads
+/// may observe at any moment.
+pub(crate) unsafe fn patch<T>(
+    address: *const c_void,
+    len: usize,
+    patch: impl FnOnce() -> T,
+) -> ShroudResult<T> {
+    let _patching = PATCHING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let previous = unprotect(address, len)?;
+    let result = patch();
+    let _ = reprotect(&previous);
+    Ok(result)
+}
+
+/// Pages sharing one protection before a patch.
+struct Span {
+    start: usize,
+    len: usize,
+    protection: Protection,
+}
+
+/// Makes the range writable a span at a time, recording each span's
+/// protection. Should a span fail, those already made writable are restored.
+unsafe fn unprotect(address: *const c_void, len: usize) -> ShroudResult<Vec<Span>> {
+    let end = address as usize + len.max(1);
+    let mut spans: Vec<Span> = Vec::new();
+    let mut next = address as usize;
+    while next < end {
+        match unprotect_span(next, end - next) {
+            Ok(span) => {
+                next = span.start + span.len;
+                spans.push(span);
+            }
+            Err(e) => {
+                let _ = reprotect(&spans);
+                return Err(e);
+            }
+        }
+    }
+    Ok(spans)
+}
+
+/// Restores every span, failing with the first that could not be.
+unsafe fn reprotect(spans: &[Span]) -> ShroudResult<()> {
+    let mut result = Ok(());
+    for span in spans {
+        let restored = reprotect_span(span);
+        if result.is_ok() {
+            result = restored;
+        }
+    }
+    result
+}
+
+#[cfg(windows)]
+type Protection = windows::Win32::System::Memory::PAGE_PROTECTION_FLAGS;
+
+/// Makes writable the region of pages of one protection `address` is in, up to
+/// `len` bytes of it.
+#[cfg(windows)]
+unsafe fn unprotect_span(address: usize, len: usize) -> ShroudResult<Span> {
+    use windows::Win32::System::Memory::{
+        VirtualProtect, VirtualQuery, MEMORY_BASIC_INFORMATION, PAGE_EXECUTE_READWRITE,
+    };
+
+    let mut region = MEMORY_BASIC_INFORMATION::default();
+    let size = std::mem::size_of::<MEMORY_BASIC_INFORMATION>();
+    if VirtualQuery(Some(address as *const c_void), &mut region, size) == 0 {
+        return Err(ShroudError::Protect(
+            address,
+            std::io::Error::last_os_error(),
+        ));
+    }
+    let len = len.min(region.BaseAddress as usize + region.RegionSize - address);
+
+    let mut protection = Protection::default();
+    VirtualProtect(
+        address as *const c_void,
+        len,
+        PAGE_EXECUTE_READWRITE,
+        &mut protection,
+    )
+    .map_err(|e| ShroudError::Protect(address, e.into()))?;
+    Ok(Span {
+        start: address,
+        len,
+        protection,
+    })
+}
+
+#[cfg(windows)]
+unsafe fn reprotect_span(span: &Span) -> ShroudResult<()> {
+    use windows::Win32::System::Memory::VirtualProtect;
+
+    let mut unused = Protection::default();
+    VirtualProtect(
+        span.start as *const c_void,
+        span.len,
+        span.protection,
+        &mut unused,
+    )
+    .map_err(|e| ShroudError::Protect(span.start, e.into()))
+}
+
+#[cfg(unix)]
+type Protection = libc::c_int;
+
+// Only Linux can tell a page's protection. Elsewhere patched memory is assumed
+// to be read-only, as vtables are, and kept executable in case it is code.
+#[cfg(unix)]
+fn protection(address: usize) -> Protection {
+    #[cfg(target_os = "linux")]
+    if let Some(permissions) = crate::module::maps::permissions(address) {
+        let permissions = permissions.as_bytes();
+        let mut protection = libc::PROT_NONE;
+        if permissions.first() == Some(&b'r') {
+            protection |= libc::PROT_READ;
+        }
+        if permissions.get(1) == Some(&b'w') {
+            protection |= libc::PROT_WRITE;
+        }
+        if permissions.get(2) == Some(&b'x') {
+            protection |= libc::PROT_EXEC;
+        }
+        return protection;
+    }
+
+    let _ = address;
+    libc::PROT_READ | libc::PROT_EXEC
+}
+
+/// Makes the page `address` is in writable.
+#[cfg(unix)]
+unsafe fn unprotect_span(address: usize, _len: usize) -> ShroudResult<Span> {
+    let page = libc::sysconf(libc::_SC_PAGESIZE) as usize;
+    let start = address & !(page - 1);
+    let protection = protection(start);
+    let writable = protection | libc::PROT_READ | libc::PROT_WRITE;
+    if libc::mprotect(start as *mut c_void, page, writable) != 0 {
+        return Err(ShroudError::Protect(
+            address,
+            std::io::Error::last_os_error(),
+        ));
+    }
+    Ok(Span {
+        start,
+        len: page,
+        protection,
+    })
+}
+
+#[cfg(unix)]
+unsafe fn reprotect_span(span: &Span) -> ShroudResult<()> {
+    if libc::mprotect(span.start as *mut c_void, span.len, span.protection) != 0 {
+        return Err(ShroudError::Protect(
+            span.start,
+            std::io::Error::last_os_error(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(any(windows, unix)))]
+type Protection = ();
+
+#[cfg(not(any(windows, unix)))]
+unsafe fn unprotect_span(address: usize, _len: usize) -> ShroudResult<Span> {
+    Err(ShroudError::Protect(
+        address,
+        std::io::ErrorKind::Unsupported.into(),
+    ))
+}
+
+#[cfg(not(any(windows, unix)))]
+unsafe fn reprotect_span(_span: &Span) -> ShroudResult<()> {
+    Ok(())
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::module::maps::permissions;
+
+    /// Two fresh pages, the first read-only and the second writable.
+    fn pages() -> (*mut u8, usize) {
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let pages = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                2 * page,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+        assert_ne!(pages, libc::MAP_FAILED);
+        assert_eq!(unsafe { libc::mprotect(pages, page, libc::PROT_READ) }, 0);
+        (pages.cast(), page)
+    }
+
+    #[test]
+    fn restores_each_page_of_a_patch() {
+        let (pages, page) = pages();
+        let across = unsafe { pages.add(page - 4) };
+
+        let patched = unsafe {
+            patch(across.cast(), 8, || {
+                across.cast::<u64>().write_unaligned(u64::MAX);
+                across.cast::<u64>().read_unaligned()
+            })
+        };
+        assert_eq!(patched.unwrap(), u64::MAX);
+        assert_eq!(permissions(pages as usize).as_deref(), Some("r--p"));
+        assert_eq!(permissions(pages as usize + page).as_deref(), Some("rw-p"));
+
+        unsafe { libc::munmap(pages.cast(), 2 * page) };
+    }
+
+    #[test]
+    fn restores_pages_before_one_that_fails() {
+        let (pages, page) = pages();
+        unsafe { libc::munmap(pages.add(page).cast(), page) };
+        let across = unsafe { pages.add(page - 4) };
+
+        let mut patched = false;
+        let result = unsafe { patch(across.cast(), 8, || patched = true) };
+        assert!(matches!(result, Err(ShroudError::Protect(..))));
+        assert!(!patched);
+        assert_eq!(permissions(pages as usize).as_deref(), Some("r--p"));
+
+        unsafe { libc::munmap(pages.cast(), page) };
+    }
+}
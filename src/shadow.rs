@@ -0,0 +1,254 @@
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::{MethodTable, ShroudError, ShroudResult};
+
+/// Hooks a single object by giving it a private copy of its vtable.
+///
+/// The object's vtable pointer is repointed at the copy, so swapping a slot of
+/// the copy only affects calls made through that one object. Other objects of
+/// the same class, e.g. swap chains of other D3D users in the process, keep the
+/// shared vtable untouched.
+///
+/// The word before the vtable comes along too: MSVC keeps the class' complete
+/// object locator there, which `typeid`, `dynamic_cast` and RTTI-based tools
+/// read through the object.
+///
+/// Dropping the shadow points the object back at its original vtable.
+pub struct ShadowVmt<E> {
+    obj: *const AtomicPtr<AtomicPtr<c_void>>,
+    original: *mut AtomicPtr<c_void>,
+    /// The word before the vtable, then the copied entries.
+    shadow: Box<[AtomicPtr<c_void>]>,
+    methods: PhantomData<E>,
+}
+
+impl<E> ShadowVmt<E>
+where
+    E: EnumCount + IntoEnumIterator + PartialEq,
+{
+    /// Copies the `len` entries of the vtable of `obj`, and the word before
+    /// them, and installs the copy on `obj`.
+    ///
+    /// `len` is the slot count of the most derived interface `obj` implements,
+    /// which live objects tend to have beyond `E`, e.g. an `IDXGISwapChain4`
+    /// shadowed for its `IDXGISwapChain` methods. Calls to slots past the copy
+    /// would read beyond its end.
+    ///
+    /// # Safety
+    ///
+    /// `obj` must point to a live COM-style object whose vtable has at least
+    /// `len` entries preceded by a readable word, and must outlive the
+    /// returned shadow.
+    pub unsafe fn new(obj: *mut c_void, len: usize) -> ShroudResult<Self> {
+        if len < E::COUNT {
+            return Err(ShroudError::MethodTableLength {
+                expected: E::COUNT,
+                actual: len,
+            });
+        }
+        if obj.is_null() {
+            return Err(ShroudError::NullPointer("object"));
+        }
+        if !obj.cast::<*mut *const c_void>().is_aligned() {
+            return Err(ShroudError::MisalignedPointer("object", obj as usize));
+        }
+
+        let obj = obj as *const AtomicPtr<AtomicPtr<c_void>>;
+        let original = (*obj).load(Ordering::Acquire);
+        if original.is_null() {
+            return Err(ShroudError::NullPointer("vtable"));
+        }
+        if !original.is_aligned() {
+            return Err(ShroudError::MisalignedPointer("vtable", original as usize));
+        }
+
+        let shadow: Box<[AtomicPtr<c_void>]> = std::slice::from_raw_parts(original.sub(1), len + 1)
+            .iter()
+            .map(|entry| AtomicPtr::new(entry.load(Ordering::Relaxed)))
+            .collect();
+        let mut shadow = Self {
+            obj,
+            original,
+            shadow,
+            methods: PhantomData,
+        };
+        (*obj).store(shadow.installed(), Ordering::Release);
+        Ok(shadow)
+    }
+
+    /// Points `method` of this object at `detour`, returning the original function.
+    pub fn hook(&mut self, method: E, detour: *const c_void) -> *const c_void {
+        let slot = MethodTable::<E>::slot(method);
+        self.entries()[slot].store(detour.cast_mut(), Ordering::Release);
+        self.original(slot)
+    }
+
+    /// Points `method` of this object back at its original function.
+    pub fn unhook(&mut self, method: E) {
+        let slot = MethodTable::<E>::slot(method);
+        self.entries()[slot].store(self.original(slot).cast_mut(), Ordering::Release);
+    }
+
+    /// The function `method` resolves to in the shared, unhooked vtable.
+    pub fn original_fn(&self, method: E) -> *const c_void {
+        self.original(MethodTable::<E>::slot(method))
+    }
+
+    /// Whether `method` is currently hooked on this object.
+    pub fn is_hooked(&self, method: E) -> bool {
+        let slot = MethodTable::<E>::slot(method);
+        self.entries()[slot].load(Ordering::Acquire).cast_const() != self.original(slot)
+    }
+
+    fn original(&self, slot: usize) -> *const c_void {
+        unsafe { (*self.original.add(slot)).load(Ordering::Relaxed) }
+    }
+}
+
+impl<E> ShadowVmt<E> {
+    /// The copied entries, without the word before them.
+    fn entries(&self) -> &[AtomicPtr<c_void>] {
+        &self.shadow[1..]
+    }
+
+    /// What the object points at while the shadow is installed.
+    fn installed(&mut self) -> *mut AtomicPtr<c_void> {
+        self.shadow[1..].as_mut_ptr()
+    }
+}
+
+impl<E> Drop for ShadowVmt<E> {
+    fn drop(&mut self) {
+        let installed = unsafe {
+            (*self.obj).compare_exchange(
+                self.installed(),
+                self.original,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+        };
+
+        // Someone repointed the object since, possibly at a copy of our shadow that
+        // will eventually be restored to it, so the shadow must stay alive.
+        if installed.is_err() {
+            std::mem::forget(std::mem::take(&mut self.shadow));
+        }
+    }
+}
+
+impl<E> std::fmt::Debug for ShadowVmt<E>
+where
+    E: EnumCount + IntoEnumIterator + PartialEq + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (method, address)) in E::iter().zip(self.entries()).enumerate() {
+            let address = address.load(Ordering::Acquire).cast_const();
+            write!(f, "\t[{}] {:?} {:#?}", i, method, address)?;
+            match address != self.original(i) {
+                true => writeln!(f, " (hooked, original {:#?})", self.original(i))?,
+                false => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{detour, FakeMethods, FakeObject, LOCATOR, MAX_SLOTS};
+
+    #[test]
+    fn hooks_only_the_shadowed_object() {
+        let mut object = FakeObject::new(MAX_SLOTS);
+        let other = FakeObject::new(MAX_SLOTS);
+        let vtable = object.vtable;
+
+        let mut shadow =
+            unsafe { ShadowVmt::<FakeMethods>::new(object.as_mut_ptr(), MAX_SLOTS) }.unwrap();
+        assert_ne!(object.vtable, vtable);
+
+        let original = shadow.hook(FakeMethods::Present, detour as *const c_void);
+        assert_eq!(original, shadow.original_fn(FakeMethods::Present));
+        assert!(shadow.is_hooked(FakeMethods::Present));
+        assert!(!shadow.is_hooked(FakeMethods::ResizeBuffers));
+        assert_eq!(object.call(3, 1), 9001);
+        assert_eq!(other.call(3, 1), 301);
+
+        shadow.unhook(FakeMethods::Present);
+        assert!(!shadow.is_hooked(FakeMethods::Present));
+        assert_eq!(object.call(3, 1), 301);
+
+        drop(shadow);
+        assert_eq!(object.vtable, vtable);
+    }
+
+    #[test]
+    fn copies_slots_beyond_the_interface() {
+        let mut object = FakeObject::new(MAX_SLOTS);
+        let shadow =
+            unsafe { ShadowVmt::<FakeMethods>::new(object.as_mut_ptr(), MAX_SLOTS) }.unwrap();
+
+        // Slots of a derived interface the methods enum does not name.
+        for index in FakeMethods::COUNT..MAX_SLOTS {
+            assert_eq!(object.call(index, 2), index as u32 * 100 + 2);
+        }
+        drop(shadow);
+    }
+
+    #[test]
+    fn rejects_lengths_shorter_than_the_interface() {
+        let mut object = FakeObject::new(MAX_SLOTS);
+        let vtable = object.vtable;
+        let result = unsafe { ShadowVmt::<FakeMethods>::new(object.as_mut_ptr(), 4) };
+        assert!(matches!(
+            result,
+            Err(ShroudError::MethodTableLength {
+                expected: 5,
+                actual: 4
+            })
+        ));
+        assert_eq!(object.vtable, vtable);
+    }
+
+    #[test]
+    fn rejects_null_pointers() {
+        let result = unsafe { ShadowVmt::<FakeMethods>::new(std::ptr::null_mut(), MAX_SLOTS) };
+        assert!(matches!(result, Err(ShroudError::NullPointer("object"))));
+
+        let mut object = FakeObject {
+            vtable: std::ptr::null(),
+        };
+        let result = unsafe { ShadowVmt::<FakeMethods>::new(object.as_mut_ptr(), MAX_SLOTS) };
+        assert!(matches!(result, Err(ShroudError::NullPointer("vtable"))));
+    }
+
+    #[test]
+    fn keeps_the_shadow_when_repointed_since() {
+        let mut object = FakeObject::new(MAX_SLOTS);
+        let elsewhere = FakeObject::new(MAX_SLOTS).vtable;
+        let shadow =
+            unsafe { ShadowVmt::<FakeMethods>::new(object.as_mut_ptr(), MAX_SLOTS) }.unwrap();
+        object.vtable = elsewhere;
+        drop(shadow);
+        assert_eq!(object.vtable, elsewhere);
+    }
+
+    #[test]
+    fn keeps_the_word_before_the_vtable() {
+        let mut object = FakeObject::new(MAX_SLOTS);
+        let vtable = object.vtable;
+        let shadow =
+            unsafe { ShadowVmt::<FakeMethods>::new(object.as_mut_ptr(), MAX_SLOTS) }.unwrap();
+        assert_ne!(object.vtable, vtable);
+        assert_eq!(object.locator(), LOCATOR);
+        assert_eq!(unsafe { *object.vtable.sub(1) }, unsafe { *vtable.sub(1) });
+        drop(shadow);
+    }
+}
@@ -0,0 +1,310 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::{
+    method_table::InterfaceMethods,
+    module::{Location, Module},
+    MethodTable, RenderEngine, ShroudError, ShroudResult,
+};
+
+/// Format version written into every [`Snapshot`].
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// A host-independent record of discovered method tables.
+///
+/// Snapshots hold no live pointers, only where each method lived, so they can be
+/// archived per game build and driver version and loaded back on any OS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Snapshot {
+    pub version: u32,
+    pub engine: RenderEngine,
+    pub entries: Vec<SnapshotEntry>,
+}
+
+/// One vtable slot of one interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SnapshotEntry {
+    pub interface: String,
+    pub slot: usize,
+    pub method: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub module: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub module_version: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rva: Option<u64>,
+}
+
+impl Snapshot {
+    pub fn new(engine: RenderEngine) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            engine,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Records every slot of `table`.
+    pub fn push<E>(&mut self, table: &MethodTable<E>)
+    where
+        E: InterfaceMethods + EnumCount + IntoEnumIterator + PartialEq + std::fmt::Debug,
+    {
+        for (slot, (method, location)) in table.locations().enumerate() {
+            self.entries.push(SnapshotEntry {
+                interface: E::INTERFACE.to_owned(),
+                slot,
+                method: format!("{:?}", method),
+                module: location.map(|l| l.module.name.clone()),
+                module_version: location.and_then(|l| l.module.version.clone()),
+                rva: location.map(|l| l.rva as u64),
+            });
+        }
+    }
+
+    /// Rebuilds the table of `E`'s interface, or `None` if it was not recorded.
+    ///
+    /// The rebuilt table carries module names, versions and RVAs but null addresses.
+    pub fn table<E>(&self) -> ShroudResult<Option<MethodTable<E>>>
+    where
+        E: InterfaceMethods + EnumCount + IntoEnumIterator + PartialEq + std::fmt::Debug,
+    {
+        let mut entries: Vec<&SnapshotEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.interface == E::INTERFACE)
+            .collect();
+        if entries.is_empty() {
+            return Ok(None);
+        }
+        entries.sort_by_key(|entry| entry.slot);
+
+        if entries.len() != E::COUNT {
+            return Err(ShroudError::MethodTableLength {
+                expected: E::COUNT,
+                actual: entries.len(),
+            });
+        }
+
+        let mut modules: HashMap<(&str, Option<&str>), Arc<Module>> = HashMap::new();
+        let mut locations = Vec::with_capacity(entries.len());
+        for (slot, (method, entry)) in E::iter().zip(entries).enumerate() {
+            let name = format!("{:?}", method);
+            if entry.slot != slot || entry.method != name {
+                return Err(ShroudError::Snapshot(format!(
+                    "{} slot {} is `{}`, expected `{}`",
+                    E::INTERFACE,
+                    entry.slot,
+                    entry.method,
+                    name
+                )));
+            }
+
+            let location = match (&entry.module, entry.rva) {
+                (Some(module), Some(rva)) => {
+                    let module = modules
+                        .entry((module, entry.module_version.as_deref()))
+                        .or_insert_with(|| {
+                            Arc::new(Module {
+                                name: module.clone(),
+                                path: PathBuf::from(module),
+                                base: 0,
+                                version: entry.module_version.clone(),
+                            })
+                        })
+                        .clone();
+                    let rva = usize::try_from(rva).map_err(|_| {
+                        ShroudError::Snapshot(format!("RVA {:#x} does not fit this host", rva))
+                    })?;
+                    Some(Location { module, rva })
+                }
+                _ => None,
+            };
+            locations.push(location);
+        }
+
+        MethodTable::from_locations(locations).map(Some)
+    }
+
+    /// Like [`Snapshot::table`], but the interface must have been recorded.
+    pub fn required_table<E>(&self) -> ShroudResult<MethodTable<E>>
+    where
+        E: InterfaceMethods + EnumCount + IntoEnumIterator + PartialEq + std::fmt::Debug,
+    {
+        self.table()?
+            .ok_or_else(|| ShroudError::Snapshot(format!("missing interface `{}`", E::INTERFACE)))
+    }
+
+    /// Checks the snapshot was written in a supported format for `engine`.
+    pub fn expect(&self, engine: RenderEngine) -> ShroudResult<()> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(ShroudError::Snapshot(format!(
+                "unsupported version {}, expected {}",
+                self.version, SNAPSHOT_VERSION
+            )));
+        }
+        if self.engine != engine {
+            return Err(ShroudError::Snapshot(format!(
+                "snapshot is for {:?}, expected {:?}",
+                self.engine, engine
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Implements `Serialize`/`Deserialize` for a method struct through its
+/// [`Snapshot`], given `snapshot()` and `TryFrom<&Snapshot>` on that struct.
+#[cfg(any(
+    feature = "directx9",
+    feature = "directx10",
+    feature = "directx11",
+    feature = "directx12"
+))]
+macro_rules! snapshot_serde {
+    ($methods:ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $methods {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.snapshot().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $methods {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let snapshot = $crate::snapshot::Snapshot::deserialize(deserializer)?;
+                Self::try_from(&snapshot).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+#[cfg(any(
+    feature = "directx9",
+    feature = "directx10",
+    feature = "directx11",
+    feature = "directx12"
+))]
+pub(crate) use snapshot_serde;
+
+#[cfg(test)]
+mod tests {
+    use strum::EnumCount;
+
+    use super::*;
+    use crate::testing::{FakeMethods, FakeObject};
+
+    fn snapshot() -> Snapshot {
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let table = unsafe { crate::vtable::read::<FakeMethods>(object.as_ptr()) }.unwrap();
+        let mut snapshot = Snapshot::new(RenderEngine::DirectX11);
+        snapshot.push(&table);
+        snapshot
+    }
+
+    #[test]
+    fn records_every_slot() {
+        let snapshot = snapshot();
+        assert_eq!(snapshot.entries.len(), FakeMethods::COUNT);
+
+        let present = &snapshot.entries[3];
+        assert_eq!(present.interface, "IFake");
+        assert_eq!(present.slot, 3);
+        assert_eq!(present.method, "Present");
+        assert!(present.module.is_some());
+        assert!(present.rva.is_some());
+    }
+
+    #[test]
+    fn rebuilds_the_recorded_table() {
+        let snapshot = snapshot();
+        let table = snapshot.required_table::<FakeMethods>().unwrap();
+        for (method, location) in table.locations() {
+            let entry = &snapshot.entries[MethodTable::slot(method)];
+            let location = location.unwrap();
+            assert_eq!(Some(&location.module.name), entry.module.as_ref());
+            assert_eq!(Some(location.rva as u64), entry.rva);
+        }
+        assert!(table.get(FakeMethods::Present).is_null());
+    }
+
+    #[test]
+    fn missing_interfaces_are_none() {
+        let snapshot = Snapshot::new(RenderEngine::DirectX11);
+        assert!(snapshot.table::<FakeMethods>().unwrap().is_none());
+        assert!(matches!(
+            snapshot.required_table::<FakeMethods>(),
+            Err(ShroudError::Snapshot(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_a_wrong_method_name() {
+        let mut snapshot = snapshot();
+        snapshot.entries[3].method = "Present1".to_owned();
+        let error = snapshot.table::<FakeMethods>().unwrap_err();
+        assert!(matches!(error, ShroudError::Snapshot(message) if message.contains("Present1")));
+    }
+
+    #[test]
+    fn rejects_a_wrong_slot() {
+        let mut snapshot = snapshot();
+        snapshot.entries[4].slot = 7;
+        assert!(matches!(
+            snapshot.table::<FakeMethods>(),
+            Err(ShroudError::Snapshot(_))
+        ));
+
+        let mut snapshot = self::snapshot();
+        snapshot.entries.pop();
+        assert!(matches!(
+            snapshot.table::<FakeMethods>(),
+            Err(ShroudError::MethodTableLength {
+                expected: 5,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn checks_version_and_engine() {
+        let mut snapshot = snapshot();
+        assert!(snapshot.expect(RenderEngine::DirectX11).is_ok());
+        assert!(snapshot.expect(RenderEngine::DirectX12).is_err());
+        snapshot.version = SNAPSHOT_VERSION + 1;
+        assert!(snapshot.expect(RenderEngine::DirectX11).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_json() {
+        let snapshot = snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded: Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, snapshot);
+        assert!(loaded.table::<FakeMethods>().unwrap().is_some());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_toml() {
+        let snapshot = snapshot();
+        let text = toml::to_string(&snapshot).unwrap();
+        let loaded: Snapshot = toml::from_str(&text).unwrap();
+        assert_eq!(loaded, snapshot);
+        assert!(loaded.table::<FakeMethods>().unwrap().is_some());
+    }
+}
@@ -8,10 +8,9 @@ use windows::Win32::{
 };
 
 pub fn get_window_from_swapchain(swapchain: &IDXGISwapChain) -> Option<HWND> {
-    let mut desc = default_swapchain_descriptor(HWND::default());
-    match unsafe { (swapchain).GetDesc(&mut desc) } {
+    match unsafe { (swapchain).GetDesc() } {
         Err(_e) => None,
-        Ok(_) => Some(desc.OutputWindow),
+        Ok(desc) => Some(desc.OutputWindow),
     }
 }
 
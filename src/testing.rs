@@ -0,0 +1,104 @@
+//! A COM-shaped object built in Rust, for exercising vtable code on any host.
+
+use std::ffi::c_void;
+
+use strum_macros::{EnumCount, EnumIter};
+
+use crate::InterfaceMethods;
+
+/// Slots of [`FakeObject`]'s interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum FakeMethods {
+    QueryInterface,
+    AddRef,
+    Release,
+    Present,
+    ResizeBuffers,
+}
+
+impl InterfaceMethods for FakeMethods {
+    const INTERFACE: &'static str = "IFake";
+}
+
+pub(crate) mod fake {
+    use std::ffi::c_void;
+
+    use super::FakeMethods;
+    use crate::method_table::signatures;
+
+    signatures! {
+        FakeMethods;
+        Present(arg: u32) -> u32,
+    }
+}
+
+/// Every slot function of a fake vtable has this signature.
+pub(crate) type SlotFn = unsafe extern "system" fn(this: *mut c_void, arg: u32) -> u32;
+
+/// Slot `N` returns `N * 100 + arg`, telling apart which slot was called.
+unsafe extern "system" fn slot<const N: u32>(_this: *mut c_void, arg: u32) -> u32 {
+    N * 100 + arg
+}
+
+/// The most slots a fake vtable can have.
+pub(crate) const MAX_SLOTS: usize = 8;
+
+const SLOTS: [SlotFn; MAX_SLOTS] = [
+    slot::<0>, slot::<1>, slot::<2>, slot::<3>, slot::<4>, slot::<5>, slot::<6>, slot::<7>,
+];
+
+/// What fake vtables keep in the word before their first slot, where MSVC puts
+/// the complete object locator.
+pub(crate) const LOCATOR: *const c_void = 0x10c0 as *const c_void;
+
+/// An object whose first field points at its vtable, like every COM object.
+#[repr(C)]
+pub(crate) struct FakeObject {
+    pub(crate) vtable: *const *const c_void,
+}
+
+impl FakeObject {
+    /// An object with a leaked vtable of `len` slots, each calling [`slot`],
+    /// preceded by [`LOCATOR`]. Tables longer than [`MAX_SLOTS`] repeat its
+    /// slot functions.
+    pub(crate) fn new(len: usize) -> Box<Self> {
+        let vtable: Vec<*const c_void> = std::iter::once(LOCATOR)
+            .chain((0..len).map(|index| slot_fn(index % MAX_SLOTS)))
+            .collect();
+        Box::new(Self {
+            vtable: Vec::leak(vtable)[1..].as_ptr(),
+        })
+    }
+
+    /// The word before the vtable the object points at now.
+    pub(crate) fn locator(&self) -> *const c_void {
+        unsafe { *self.vtable.sub(1) }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const c_void {
+        self as *const Self as *const c_void
+    }
+
+    /// For code that repoints the object at another vtable.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut c_void {
+        self as *mut Self as *mut c_void
+    }
+
+    /// Calls slot `index` through whichever vtable the object points at now.
+    pub(crate) fn call(&self, index: usize, arg: u32) -> u32 {
+        unsafe {
+            let function: SlotFn = std::mem::transmute(*self.vtable.add(index));
+            function(self.as_ptr().cast_mut(), arg)
+        }
+    }
+}
+
+/// What slot `index` of a fresh fake vtable points at.
+pub(crate) fn slot_fn(index: usize) -> *const c_void {
+    SLOTS[index] as *const c_void
+}
+
+/// Stand-in for a detour, telling its calls apart from every slot's.
+pub(crate) unsafe extern "system" fn detour(_this: *mut c_void, arg: u32) -> u32 {
+    9000 + arg
+}
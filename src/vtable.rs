@@ -0,0 +1,161 @@
+use std::ffi::c_void;
+
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::{MethodTable, ShroudError, ShroudResult};
+
+/// Reads the virtual method table of a COM-style object.
+///
+/// A COM-style object is any object whose first pointer-sized field points at
+/// its vtable, which is the layout of every DirectX interface pointer. This
+/// works equally on shroud's own throwaway devices and on the live objects a
+/// game hands to its hooks, e.g. `windows::core::Interface::as_raw`.
+///
+/// Null and misaligned pointers, for both the object and its vtable, are
+/// reported as errors instead of being dereferenced.
+///
+/// # Safety
+///
+/// If `obj` is non-null and aligned it must point to a live object whose vtable
+/// has at least `E::COUNT` readable entries.
+pub unsafe fn read<E>(obj: *const c_void) -> ShroudResult<MethodTable<E>>
+where
+    E: EnumCount + IntoEnumIterator + PartialEq,
+{
+    let obj = checked::<*const *const c_void>(obj.cast(), "object")?;
+    let vtable = checked::<*const c_void>(obj.read(), "vtable")?;
+    MethodTable::from_vtable(vtable)
+}
+
+fn checked<T>(ptr: *const T, what: &'static str) -> ShroudResult<*const T> {
+    if ptr.is_null() {
+        return Err(ShroudError::NullPointer(what));
+    }
+    if !ptr.is_aligned() {
+        return Err(ShroudError::MisalignedPointer(what, ptr as usize));
+    }
+    Ok(ptr)
+}
+
+/// Asserts that `$method` indexes the slot of `$field` in the `windows` crate's
+/// `$vtbl` layout. Used in `const` items so a wrong index fails the build.
+///
+/// The `windows` crate is empty on other targets, so the checks only exist when
+/// targeting Windows; CI cross-checks the Windows targets from Linux as well.
+#[cfg(all(
+    windows,
+    any(
+        feature = "directx9",
+        feature = "directx10",
+        feature = "directx11",
+        feature = "directx12"
+    )
+))]
+macro_rules! assert_slot {
+    ($vtbl:ty, $($field:ident).+ => $method:expr) => {
+        assert!(
+            std::mem::offset_of!($vtbl, $($field).+)
+                == $method as usize * std::mem::size_of::<usize>()
+        );
+    };
+}
+#[cfg(all(
+    windows,
+    any(
+        feature = "directx9",
+        feature = "directx10",
+        feature = "directx11",
+        feature = "directx12"
+    )
+))]
+pub(crate) use assert_slot;
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::c_void;
+
+    use super::*;
+    use crate::testing::{slot_fn, FakeMethods, FakeObject};
+
+    /// A `#[repr(C)]` vtable laid out by hand, and an object pointing at it.
+    #[repr(C)]
+    struct Vtbl {
+        query_interface: *const c_void,
+        add_ref: *const c_void,
+        release: *const c_void,
+        present: *const c_void,
+        resize_buffers: *const c_void,
+    }
+
+    #[repr(C)]
+    struct Object {
+        vtable: *const Vtbl,
+    }
+
+    #[test]
+    fn reads_a_hand_built_vtable() {
+        let vtbl = Vtbl {
+            query_interface: slot_fn(0),
+            add_ref: slot_fn(1),
+            release: slot_fn(2),
+            present: slot_fn(3),
+            resize_buffers: slot_fn(4),
+        };
+        let object = Object { vtable: &vtbl };
+
+        let table =
+            unsafe { read::<FakeMethods>(&object as *const Object as *const c_void) }.unwrap();
+        assert_eq!(table.vtable(), &vtbl as *const Vtbl as *const *const c_void);
+        assert_eq!(table.get(FakeMethods::Release), vtbl.release);
+        assert_eq!(table.get(FakeMethods::ResizeBuffers), vtbl.resize_buffers);
+    }
+
+    #[test]
+    fn reads_a_fake_object() {
+        let object = FakeObject::new(FakeMethods::COUNT);
+        let table = unsafe { read::<FakeMethods>(object.as_ptr()) }.unwrap();
+        assert_eq!(table.get(FakeMethods::Present), slot_fn(3));
+    }
+
+    #[test]
+    fn rejects_a_null_object() {
+        let error = unsafe { read::<FakeMethods>(std::ptr::null()) }.unwrap_err();
+        assert!(matches!(error, ShroudError::NullPointer("object")));
+    }
+
+    #[test]
+    fn rejects_a_null_vtable() {
+        let object = Object {
+            vtable: std::ptr::null(),
+        };
+        let error =
+            unsafe { read::<FakeMethods>(&object as *const Object as *const c_void) }.unwrap_err();
+        assert!(matches!(error, ShroudError::NullPointer("vtable")));
+    }
+
+    #[test]
+    fn rejects_a_misaligned_object() {
+        let objects = [0usize; 2];
+        let misaligned = unsafe { objects.as_ptr().cast::<u8>().add(1) };
+        let error = unsafe { read::<FakeMethods>(misaligned.cast()) }.unwrap_err();
+        assert!(matches!(
+            error,
+            ShroudError::MisalignedPointer("object", address) if address == misaligned as usize
+        ));
+    }
+
+    #[test]
+    fn rejects_a_misaligned_vtable() {
+        let vtbl = [slot_fn(0); 6];
+        let misaligned = unsafe { vtbl.as_ptr().cast::<u8>().add(1) };
+        let object = Object {
+            vtable: misaligned.cast(),
+        };
+        let error =
+            unsafe { read::<FakeMethods>(&object as *const Object as *const c_void) }.unwrap_err();
+        assert!(matches!(
+            error,
+            ShroudError::MisalignedPointer("vtable", address) if address == misaligned as usize
+        ));
+    }
+}
@@ -0,0 +1,490 @@
+//! Waiting for the process to load its renderer.
+//!
+//! A DLL injected early runs before the game loads `d3d11.dll` and friends, so
+//! there is nothing to discover yet. A watch rescans the loaded modules every
+//! time a module is loaded, and hands the first match to its callback on a
+//! thread of its own, outside the loader lock.
+
+use std::{
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    detect::detect_render_engines_in,
+    module::{Module, ModuleEnumerator, ProcessModules},
+    RenderEngine, ShroudError, ShroudResult,
+};
+
+/// Longest a watch sleeps between looks at the module list, for loads the
+/// platform does not report.
+const POLL: Duration = Duration::from_millis(10);
+
+#[derive(Default)]
+struct State {
+    /// Loads reported by the platform's notifications.
+    #[cfg(not(target_os = "linux"))]
+    loads: u64,
+    cancelled: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    wake: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    #[cfg(windows)]
+    fn loaded(&self) {
+        self.lock().loads += 1;
+        self.wake.notify_all();
+    }
+}
+
+/// A running watch, see [`watch`]. Dropping it cancels the watch unless the
+/// callback is already running.
+#[must_use = "the watch is cancelled as soon as it is dropped, see `Watch::detach`"]
+pub struct Watch<R> {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<Option<R>>>,
+}
+
+impl<R> Watch<R> {
+    /// Blocks until the callback has run and returns its result, or `None` once
+    /// the watch timed out.
+    pub fn wait(mut self) -> Option<R> {
+        let thread = self.thread.take()?;
+        match thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+
+    /// Stops watching, waiting for a callback already running to finish.
+    pub fn cancel(mut self) {
+        self.shared.lock().cancelled = true;
+        self.shared.wake.notify_all();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+
+    /// Lets the watch run on its own until it finds its module or times out.
+    pub fn detach(mut self) {
+        self.thread.take();
+    }
+
+    /// Whether the watch is over, with the callback run or not.
+    pub fn is_finished(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_none_or(|thread| thread.is_finished())
+    }
+}
+
+impl<R> Drop for Watch<R> {
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            self.shared.lock().cancelled = true;
+            self.shared.wake.notify_all();
+        }
+    }
+}
+
+impl<R> std::fmt::Debug for Watch<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watch")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// Runs `on_engine` with the process' render engine as soon as it is loaded,
+/// right away if it already is. The engine is the best guess of
+/// [`crate::detect_render_engines`].
+///
+/// The callback runs on the watch's own thread, where discovery such as
+/// `directx11::methods()` is safe to call; its result is what [`Watch::wait`]
+/// returns.
+pub fn watch<R: Send + 'static>(
+    on_engine: impl FnOnce(RenderEngine) -> R + Send + 'static,
+) -> ShroudResult<Watch<R>> {
+    watch_modules(None, best_engine, on_engine)
+}
+
+/// [`watch`], giving up once `timeout` has passed without a render engine.
+pub fn watch_timeout<R: Send + 'static>(
+    timeout: Duration,
+    on_engine: impl FnOnce(RenderEngine) -> R + Send + 'static,
+) -> ShroudResult<Watch<R>> {
+    watch_modules(Some(timeout), best_engine, on_engine)
+}
+
+fn best_engine(modules: &[Module]) -> Option<RenderEngine> {
+    detect_render_engines_in(modules)
+        .first()
+        .map(|detection| detection.engine)
+}
+
+/// Calls `find` on the process' modules whenever one is loaded, and `on_found`
+/// with the first thing it finds. Gives up after `timeout`, if any.
+pub fn watch_modules<T, R: Send + 'static>(
+    timeout: Option<Duration>,
+    mut find: impl FnMut(&[Module]) -> Option<T> + Send + 'static,
+    on_found: impl FnOnce(T) -> R + Send + 'static,
+) -> ShroudResult<Watch<R>> {
+    let shared = Arc::new(Shared::default());
+    let notifications = Notifications::register(&shared)?;
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    let worker = shared.clone();
+    let thread = std::thread::Builder::new()
+        .name("shroud-watch".to_owned())
+        .spawn(move || {
+            let shared = worker;
+            let mut seen = None;
+            loop {
+                let loads = notifications.loads(&shared);
+                if seen != Some(loads) {
+                    seen = Some(loads);
+                    if let Some(found) = find(&ProcessModules.modules()) {
+                        if shared.lock().cancelled {
+                            return None;
+                        }
+                        return Some(on_found(found));
+                    }
+                }
+
+                let wait = match deadline {
+                    Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                        Some(left) if !left.is_zero() => left.min(POLL),
+                        _ => return None,
+                    },
+                    None => POLL,
+                };
+                let state = shared.lock();
+                if state.cancelled {
+                    return None;
+                }
+                drop(shared.wake.wait_timeout(state, wait));
+            }
+        })
+        .map_err(ShroudError::Watch)?;
+
+    Ok(Watch {
+        shared,
+        thread: Some(thread),
+    })
+}
+
+/// `LdrRegisterDllNotification`, which calls back under the loader lock as
+/// each module is mapped.
+#[cfg(windows)]
+struct Notifications {
+    cookie: *mut std::ffi::c_void,
+    context: *const Shared,
+}
+
+// The cookie and context are only handed back to ntdll and released on drop.
+#[cfg(windows)]
+unsafe impl Send for Notifications {}
+
+#[cfg(windows)]
+type DllNotification = unsafe extern "system" fn(
+    reason: u32,
+    data: *const std::ffi::c_void,
+    context: *mut std::ffi::c_void,
+);
+
+#[cfg(windows)]
+impl Notifications {
+    const LOADED: u32 = 1;
+
+    fn register(shared: &Arc<Shared>) -> ShroudResult<Self> {
+        use std::ffi::c_void;
+        use windows::{
+            core::s,
+            Win32::System::LibraryLoader::{GetModuleHandleA, GetProcAddress},
+        };
+
+        type Register = unsafe extern "system" fn(
+            flags: u32,
+            notification: DllNotification,
+            context: *mut c_void,
+            cookie: *mut *mut c_void,
+        ) -> i32;
+
+        unsafe extern "system" fn notification(
+            reason: u32,
+            _data: *const c_void,
+            context: *mut c_void,
+        ) {
+            if reason == Notifications::LOADED {
+                (*(context as *const Shared)).loaded();
+            }
+        }
+
+        let register = unsafe {
+            let ntdll = GetModuleHandleA(s!("ntdll.dll"))
+                .map_err(|e| ShroudError::Watch(std::io::Error::other(e)))?;
+            GetProcAddress(ntdll, s!("LdrRegisterDllNotification"))
+        }
+        .ok_or(ShroudError::Watch(std::io::ErrorKind::Unsupported.into()))?;
+        let register: Register = unsafe { std::mem::transmute(register) };
+
+        let context = Arc::into_raw(shared.clone());
+        let mut cookie = std::ptr::null_mut();
+        let status = unsafe { register(0, notification, context as *mut c_void, &mut cookie) };
+        if status < 0 {
+            drop(unsafe { Arc::from_raw(context) });
+            return Err(ShroudError::Watch(std::io::Error::other(format!(
+                "LdrRegisterDllNotification failed with {status:#x}"
+            ))));
+        }
+        Ok(Self { cookie, context })
+    }
+
+    fn loads(&self, shared: &Shared) -> u64 {
+        shared.lock().loads
+    }
+}
+
+#[cfg(windows)]
+impl Drop for Notifications {
+    fn drop(&mut self) {
+        use windows::{
+            core::s,
+            Win32::System::LibraryLoader::{GetModuleHandleA, GetProcAddress},
+        };
+
+        type Unregister = unsafe extern "system" fn(cookie: *mut std::ffi::c_void) -> i32;
+
+        // Unregistering waits out a notification in progress, after which the
+        // context is no longer used.
+        unsafe {
+            let Ok(ntdll) = GetModuleHandleA(s!("ntdll.dll")) else {
+                return;
+            };
+            let Some(unregister) = GetProcAddress(ntdll, s!("LdrUnregisterDllNotification")) else {
+                return;
+            };
+            let unregister: Unregister = std::mem::transmute(unregister);
+            if unregister(self.cookie) >= 0 {
+                drop(Arc::from_raw(self.context));
+            }
+        }
+    }
+}
+
+/// glibc and musl count the modules ever loaded in `dl_iterate_phdr`'s
+/// `dlpi_adds`, which is polled.
+#[cfg(target_os = "linux")]
+struct Notifications;
+
+#[cfg(target_os = "linux")]
+impl Notifications {
+    fn register(_shared: &Arc<Shared>) -> ShroudResult<Self> {
+        Ok(Self)
+    }
+
+    fn loads(&self, _shared: &Shared) -> u64 {
+        unsafe extern "C" fn callback(
+            info: *mut libc::dl_phdr_info,
+            size: libc::size_t,
+            loads: *mut std::ffi::c_void,
+        ) -> libc::c_int {
+            if size >= std::mem::offset_of!(libc::dl_phdr_info, dlpi_subs) {
+                *(loads as *mut u64) = (*info).dlpi_adds;
+            }
+            1
+        }
+
+        let mut loads = 0u64;
+        unsafe { libc::dl_iterate_phdr(Some(callback), &mut loads as *mut u64 as *mut _) };
+        loads
+    }
+}
+
+/// Without a way to tell, every poll rescans.
+#[cfg(not(any(windows, target_os = "linux")))]
+struct Notifications;
+
+#[cfg(not(any(windows, target_os = "linux")))]
+impl Notifications {
+    fn register(_shared: &Arc<Shared>) -> ShroudResult<Self> {
+        Ok(Self)
+    }
+
+    fn loads(&self, shared: &Shared) -> u64 {
+        let mut state = shared.lock();
+        state.loads += 1;
+        state.loads
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn finds_loaded_modules_right_away() {
+        let watch = watch_modules(
+            Some(Duration::from_secs(5)),
+            |modules| modules.first().map(|module| module.name.clone()),
+            |name| name,
+        )
+        .unwrap();
+        assert!(watch.wait().is_some());
+    }
+
+    /// A copy of `libgcc_s`, which std links on GNU targets, under a name no
+    /// other module of the process has. Removed on drop.
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    struct LibraryCopy(std::path::PathBuf);
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    impl LibraryCopy {
+        fn new() -> Self {
+            static COPIES: AtomicUsize = AtomicUsize::new(0);
+
+            let modules = ProcessModules.modules();
+            let original = modules
+                .iter()
+                .find(|module| module.name.starts_with("libgcc_s.so"))
+                .expect("libgcc_s is loaded");
+            let name = format!(
+                "libshroud-watch-{}-{}.so",
+                std::process::id(),
+                COPIES.fetch_add(1, Ordering::Relaxed)
+            );
+            let path = std::env::temp_dir().join(name);
+            std::fs::copy(&original.path, &path).unwrap();
+            Self(path)
+        }
+
+        fn name(&self) -> String {
+            self.0.file_name().unwrap().to_string_lossy().into_owned()
+        }
+
+        /// Loads the copy, which dlopen maps anew as it is another file.
+        fn open(&self) -> *mut std::ffi::c_void {
+            use std::os::unix::ffi::OsStrExt;
+
+            let path = std::ffi::CString::new(self.0.as_os_str().as_bytes()).unwrap();
+            let handle = unsafe { libc::dlopen(path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+            assert!(!handle.is_null());
+            handle
+        }
+    }
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    impl Drop for LibraryCopy {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    #[test]
+    fn finds_modules_loaded_later() {
+        let library = LibraryCopy::new();
+        let name = library.name();
+        assert!(!ProcessModules
+            .modules()
+            .iter()
+            .any(|module| module.name == name));
+
+        let wanted = name.clone();
+        let watch = watch_modules(
+            Some(Duration::from_secs(5)),
+            move |modules| modules.iter().find(|module| module.name == wanted).cloned(),
+            |module| module.name,
+        )
+        .unwrap();
+        std::thread::sleep(POLL * 3);
+        assert!(!watch.is_finished());
+
+        let handle = library.open();
+        let found = watch.wait();
+        unsafe { libc::dlclose(handle) };
+        assert_eq!(found, Some(name));
+    }
+
+    #[test]
+    fn gives_up_after_the_timeout() {
+        let called = Arc::new(AtomicBool::new(false));
+        let flag = called.clone();
+        let start = Instant::now();
+        let watch = watch_modules(
+            Some(POLL * 5),
+            |_| None::<()>,
+            move |()| flag.store(true, Ordering::SeqCst),
+        )
+        .unwrap();
+        assert_eq!(watch.wait(), None);
+        assert!(start.elapsed() >= POLL * 5);
+        assert!(!called.load(Ordering::SeqCst));
+    }
+
+    fn never_found() -> (Watch<()>, Arc<AtomicUsize>) {
+        let looks = Arc::new(AtomicUsize::new(0));
+        let counter = looks.clone();
+        let watch = watch_modules(
+            None,
+            move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                None::<()>
+            },
+            |()| unreachable!(),
+        )
+        .unwrap();
+        (watch, looks)
+    }
+
+    #[test]
+    fn cancelling_stops_looking() {
+        let (watch, looks) = never_found();
+        std::thread::sleep(POLL * 3);
+        assert!(!watch.is_finished());
+        watch.cancel();
+
+        let after = looks.load(Ordering::SeqCst);
+        assert!(after >= 1);
+        std::thread::sleep(POLL * 3);
+        assert_eq!(looks.load(Ordering::SeqCst), after);
+    }
+
+    #[test]
+    fn dropping_cancels() {
+        let (watch, looks) = never_found();
+        drop(watch);
+        // The thread is not joined on drop, so give it a moment to notice.
+        std::thread::sleep(POLL * 3);
+        let after = looks.load(Ordering::SeqCst);
+        std::thread::sleep(POLL * 3);
+        assert_eq!(looks.load(Ordering::SeqCst), after);
+    }
+
+    #[test]
+    fn detached_watches_keep_running() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        watch_modules(
+            Some(Duration::from_secs(5)),
+            |modules| modules.first().map(|_| ()),
+            move |()| sender.send(()).unwrap(),
+        )
+        .unwrap()
+        .detach();
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+}
@@ -0,0 +1,415 @@
+//! Choosing the window devices and swap chains are created for.
+//!
+//! A process rarely owns a single top-level window: splash screens, launchers
+//! and hidden IME windows come and go next to the game's own. A
+//! [`WindowSelector`] scores every top-level window of the process and the
+//! best one wins, the topmost on a tie.
+//!
+//! Before the game has created any window, a [`TemporaryWindow`] stands in for
+//! one: discovery only needs a window to create its devices for, not the one
+//! frames end up in.
+
+use std::sync::Arc;
+
+use crate::{ShroudError, ShroudResult};
+
+mod temporary;
+pub use temporary::{TemporaryWindow, TemporaryWindowGuard};
+
+/// Supplies the window discovery creates its devices and swap chains for.
+pub trait WindowProvider {
+    /// The window, kept alive until it is dropped.
+    type Window: ProvidedWindow;
+
+    fn acquire(&self) -> ShroudResult<Self::Window>;
+}
+
+/// A window handed out by a [`WindowProvider`].
+pub trait ProvidedWindow {
+    /// The `HWND`.
+    fn handle(&self) -> usize;
+}
+
+/// A window of the process, which is not the provider's to release.
+impl ProvidedWindow for usize {
+    fn handle(&self) -> usize {
+        *self
+    }
+}
+
+/// What is known of a top-level window when choosing one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowInfo {
+    /// The `HWND`.
+    pub handle: usize,
+    pub class: String,
+    pub title: String,
+    pub visible: bool,
+    pub foreground: bool,
+    /// Client area size.
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowInfo {
+    fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+}
+
+/// Scores a window for [`WindowSelector::Custom`].
+pub type WindowScore = Arc<dyn Fn(&WindowInfo) -> Option<u64> + Send + Sync>;
+
+/// How to pick the window of the process to create devices for.
+#[derive(Clone, Default)]
+pub enum WindowSelector {
+    /// Any window, visible ones first, then the largest.
+    #[default]
+    Any,
+    /// The largest visible window.
+    LargestVisible,
+    /// Windows of this class, compared without case.
+    ClassName(String),
+    /// Windows whose title matches this pattern, compared without case. `*`
+    /// matches any run of characters and `?` any single one.
+    Title(String),
+    /// The foreground window, if it belongs to the process.
+    Foreground,
+    /// This `HWND`, whether or not it is a top-level window of the process.
+    Handle(usize),
+    /// Windows the callback scores, the highest score winning. `None` rules a
+    /// window out.
+    Custom(WindowScore),
+}
+
+impl WindowSelector {
+    /// A [`WindowSelector::Custom`] scoring windows with `score`.
+    pub fn custom(score: impl Fn(&WindowInfo) -> Option<u64> + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(score))
+    }
+
+    /// Whether `window` is a candidate, and how strongly this strategy prefers
+    /// it over the other candidates.
+    pub fn score(&self, window: &WindowInfo) -> Option<u64> {
+        match self {
+            Self::Any => Some(0),
+            Self::LargestVisible => (window.visible && window.area() > 0).then_some(0),
+            Self::ClassName(class) => window.class.eq_ignore_ascii_case(class).then_some(0),
+            Self::Title(pattern) => matches(pattern, &window.title).then_some(0),
+            Self::Foreground => window.foreground.then_some(0),
+            Self::Handle(handle) => (window.handle == *handle).then_some(0),
+            Self::Custom(score) => score(window),
+        }
+    }
+
+    /// The best of `windows`, listed topmost first: the highest score, then
+    /// visible over hidden, then the largest, then the topmost.
+    pub fn select<'a>(&self, windows: &'a [WindowInfo]) -> Option<&'a WindowInfo> {
+        windows
+            .iter()
+            .enumerate()
+            .filter_map(|(z, window)| {
+                let score = self.score(window)?;
+                Some((
+                    (score, window.visible, window.area(), std::cmp::Reverse(z)),
+                    window,
+                ))
+            })
+            .max_by_key(|&(rank, _)| rank)
+            .map(|(_, window)| window)
+    }
+}
+
+impl std::fmt::Debug for WindowSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "Any"),
+            Self::LargestVisible => write!(f, "LargestVisible"),
+            Self::ClassName(class) => f.debug_tuple("ClassName").field(class).finish(),
+            Self::Title(pattern) => f.debug_tuple("Title").field(pattern).finish(),
+            Self::Foreground => write!(f, "Foreground"),
+            Self::Handle(handle) => write!(f, "Handle({handle:#x})"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Whether `text` matches the wildcard `pattern`, without case.
+fn matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    // Greedy matching, backtracking to the last `*` on a mismatch.
+    let (mut p, mut t) = (0, 0);
+    let mut star = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+impl WindowProvider for WindowSelector {
+    type Window = usize;
+
+    fn acquire(&self) -> ShroudResult<usize> {
+        find(self).ok_or(ShroudError::Window)
+    }
+}
+
+/// The window `selector` picks among the top-level windows of the process.
+fn find(selector: &WindowSelector) -> Option<usize> {
+    #[cfg(windows)]
+    if let WindowSelector::Handle(handle) = selector {
+        use windows::Win32::{Foundation::HWND, UI::WindowsAndMessaging::IsWindow};
+
+        let window = HWND(*handle as *mut _);
+        return unsafe { IsWindow(window) }.as_bool().then_some(*handle);
+    }
+
+    let windows = process_windows();
+    Some(selector.select(&windows)?.handle)
+}
+
+/// Top-level windows of the process, topmost first. Only Windows has any.
+#[cfg(not(windows))]
+pub fn process_windows() -> Vec<WindowInfo> {
+    Vec::new()
+}
+
+/// Top-level windows of the process, topmost first.
+#[cfg(windows)]
+pub fn process_windows() -> Vec<WindowInfo> {
+    use windows::Win32::{
+        Foundation::{BOOL, HWND, LPARAM, RECT},
+        UI::WindowsAndMessaging::{
+            EnumWindows, GetClassNameW, GetClientRect, GetForegroundWindow, GetWindowTextW,
+            GetWindowThreadProcessId, IsWindowVisible,
+        },
+    };
+
+    extern "system" fn enum_windows_callback(hwnd: HWND, l_param: LPARAM) -> BOOL {
+        let mut wnd_proc_id: u32 = 0;
+        unsafe {
+            GetWindowThreadProcessId(hwnd, Some(&mut wnd_proc_id));
+            if std::process::id() == wnd_proc_id {
+                (*(l_param.0 as *mut Vec<HWND>)).push(hwnd);
+            }
+        }
+        true.into()
+    }
+
+    let mut handles: Vec<HWND> = Vec::new();
+    let _ = unsafe {
+        EnumWindows(
+            Some(enum_windows_callback),
+            LPARAM(&mut handles as *mut Vec<HWND> as isize),
+        )
+    };
+
+    let foreground = unsafe { GetForegroundWindow() };
+    handles
+        .into_iter()
+        .map(|hwnd| {
+            let mut class = [0u16; 256];
+            let class_len = unsafe { GetClassNameW(hwnd, &mut class) } as usize;
+            let mut title = [0u16; 512];
+            let title_len = unsafe { GetWindowTextW(hwnd, &mut title) } as usize;
+            let mut rect = RECT::default();
+            let _ = unsafe { GetClientRect(hwnd, &mut rect) };
+
+            WindowInfo {
+                handle: hwnd.0 as usize,
+                class: String::from_utf16_lossy(&class[..class_len]),
+                title: String::from_utf16_lossy(&title[..title_len]),
+                visible: unsafe { IsWindowVisible(hwnd) }.as_bool(),
+                foreground: hwnd == foreground,
+                width: (rect.right - rect.left).max(0) as u32,
+                height: (rect.bottom - rect.top).max(0) as u32,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(handle: usize, class: &str, title: &str) -> WindowInfo {
+        WindowInfo {
+            handle,
+            class: class.to_owned(),
+            title: title.to_owned(),
+            visible: true,
+            foreground: false,
+            width: 1280,
+            height: 720,
+        }
+    }
+
+    fn hidden(window: WindowInfo) -> WindowInfo {
+        WindowInfo {
+            visible: false,
+            ..window
+        }
+    }
+
+    fn sized(window: WindowInfo, width: u32, height: u32) -> WindowInfo {
+        WindowInfo {
+            width,
+            height,
+            ..window
+        }
+    }
+
+    fn selected(selector: WindowSelector, windows: &[WindowInfo]) -> Option<usize> {
+        selector.select(windows).map(|window| window.handle)
+    }
+
+    #[test]
+    fn matches_literals_without_case() {
+        assert!(matches("Game", "game"));
+        assert!(matches("ÄRGER", "ärger"));
+        assert!(matches("", ""));
+        assert!(!matches("", "game"));
+        assert!(!matches("game", ""));
+        assert!(!matches("game", "games"));
+        assert!(!matches("games", "game"));
+    }
+
+    #[test]
+    fn matches_single_characters() {
+        assert!(matches("g?me", "game"));
+        assert!(matches("????", "game"));
+        assert!(!matches("???", "game"));
+        assert!(!matches("?????", "game"));
+        assert!(!matches("?", ""));
+    }
+
+    #[test]
+    fn matches_runs_of_characters() {
+        assert!(matches("*", ""));
+        assert!(matches("*", "game"));
+        assert!(matches("**", "game"));
+        assert!(matches("game*", "game"));
+        assert!(matches("*game", "game"));
+        assert!(matches("g*e", "game"));
+        assert!(matches("*Launcher*", "The Game Launcher v2"));
+        assert!(!matches("*launcher", "launcher v2"));
+    }
+
+    #[test]
+    fn matches_backtrack_to_the_last_star() {
+        // The first `b` and `ab` the stars stop at are not the ones that match.
+        assert!(matches("*ab", "aab"));
+        assert!(matches("a*b", "abbb"));
+        assert!(matches("*abc*", "ababcab"));
+        assert!(matches("a*b*c", "abxbxc"));
+        assert!(matches("*?b", "abab"));
+        assert!(matches("*a?c", "abcaxc"));
+        assert!(matches("*a*a*a", "aaa"));
+        assert!(!matches("*a*a*a*a", "aaa"));
+        assert!(!matches("a*b", "abba"));
+        assert!(!matches("*ab?", "aab"));
+    }
+
+    #[test]
+    fn any_prefers_visible_then_large_then_topmost() {
+        let windows = [
+            hidden(window(1, "Splash", "Loading")),
+            sized(window(2, "Game", "Game"), 640, 480),
+            window(3, "Game", "Game"),
+            window(4, "Game", "Game"),
+        ];
+        assert_eq!(selected(WindowSelector::Any, &windows), Some(3));
+        assert_eq!(selected(WindowSelector::Any, &windows[..2]), Some(2));
+        assert_eq!(selected(WindowSelector::Any, &windows[..1]), Some(1));
+        assert_eq!(selected(WindowSelector::Any, &[]), None);
+    }
+
+    #[test]
+    fn largest_visible_skips_hidden_and_empty_windows() {
+        let windows = [
+            hidden(sized(window(1, "Ime", ""), 4096, 4096)),
+            sized(window(2, "Tray", ""), 0, 0),
+            sized(window(3, "Launcher", ""), 800, 600),
+            window(4, "Game", ""),
+        ];
+        let selector = || WindowSelector::LargestVisible;
+        assert_eq!(selected(selector(), &windows), Some(4));
+        assert_eq!(selected(selector(), &windows[..3]), Some(3));
+        assert_eq!(selected(selector(), &windows[..2]), None);
+    }
+
+    #[test]
+    fn class_names_compare_without_case() {
+        let windows = [window(1, "UnityWndClass", ""), window(2, "Launcher", "")];
+        let by_class = |class: &str| WindowSelector::ClassName(class.to_owned());
+        assert_eq!(selected(by_class("unitywndclass"), &windows), Some(1));
+        assert_eq!(selected(by_class("LAUNCHER"), &windows), Some(2));
+        assert_eq!(selected(by_class("Unity*"), &windows), None);
+    }
+
+    #[test]
+    fn titles_match_the_pattern() {
+        let windows = [
+            window(1, "", "Game Launcher"),
+            hidden(window(2, "", "Game (DX11)")),
+            window(3, "", "Game (DX12)"),
+        ];
+        let by_title = |pattern: &str| WindowSelector::Title(pattern.to_owned());
+        assert_eq!(selected(by_title("game (dx1?)"), &windows), Some(3));
+        assert_eq!(selected(by_title("*DX11*"), &windows), Some(2));
+        assert_eq!(selected(by_title("*launcher"), &windows), Some(1));
+        assert_eq!(selected(by_title("Launcher"), &windows), None);
+    }
+
+    #[test]
+    fn foreground_and_handle_pick_their_window() {
+        let windows = [
+            window(1, "", ""),
+            WindowInfo {
+                foreground: true,
+                ..hidden(window(2, "", ""))
+            },
+        ];
+        assert_eq!(selected(WindowSelector::Foreground, &windows), Some(2));
+        assert_eq!(selected(WindowSelector::Foreground, &windows[..1]), None);
+        assert_eq!(selected(WindowSelector::Handle(2), &windows), Some(2));
+        assert_eq!(selected(WindowSelector::Handle(3), &windows), None);
+    }
+
+    #[test]
+    fn custom_scores_rank_before_visibility_and_size() {
+        let windows = [
+            window(1, "", "second"),
+            hidden(sized(window(2, "", "first"), 1, 1)),
+            window(3, "", "excluded"),
+            window(4, "", "second"),
+        ];
+        let selector = WindowSelector::custom(|window| match window.title.as_str() {
+            "first" => Some(2),
+            "second" => Some(1),
+            _ => None,
+        });
+        assert_eq!(selected(selector.clone(), &windows), Some(2));
+        assert_eq!(selected(selector.clone(), &windows[2..]), Some(4));
+        assert_eq!(selected(selector, &windows[2..3]), None);
+    }
+}
@@ -0,0 +1,342 @@
+//! Windows of shroud's own, for discovery before the game has created one.
+
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::{ProvidedWindow, WindowProvider};
+use crate::ShroudResult;
+
+/// Temporary windows not yet destroyed.
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// A hidden window shroud creates for discovery and destroys right after it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TemporaryWindow {
+    /// A hidden top-level window placed off-screen, which every API creates
+    /// swap chains for.
+    #[default]
+    OffScreen,
+    /// A message-only window, which window enumeration never lists. Some
+    /// drivers refuse swap chains for it.
+    MessageOnly,
+}
+
+impl TemporaryWindow {
+    /// How many temporary windows exist right now, for catching leaks.
+    pub fn live() -> usize {
+        LIVE.load(Ordering::SeqCst)
+    }
+}
+
+impl WindowProvider for TemporaryWindow {
+    type Window = TemporaryWindowGuard;
+
+    fn acquire(&self) -> ShroudResult<TemporaryWindowGuard> {
+        let handle = platform::create(*self)?;
+        LIVE.fetch_add(1, Ordering::SeqCst);
+        Ok(TemporaryWindowGuard {
+            handle,
+            kind: *self,
+            thread: PhantomData,
+        })
+    }
+}
+
+/// A window made by [`TemporaryWindow`], destroyed when dropped. Windows can
+/// only be destroyed by the thread that created them, so the guard stays on it.
+pub struct TemporaryWindowGuard {
+    handle: usize,
+    kind: TemporaryWindow,
+    thread: PhantomData<*mut ()>,
+}
+
+impl TemporaryWindowGuard {
+    pub fn kind(&self) -> TemporaryWindow {
+        self.kind
+    }
+}
+
+impl ProvidedWindow for TemporaryWindowGuard {
+    fn handle(&self) -> usize {
+        self.handle
+    }
+}
+
+impl Drop for TemporaryWindowGuard {
+    fn drop(&mut self) {
+        platform::destroy(self.handle);
+        LIVE.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl std::fmt::Debug for TemporaryWindowGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TemporaryWindowGuard")
+            .field("handle", &format_args!("{:#x}", self.handle))
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+/// A window class registered while any temporary window exists, and
+/// unregistered with the last one so that no class outlives the module its
+/// window procedure is in.
+///
+/// The class belongs to the module shroud is linked into rather than to the
+/// game, so that other copies of shroud in the process register classes of
+/// their own instead of reusing one whose window procedure may be unloaded.
+#[cfg(windows)]
+mod platform {
+    use std::sync::Mutex;
+
+    use windows::{
+        core::{w, PCWSTR},
+        Win32::{
+            Foundation::{
+                ERROR_CLASS_ALREADY_EXISTS, HINSTANCE, HMODULE, HWND, LPARAM, LRESULT, WPARAM,
+            },
+            System::LibraryLoader::{
+                GetModuleHandleExW, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
+                GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+            },
+            UI::WindowsAndMessaging::{
+                CreateWindowExW, DefWindowProcW, DestroyWindow, RegisterClassExW, UnregisterClassW,
+                HMENU, HWND_MESSAGE, WNDCLASSEXW, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW,
+                WS_OVERLAPPEDWINDOW,
+            },
+        },
+    };
+
+    use super::TemporaryWindow;
+    use crate::{ShroudError, ShroudResult};
+
+    const CLASS_NAME: PCWSTR = w!("shroud-temporary-window");
+
+    /// Serializes registering the class and creating a window against
+    /// destroying one and unregistering the class.
+    static CLASS: Mutex<()> = Mutex::new(());
+
+    /// The module containing [`window_proc`], which the class is registered
+    /// for.
+    fn instance() -> ShroudResult<HINSTANCE> {
+        let mut module = HMODULE::default();
+        unsafe {
+            GetModuleHandleExW(
+                GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS
+                    | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+                PCWSTR(window_proc as *const u16),
+                &mut module,
+            )
+        }
+        .map_err(|e| ShroudError::TemporaryWindow(std::io::Error::other(e)))?;
+        Ok(module.into())
+    }
+
+    pub(super) fn create(kind: TemporaryWindow) -> ShroudResult<usize> {
+        let _class = CLASS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let instance = instance()?;
+
+        let class = WNDCLASSEXW {
+            cbSize: std::mem::size_of::<WNDCLASSEXW>() as u32,
+            lpfnWndProc: Some(window_proc),
+            hInstance: instance,
+            lpszClassName: CLASS_NAME,
+            ..Default::default()
+        };
+        // The class only already exists when windows of other threads kept the
+        // last one to be destroyed from unregistering it.
+        if unsafe { RegisterClassExW(&class) } == 0 {
+            let error = std::io::Error::last_os_error();
+            if error.raw_os_error() != Some(ERROR_CLASS_ALREADY_EXISTS.0 as i32) {
+                return Err(ShroudError::TemporaryWindow(error));
+            }
+        }
+
+        let (parent, x, y) = match kind {
+            TemporaryWindow::OffScreen => (HWND::default(), -32000, -32000),
+            TemporaryWindow::MessageOnly => (HWND_MESSAGE, 0, 0),
+        };
+        let window = unsafe {
+            CreateWindowExW(
+                WS_EX_NOACTIVATE | WS_EX_TOOLWINDOW,
+                CLASS_NAME,
+                w!("shroud"),
+                WS_OVERLAPPEDWINDOW,
+                x,
+                y,
+                640,
+                480,
+                parent,
+                HMENU::default(),
+                instance,
+                None,
+            )
+        }
+        .map_err(|e| ShroudError::TemporaryWindow(std::io::Error::other(e)))?;
+        Ok(window.0 as usize)
+    }
+
+    pub(super) fn destroy(handle: usize) {
+        let _class = CLASS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        unsafe {
+            let _ = DestroyWindow(HWND(handle as *mut _));
+            // Fails while other threads' windows still use the class, the last
+            // of which unregisters it.
+            if let Ok(instance) = instance() {
+                let _ = UnregisterClassW(CLASS_NAME, instance);
+            }
+        }
+    }
+
+    /// Whether the class is registered for shroud's module, with its window
+    /// procedure.
+    #[cfg(test)]
+    pub(super) fn registered() -> bool {
+        use windows::Win32::UI::WindowsAndMessaging::GetClassInfoExW;
+
+        let Ok(instance) = instance() else {
+            return false;
+        };
+        let mut class = WNDCLASSEXW {
+            cbSize: std::mem::size_of::<WNDCLASSEXW>() as u32,
+            ..Default::default()
+        };
+        unsafe { GetClassInfoExW(instance, CLASS_NAME, &mut class) }.is_ok()
+            && class.lpfnWndProc.map(|proc| proc as *const ()) == Some(window_proc as *const ())
+    }
+
+    extern "system" fn window_proc(
+        window: HWND,
+        message: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        unsafe { DefWindowProcW(window, message, w_param, l_param) }
+    }
+}
+
+/// Without windows to create, handles are made up and the class is only kept
+/// track of, so that providers and their lifecycle behave the same everywhere.
+#[cfg(not(windows))]
+mod platform {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard,
+    };
+
+    use super::TemporaryWindow;
+    use crate::ShroudResult;
+
+    static NEXT: AtomicUsize = AtomicUsize::new(0x10000);
+
+    /// The class Windows would have registered, and how many windows use it.
+    struct Class {
+        registered: bool,
+        windows: usize,
+    }
+
+    static CLASS: Mutex<Class> = Mutex::new(Class {
+        registered: false,
+        windows: 0,
+    });
+
+    fn class() -> MutexGuard<'static, Class> {
+        CLASS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(super) fn create(_kind: TemporaryWindow) -> ShroudResult<usize> {
+        let mut class = class();
+        class.registered = true;
+        class.windows += 1;
+        Ok(NEXT.fetch_add(0x10, Ordering::Relaxed))
+    }
+
+    pub(super) fn destroy(_handle: usize) {
+        let mut class = class();
+        class.windows -= 1;
+        // Unregistering fails while other windows still use the class.
+        if class.windows == 0 {
+            class.registered = false;
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn registered() -> bool {
+        class().registered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::*;
+
+    /// [`TemporaryWindow::live`] and the class are the whole process', so tests
+    /// creating windows take turns.
+    static WINDOWS: Mutex<()> = Mutex::new(());
+
+    fn take_turn() -> MutexGuard<'static, ()> {
+        let turn = WINDOWS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        assert_eq!(TemporaryWindow::live(), 0);
+        assert!(!platform::registered());
+        turn
+    }
+
+    /// `live` windows exist, and the class is registered exactly while any do.
+    fn assert_live(live: usize) {
+        assert_eq!(TemporaryWindow::live(), live);
+        assert_eq!(platform::registered(), live > 0);
+    }
+
+    #[test]
+    fn windows_live_until_dropped() {
+        let _turn = take_turn();
+
+        let window = TemporaryWindow::OffScreen.acquire().unwrap();
+        assert_eq!(window.kind(), TemporaryWindow::OffScreen);
+        assert_ne!(window.handle(), 0);
+        assert_live(1);
+
+        drop(window);
+        assert_live(0);
+    }
+
+    #[test]
+    fn the_last_window_unregisters_the_class() {
+        let _turn = take_turn();
+
+        let first = TemporaryWindow::OffScreen.acquire().unwrap();
+        let second = TemporaryWindow::MessageOnly.acquire().unwrap();
+        assert_eq!(second.kind(), TemporaryWindow::MessageOnly);
+        assert_ne!(first.handle(), second.handle());
+        assert_live(2);
+
+        // The first window going away leaves the class to the second.
+        drop(first);
+        assert_live(1);
+        let third = TemporaryWindow::default().acquire().unwrap();
+        assert_live(2);
+
+        drop(second);
+        assert_live(1);
+        drop(third);
+        assert_live(0);
+
+        // And the next window registers it anew.
+        let fourth = TemporaryWindow::default().acquire().unwrap();
+        assert_live(1);
+        drop(fourth);
+        assert_live(0);
+    }
+}
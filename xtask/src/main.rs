@@ -0,0 +1,757 @@
+//! Repository automation, run through `cargo xtask <command>`.
+//!
+//! `codegen` regenerates the method enums in `src/*/methods.rs` from the vendored
+//! `winmd/Windows.Win32.winmd`, together with compile-time assertions that every
+//! variant's index matches its slot in the `windows` crate's `*_Vtbl` struct, and
+//! a module of typed signatures per enum.
+//! `codegen --check` fails if the committed files differ from what the metadata produces.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    io::Write as _,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+};
+
+use windows_metadata::{
+    File, HasAttributes, MethodDef, Reader, Type, TypeDef, TypeKind, TypeName, Value,
+};
+
+/// One generated file and the enums it holds: (enum name, full interface name).
+struct Output {
+    path: &'static str,
+    /// Stripped from enum names, with the `Methods` suffix, to name their
+    /// signature modules.
+    prefix: &'static str,
+    enums: &'static [(&'static str, &'static str)],
+}
+
+const OUTPUTS: &[Output] = &[
+    Output {
+        path: "src/dxgi/methods.rs",
+        prefix: "Dxgi",
+        enums: &[
+            (
+                "DxgiSwapchain1Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain1",
+            ),
+            (
+                "DxgiSwapchain2Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain2",
+            ),
+            (
+                "DxgiSwapchain3Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain3",
+            ),
+            (
+                "DxgiSwapchain4Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain4",
+            ),
+            (
+                "DxgiFactoryMethods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory",
+            ),
+            (
+                "DxgiFactory1Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory1",
+            ),
+            (
+                "DxgiFactory2Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory2",
+            ),
+            (
+                "DxgiFactory3Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory3",
+            ),
+            (
+                "DxgiFactory4Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory4",
+            ),
+            (
+                "DxgiFactory5Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory5",
+            ),
+            (
+                "DxgiFactory6Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory6",
+            ),
+            (
+                "DxgiFactory7Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIFactory7",
+            ),
+            (
+                "DxgiAdapterMethods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIAdapter",
+            ),
+            (
+                "DxgiAdapter1Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIAdapter1",
+            ),
+            (
+                "DxgiAdapter2Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIAdapter2",
+            ),
+            (
+                "DxgiAdapter3Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIAdapter3",
+            ),
+            (
+                "DxgiAdapter4Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIAdapter4",
+            ),
+            (
+                "DxgiOutputMethods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput",
+            ),
+            (
+                "DxgiOutput1Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput1",
+            ),
+            (
+                "DxgiOutput2Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput2",
+            ),
+            (
+                "DxgiOutput3Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput3",
+            ),
+            (
+                "DxgiOutput4Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput4",
+            ),
+            (
+                "DxgiOutput5Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput5",
+            ),
+            (
+                "DxgiOutput6Methods",
+                "Windows.Win32.Graphics.Dxgi.IDXGIOutput6",
+            ),
+        ],
+    },
+    Output {
+        path: "src/directx9/methods.rs",
+        prefix: "DirectX9",
+        enums: &[
+            (
+                "DirectX9DeviceMethods",
+                "Windows.Win32.Graphics.Direct3D9.IDirect3DDevice9",
+            ),
+            (
+                "DirectX9DeviceExMethods",
+                "Windows.Win32.Graphics.Direct3D9.IDirect3DDevice9Ex",
+            ),
+            (
+                "DirectX9Direct3DMethods",
+                "Windows.Win32.Graphics.Direct3D9.IDirect3D9",
+            ),
+            (
+                "DirectX9Direct3DExMethods",
+                "Windows.Win32.Graphics.Direct3D9.IDirect3D9Ex",
+            ),
+            (
+                "DirectX9SwapchainMethods",
+                "Windows.Win32.Graphics.Direct3D9.IDirect3DSwapChain9",
+            ),
+        ],
+    },
+    Output {
+        path: "src/directx10/methods.rs",
+        prefix: "DirectX10",
+        enums: &[
+            (
+                "DirectX10SwapchainMethods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain",
+            ),
+            (
+                "DirectX10DeviceMethods",
+                "Windows.Win32.Graphics.Direct3D10.ID3D10Device",
+            ),
+            (
+                "DirectX10Device1Methods",
+                "Windows.Win32.Graphics.Direct3D10.ID3D10Device1",
+            ),
+        ],
+    },
+    Output {
+        path: "src/directx11/methods.rs",
+        prefix: "DirectX11",
+        enums: &[
+            (
+                "DirectX11SwapchainMethods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain",
+            ),
+            (
+                "DirectX11DeviceMethods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11Device",
+            ),
+            (
+                "DirectX11ContextMethods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11DeviceContext",
+            ),
+            (
+                "DirectX11Device1Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11Device1",
+            ),
+            (
+                "DirectX11Device2Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11Device2",
+            ),
+            (
+                "DirectX11Device3Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11Device3",
+            ),
+            (
+                "DirectX11Device4Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11Device4",
+            ),
+            (
+                "DirectX11Device5Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11Device5",
+            ),
+            (
+                "DirectX11Context1Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11DeviceContext1",
+            ),
+            (
+                "DirectX11Context2Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11DeviceContext2",
+            ),
+            (
+                "DirectX11Context3Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11DeviceContext3",
+            ),
+            (
+                "DirectX11Context4Methods",
+                "Windows.Win32.Graphics.Direct3D11.ID3D11DeviceContext4",
+            ),
+        ],
+    },
+    Output {
+        path: "src/directx12/methods.rs",
+        prefix: "DirectX12",
+        enums: &[
+            (
+                "DirectX12DeviceMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device",
+            ),
+            (
+                "DirectX12CommandQueueMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12CommandQueue",
+            ),
+            (
+                "DirectX12CommandAllocatorMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12CommandAllocator",
+            ),
+            (
+                "DirectX12CommandListMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList",
+            ),
+            (
+                "DirectX12SwapchainMethods",
+                "Windows.Win32.Graphics.Dxgi.IDXGISwapChain",
+            ),
+            (
+                "DirectX12Device1Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device1",
+            ),
+            (
+                "DirectX12Device2Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device2",
+            ),
+            (
+                "DirectX12Device3Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device3",
+            ),
+            (
+                "DirectX12Device4Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device4",
+            ),
+            (
+                "DirectX12Device5Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device5",
+            ),
+            (
+                "DirectX12Device6Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device6",
+            ),
+            (
+                "DirectX12Device7Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device7",
+            ),
+            (
+                "DirectX12Device8Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device8",
+            ),
+            (
+                "DirectX12Device9Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device9",
+            ),
+            (
+                "DirectX12Device10Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Device10",
+            ),
+            (
+                "DirectX12CommandList1Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList1",
+            ),
+            (
+                "DirectX12CommandList2Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList2",
+            ),
+            (
+                "DirectX12CommandList3Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList3",
+            ),
+            (
+                "DirectX12CommandList4Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList4",
+            ),
+            (
+                "DirectX12CommandList5Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList5",
+            ),
+            (
+                "DirectX12CommandList6Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList6",
+            ),
+            (
+                "DirectX12CommandList7Methods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12GraphicsCommandList7",
+            ),
+            (
+                "DirectX12FenceMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Fence",
+            ),
+            (
+                "DirectX12ResourceMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12Resource",
+            ),
+            (
+                "DirectX12DescriptorHeapMethods",
+                "Windows.Win32.Graphics.Direct3D12.ID3D12DescriptorHeap",
+            ),
+        ],
+    },
+];
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        _ => Err("usage: cargo xtask codegen [--check]".to_owned()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside the workspace")
+        .to_path_buf()
+}
+
+fn codegen(check: bool) -> Result<(), String> {
+    let root = root();
+    let winmd = root.join("xtask/winmd/Windows.Win32.winmd");
+    let bytes = std::fs::read(&winmd).map_err(|e| format!("{}: {e}", winmd.display()))?;
+    let file = File::new(bytes).ok_or_else(|| format!("{}: not a winmd file", winmd.display()))?;
+    let reader = Reader::new(vec![file]);
+
+    let mut stale = Vec::new();
+    for output in OUTPUTS {
+        let generated = generate(reader, output)?;
+        let path = root.join(output.path);
+        let current = std::fs::read_to_string(&path).unwrap_or_default();
+        if current == generated {
+            continue;
+        }
+
+        if check {
+            stale.push(output.path);
+        } else {
+            std::fs::write(&path, generated).map_err(|e| format!("{}: {e}", path.display()))?;
+            println!("wrote {}", output.path);
+        }
+    }
+
+    match stale.is_empty() {
+        true => Ok(()),
+        false => Err(format!(
+            "out of date, run `cargo xtask codegen`: {}",
+            stale.join(", ")
+        )),
+    }
+}
+
+fn generate(reader: &'static Reader, output: &Output) -> Result<String, String> {
+    let mut out = String::new();
+    out.push_str("// Generated by `cargo xtask codegen` from xtask/winmd/Windows.Win32.winmd, do not edit.\n\n");
+    out.push_str("use strum_macros::{EnumCount, EnumIter};\n\n");
+    out.push_str("#[cfg(windows)]\nuse crate::vtable::assert_slot;\n");
+    out.push_str("use crate::InterfaceMethods;\n");
+
+    let mut modules = String::new();
+    for (name, interface) in output.enums {
+        let def = type_def(reader, interface)?;
+        let slots = vtable(reader, def)?;
+
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "/// `{}` vtable slots, inherited methods first.",
+            def.name()
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]"
+        )
+        .unwrap();
+        writeln!(out, "pub enum {name} {{").unwrap();
+        for slot in &slots {
+            writeln!(out, "    {},", slot.variant).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+
+        writeln!(out).unwrap();
+        writeln!(out, "impl InterfaceMethods for {name} {{").unwrap();
+        writeln!(
+            out,
+            "    const INTERFACE: &'static str = \"{}\";",
+            def.name()
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+
+        writeln!(out).unwrap();
+        writeln!(out, "#[cfg(windows)]").unwrap();
+        writeln!(out, "const _: () = {{").unwrap();
+        writeln!(out, "    use {} as Vtbl;", vtbl_path(def)).unwrap();
+        writeln!(out, "    use {name} as M;").unwrap();
+        writeln!(out).unwrap();
+        for slot in &slots {
+            writeln!(
+                out,
+                "    assert_slot!(Vtbl, {} => M::{});",
+                slot.field, slot.variant
+            )
+            .unwrap();
+        }
+        writeln!(out, "}};").unwrap();
+
+        let module = module_name(name, output.prefix)?;
+        signatures(reader, &mut modules, name, &module, &slots)?;
+    }
+
+    // The signature macro's body is left as generated, one method per line.
+    Ok(rustfmt(out)? + &modules)
+}
+
+/// Snake case name of the signature module of `name`, e.g. `command_list1` for
+/// `DirectX12CommandList1Methods`.
+fn module_name(name: &str, prefix: &str) -> Result<String, String> {
+    let stem = name
+        .strip_prefix(prefix)
+        .and_then(|name| name.strip_suffix("Methods"))
+        .ok_or_else(|| format!("`{name}` does not match `{prefix}*Methods`"))?;
+
+    Ok(snake_case(stem))
+}
+
+/// `CommandList1` as `command_list1`, `ppvObject` as `ppv_object`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let word_start = i > 0
+            && c.is_ascii_uppercase()
+            && (chars[i - 1].is_ascii_lowercase()
+                || chars.get(i + 1).is_some_and(char::is_ascii_lowercase));
+        if word_start {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// Appends the module of marker types carrying the function pointer type of each
+/// of `slots`, see `Method` in `src/method_table.rs`.
+fn signatures(
+    reader: &'static Reader,
+    out: &mut String,
+    name: &str,
+    module: &str,
+    slots: &[Slot],
+) -> Result<(), String> {
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// Typed signatures of [`{name}`], for [`MethodTable::typed`](crate::MethodTable::typed)."
+    )
+    .unwrap();
+    writeln!(out, "pub mod {module} {{").unwrap();
+    writeln!(out, "    use std::ffi::c_void;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    use super::{name};").unwrap();
+    writeln!(out, "    use crate::{{method_table::signatures, HRESULT}};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    signatures! {{").unwrap();
+    writeln!(out, "        {name};").unwrap();
+    for slot in slots {
+        writeln!(
+            out,
+            "        {}{},",
+            slot.variant,
+            signature(reader, slot.method)?
+        )
+        .unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    Ok(())
+}
+
+/// Parameters after `this` and return type of `method`, as `(Name: Type, ..) -> Type`.
+///
+/// Struct returns become an out pointer right after `this`, where COM passes
+/// them, e.g. `ID3D12Device::GetResourceAllocationInfo`.
+fn signature(reader: &'static Reader, method: MethodDef) -> Result<String, String> {
+    let signature = method.signature(&[]);
+    let names = method
+        .params()
+        .filter(|param| param.sequence() != 0)
+        .map(|param| param.name());
+
+    let mut params = Vec::new();
+    for (name, ty) in names.zip(&signature.params) {
+        params.push(format!("{}: {}", param_name(name), abi_type(reader, ty)?));
+    }
+
+    let returns = match &signature.return_type {
+        Type::Void => String::new(),
+        Type::TypeDef(def, _) if is_plain_struct(*def) => {
+            params.insert(0, "result: *mut c_void".to_owned());
+            String::new()
+        }
+        ty => format!(" -> {}", abi_type(reader, ty)?),
+    };
+    Ok(format!("({}){returns}", params.join(", ")))
+}
+
+fn param_name(name: &str) -> String {
+    let name = snake_case(name);
+    match name.as_str() {
+        "as" | "box" | "fn" | "impl" | "in" | "loop" | "mod" | "move" | "ref" | "type" | "use"
+        | "where" => format!("r#{name}"),
+        _ => name,
+    }
+}
+
+fn is_plain_struct(def: TypeDef) -> bool {
+    def.kind() == TypeKind::Struct && def.find_attribute("NativeTypedefAttribute").is_none()
+}
+
+/// Rust type passed the same way as `ty`, using only primitives, raw pointers
+/// and `HRESULT`, so signatures read the same on every host.
+///
+/// Enums pass as their underlying integer, typedefs as what they wrap, and
+/// interfaces and pointers to anything but primitives as `c_void` pointers.
+/// Structs pass by value only when they fit an integer.
+fn abi_type(reader: &'static Reader, ty: &Type) -> Result<String, String> {
+    Ok(match ty {
+        Type::Void => "c_void".to_owned(),
+        Type::Bool => "bool".to_owned(),
+        Type::Char => "u16".to_owned(),
+        Type::I8 => "i8".to_owned(),
+        Type::U8 => "u8".to_owned(),
+        Type::I16 => "i16".to_owned(),
+        Type::U16 => "u16".to_owned(),
+        Type::I32 => "i32".to_owned(),
+        Type::U32 => "u32".to_owned(),
+        Type::I64 => "i64".to_owned(),
+        Type::U64 => "u64".to_owned(),
+        Type::F32 => "f32".to_owned(),
+        Type::F64 => "f64".to_owned(),
+        Type::ISize => "isize".to_owned(),
+        Type::USize => "usize".to_owned(),
+        Type::MutPtr(pointee, depth) => pointer(reader, "*mut ", pointee, *depth)?,
+        Type::ConstPtr(pointee, depth) => pointer(reader, "*const ", pointee, *depth)?,
+        Type::Name(TypeName(namespace, name)) => match (*namespace, *name) {
+            ("Windows.Foundation", "HResult") => "HRESULT".to_owned(),
+            ("Windows.Win32.Foundation", "PSTR") => "*mut u8".to_owned(),
+            ("Windows.Win32.Foundation", "PWSTR") => "*mut u16".to_owned(),
+            ("Windows.Win32.Foundation", "PCSTR") => "*const u8".to_owned(),
+            ("Windows.Win32.Foundation", "PCWSTR") => "*const u16".to_owned(),
+            _ => {
+                let def = type_def(reader, &format!("{namespace}.{name}"))?;
+                abi_type(reader, &Type::TypeDef(def, Vec::new()))?
+            }
+        },
+        Type::TypeDef(def, _) => match def.kind() {
+            TypeKind::Interface => "*mut c_void".to_owned(),
+            TypeKind::Enum => abi_type(reader, &def.underlying_type())?,
+            TypeKind::Struct => {
+                let fields: Vec<Type> = def.fields().map(|field| field.ty(Some(*def))).collect();
+                match fields.as_slice() {
+                    [field] => abi_type(reader, field)?,
+                    fields
+                        if fields.iter().all(|field| {
+                            abi_type(reader, field).is_ok_and(|ty| !ty.starts_with('*'))
+                        }) =>
+                    {
+                        match fields.iter().map(Type::size).sum::<usize>() {
+                            4 => "u32".to_owned(),
+                            8 => "u64".to_owned(),
+                            _ => return Err(format!("struct `{}` passed by value", def.name())),
+                        }
+                    }
+                    _ => return Err(format!("struct `{}` passed by value", def.name())),
+                }
+            }
+            _ => return Err(format!("unsupported parameter type `{}`", def.name())),
+        },
+        other => return Err(format!("unsupported parameter type `{other:?}`")),
+    })
+}
+
+/// `depth` pointers to `pointee`, which is opaque unless it is a primitive,
+/// pointer or interface.
+fn pointer(
+    reader: &'static Reader,
+    qualifier: &str,
+    pointee: &Type,
+    depth: usize,
+) -> Result<String, String> {
+    let pointee = match pointee {
+        Type::Name(TypeName(namespace, name)) => reader
+            .get_type_def(namespace, name)
+            .next()
+            .map_or_else(|| pointee.clone(), |def| Type::TypeDef(def, Vec::new())),
+        pointee => pointee.clone(),
+    };
+    let pointee = match &pointee {
+        Type::TypeDef(def, _) if is_plain_struct(*def) => "c_void".to_owned(),
+        pointee => abi_type(reader, pointee).unwrap_or_else(|_| "c_void".to_owned()),
+    };
+    Ok(qualifier.repeat(depth) + &pointee)
+}
+
+fn type_def(reader: &'static Reader, full_name: &str) -> Result<TypeDef, String> {
+    let (namespace, name) = full_name
+        .rsplit_once('.')
+        .ok_or_else(|| format!("`{full_name}` is not a full type name"))?;
+    reader
+        .get_type_def(namespace, name)
+        .next()
+        .ok_or_else(|| format!("`{full_name}` not found in metadata"))
+}
+
+/// One vtable slot of a generated enum.
+struct Slot {
+    method: MethodDef,
+    /// Enum variant name.
+    variant: String,
+    /// Path of the slot's field in the `windows` crate's `*_Vtbl` struct,
+    /// through one `base__` per inherited interface.
+    field: String,
+}
+
+/// Slots of `def` in vtable order: the root interface's methods first, then each
+/// derived interface's own methods down to `def`.
+///
+/// Fields are named the way the `windows` crate names them, overloads within one
+/// interface numbered from the second; variants take the field name, numbered the
+/// same way should a name repeat along the chain.
+fn vtable(reader: &'static Reader, def: TypeDef) -> Result<Vec<Slot>, String> {
+    let mut chain = vec![def];
+    while let Some(base) = chain.last().unwrap().interface_impls().next() {
+        let base = match base.ty(&[]) {
+            Type::TypeDef(base, _) => base,
+            Type::Name(name) => type_def(reader, &format!("{}.{}", name.0, name.1))?,
+            other => return Err(format!("unexpected base of `{}`: {other:?}", def.name())),
+        };
+        chain.push(base);
+    }
+
+    let mut variants = HashMap::<String, usize>::new();
+    let mut slots = Vec::new();
+    for (depth, def) in chain.iter().enumerate().rev() {
+        let mut fields = HashMap::<String, usize>::new();
+        for method in def.methods() {
+            let field = numbered(&mut fields, overload_name(method));
+            let variant = numbered(&mut variants, field.clone());
+            slots.push(Slot {
+                method,
+                variant,
+                field: format!("{}{field}", "base__.".repeat(depth)),
+            });
+        }
+    }
+    Ok(slots)
+}
+
+fn overload_name(method: MethodDef) -> String {
+    method
+        .find_attribute("OverloadAttribute")
+        .and_then(|attribute| {
+            attribute.args().into_iter().find_map(|(_, arg)| match arg {
+                Value::String(name) => Some(name),
+                _ => None,
+            })
+        })
+        .unwrap_or_else(|| method.name().to_owned())
+}
+
+fn numbered(seen: &mut HashMap<String, usize>, name: String) -> String {
+    let count = seen.entry(name.clone()).or_default();
+    *count += 1;
+    match *count {
+        1 => name,
+        n => format!("{name}{n}"),
+    }
+}
+
+/// Path of `def`'s vtable struct in the `windows` crate.
+fn vtbl_path(def: TypeDef) -> String {
+    let namespace = def.namespace().replacen("Windows", "windows", 1);
+    format!("{}::{}_Vtbl", namespace.replace('.', "::"), def.name())
+}
+
+fn rustfmt(source: String) -> Result<String, String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("rustfmt: {e}"))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .map_err(|e| format!("rustfmt: {e}"))?;
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("rustfmt: {e}"))?;
+    match output.status.success() {
+        true => String::from_utf8(output.stdout).map_err(|e| format!("rustfmt: {e}")),
+        false => Err(format!(
+            "rustfmt: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )),
+    }
+}